@@ -0,0 +1,65 @@
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+
+use components::{Mass, Position, Velocity};
+use toucan_ecs::entity::EntityPool;
+use toucan_ecs::world::World;
+
+mod components;
+
+const BULLETS: u32 = 1_000;
+
+type Bullet = (Position, Velocity, Mass);
+
+fn bullet() -> Bullet {
+    let position = Position { x: 0.0, y: 0.0 };
+    let velocity = Velocity { dx: 1.0, dy: 1.0 };
+    (position, velocity, Mass(1.0))
+}
+
+fn spawn_destroy(criterion: &mut Criterion) {
+    fn setup() -> World {
+        let mut world = World::new();
+        let bullets = (0..BULLETS).map(|_| world.create_with(bullet()));
+        for entity in bullets.collect::<Vec<_>>() {
+            world.destroy(entity);
+        }
+        world
+    }
+
+    fn routine(world: &mut World) {
+        let bullets = (0..BULLETS)
+            .map(|_| world.create_with(bullet()))
+            .collect::<Vec<_>>();
+        for entity in bullets {
+            world.destroy(entity);
+        }
+    }
+
+    criterion.bench_function("spawn destroy bullets", |bencher| {
+        bencher.iter_batched_ref(setup, routine, BatchSize::SmallInput)
+    });
+}
+
+fn acquire_release(criterion: &mut Criterion) {
+    fn setup() -> (World, EntityPool<Bullet>) {
+        let mut world = World::new();
+        let pool = EntityPool::new(&mut world, BULLETS, bullet());
+        (world, pool)
+    }
+
+    fn routine((world, pool): &mut (World, EntityPool<Bullet>)) {
+        let bullets = (0..BULLETS)
+            .map(|_| pool.acquire(world))
+            .collect::<Vec<_>>();
+        for entity in bullets {
+            pool.release(world, entity);
+        }
+    }
+
+    criterion.bench_function("acquire release bullets", |bencher| {
+        bencher.iter_batched_ref(setup, routine, BatchSize::SmallInput)
+    });
+}
+
+criterion_group!(pool_group, spawn_destroy, acquire_release);
+criterion_main!(pool_group);
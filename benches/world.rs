@@ -53,10 +53,57 @@ fn fill_world(criterion: &mut Criterion) {
     });
 }
 
+fn filled_world() -> World {
+    let mut world = World::new();
+    let into_iter = (0..1_000_u16).map(|i| {
+        let f = f32::from(i);
+        let velocity = Velocity {
+            dx: -f / 10.0,
+            dy: f / 10.0,
+        };
+        let position = Position { x: -f, y: f };
+        let mass = Mass(f);
+        (velocity, position, mass)
+    });
+    world.extend_with(into_iter);
+    world
+}
+
+fn snapshot_world(criterion: &mut Criterion) {
+    let world = filled_world();
+
+    criterion.bench_function("snapshot world", |bencher| {
+        bencher.iter(|| world.snapshot())
+    });
+}
+
+fn restore_world(criterion: &mut Criterion) {
+    fn setup() -> World {
+        let mut world = filled_world();
+        let entities = world.extend(100);
+        for entity in entities {
+            world.attach(entity, Mass(0.0));
+        }
+        world
+    }
+
+    let snapshot = filled_world().snapshot();
+
+    criterion.bench_function("restore world", |bencher| {
+        bencher.iter_batched_ref(
+            setup,
+            |world| world.restore(&snapshot),
+            BatchSize::SmallInput,
+        )
+    });
+}
+
 criterion_group!(
     world_group,
     create_world,
     register_world_components,
     fill_world,
+    snapshot_world,
+    restore_world,
 );
 criterion_main!(world_group);
@@ -15,6 +15,7 @@ impl Field {
     }
 }
 
+#[allow(dead_code)]
 #[derive(Copy, Clone, Component, Debug)]
 pub struct Point {
     pub x: u16,
@@ -0,0 +1,179 @@
+use std::any::TypeId;
+use std::hash::BuildHasherDefault;
+
+use hashbrown::HashSet;
+
+use crate::app::{App, Plugin};
+use crate::component::Component;
+use crate::hash::TypeIdHasher;
+#[cfg(feature = "resource")]
+use crate::resource::{Resource, ResourceSet};
+use crate::system::foreach::{ForeachSystem, Query as ForeachQuery};
+use crate::system::query::Query;
+use crate::system::{Schedule, System};
+#[cfg(feature = "resource")]
+use crate::world::FromWorld;
+use crate::world::World;
+
+/// A builder for [`App`] struct.
+///
+/// It is passed to the [plugins](Plugin) to configure the application.
+pub struct AppBuilder {
+    world: World,
+    schedule: Schedule,
+    plugins: HashSet<TypeId, BuildHasherDefault<TypeIdHasher>>,
+}
+
+impl AppBuilder {
+    pub(super) fn new() -> Self {
+        Self {
+            world: World::new(),
+            schedule: Schedule::builder().build(),
+            plugins: HashSet::default(),
+        }
+    }
+
+    /// Adds the plugin to the [application](App) and builds it immediately.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the plugin was already added
+    /// or if any of its [dependencies](Plugin::dependencies()) was not added before.
+    pub fn add_plugin<P>(&mut self, plugin: P) -> &mut Self
+    where
+        P: Plugin,
+    {
+        let type_id = TypeId::of::<P>();
+        if self.plugins.contains(&type_id) {
+            panic!("plugin {} was already added", plugin.name())
+        }
+        for (dependency, name) in plugin.dependencies().iter() {
+            if !self.plugins.contains(&dependency) {
+                panic!(
+                    "plugin {} requires plugin {} to be added before",
+                    plugin.name(),
+                    name,
+                )
+            }
+        }
+        self.plugins.insert(type_id);
+        plugin.build(self);
+        self
+    }
+
+    /// Returns `true` if the plugin was added to the [application](App).
+    pub fn has_plugin<P>(&self) -> bool
+    where
+        P: Plugin,
+    {
+        self.plugins.contains(&TypeId::of::<P>())
+    }
+
+    /// Registers new type of component in the [world](World).
+    ///
+    /// See [`World::register`] for more details.
+    pub fn register<C>(&mut self) -> &mut Self
+    where
+        C: Component,
+    {
+        self.world.register::<C>();
+        self
+    }
+
+    /// Creates new resource or set of resources in the [world](World).
+    ///
+    /// See [`World::create_resources`] for more details.
+    #[cfg(feature = "resource")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
+    pub fn create_resources<S>(&mut self, set: S) -> &mut Self
+    where
+        S: ResourceSet,
+    {
+        self.world.create_resources(set);
+        self
+    }
+
+    /// Creates new resource initialized by [`FromWorld`](crate::world::FromWorld)
+    /// trait implementation if the [world](World) does not contain it yet.
+    ///
+    /// See [`World::init_resource`] for more details.
+    #[cfg(feature = "resource")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
+    pub fn init_resource<R>(&mut self) -> &mut Self
+    where
+        R: Resource + FromWorld,
+    {
+        self.world.init_resource::<R>();
+        self
+    }
+
+    /// Adds the system to the [schedule](Schedule) of the application.
+    ///
+    /// See [`ScheduleBuilder::system`](crate::system::ScheduleBuilder::system()) for more details.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if provided query does not satisfies
+    /// the first rule of references described in
+    /// **References and Borrowing** section of [**Rust Book**][rust_book]:
+    ///
+    /// > - *At any given time, you can have either **one** mutable reference
+    /// >   or **any** number of immutable references.*
+    ///
+    /// [rust_book]: https://doc.rust-lang.org/book/ch04-02-references-and-borrowing.html#the-rules-of-references
+    pub fn system<S, Q>(&mut self, system: S) -> &mut Self
+    where
+        S: System<'static, Q> + for<'data> System<'data, Q::Rebind<'data>> + Send,
+        Q: Query<'static>,
+    {
+        self.schedule.insert_system(system);
+        self
+    }
+
+    /// Adds the foreach system to the [schedule](Schedule) of the application.
+    ///
+    /// See [`ScheduleBuilder::foreach_system`](crate::system::ScheduleBuilder::foreach_system())
+    /// for more details.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if provided query does not satisfies
+    /// the first rule of references described in
+    /// **References and Borrowing** section of [**Rust Book**][rust_book]:
+    ///
+    /// > - *At any given time, you can have either **one** mutable reference
+    /// >   or **any** number of immutable references.*
+    ///
+    /// [rust_book]: https://doc.rust-lang.org/book/ch04-02-references-and-borrowing.html#the-rules-of-references
+    pub fn foreach_system<S, Q>(&mut self, system: S) -> &mut Self
+    where
+        S: ForeachSystem<'static, Q> + for<'data> ForeachSystem<'data, Q::Rebind<'data>> + Send,
+        Q: ForeachQuery<'static>,
+    {
+        self.schedule.insert_foreach_system(system);
+        self
+    }
+
+    /// Returns **shared** reference to the [world](World) of the application.
+    pub fn world(&self) -> &World {
+        &self.world
+    }
+
+    /// Returns **unique** reference to the [world](World) of the application.
+    pub fn world_mut(&mut self) -> &mut World {
+        &mut self.world
+    }
+
+    /// Returns **unique** reference to the [schedule](Schedule) of the application.
+    pub fn schedule_mut(&mut self) -> &mut Schedule {
+        &mut self.schedule
+    }
+
+    /// Finalizes the builder into an [application](App).
+    pub fn build(self) -> App {
+        let Self {
+            world, schedule, ..
+        } = self;
+        App { world, schedule }
+    }
+}
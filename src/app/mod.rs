@@ -0,0 +1,83 @@
+//! Provides [application](App) which bundles the [world](World)
+//! with the [schedule](Schedule) of systems, configured by [plugins](Plugin).
+
+pub use builder::AppBuilder;
+pub use plugin::{Dependencies, Plugin};
+
+use crate::system::Schedule;
+use crate::world::World;
+
+mod builder;
+mod plugin;
+
+/// Application which owns the [world](World) and the [schedule](Schedule)
+/// of systems to execute on this world.
+///
+/// It can be created by the [builder](AppBuilder) configured by [plugins](Plugin).
+///
+/// # Examples
+///
+/// ```
+/// use toucan_ecs::prelude::*;
+///
+/// #[derive(Copy, Clone, Component)]
+/// struct Position(f32);
+///
+/// struct MovementPlugin;
+///
+/// impl Plugin for MovementPlugin {
+///     fn build(&self, app: &mut AppBuilder) {
+///         app.register::<Position>()
+///             .foreach_system(|position: &mut Position| position.0 += 1.0);
+///     }
+/// }
+///
+/// let mut builder = App::builder();
+/// builder.add_plugin(MovementPlugin);
+/// let mut app = builder.build();
+///
+/// let entity = app.world_mut().create_with(Position(0.0));
+/// app.run();
+/// assert_eq!(app.world().get::<Position>(entity).unwrap().0, 1.0);
+/// ```
+pub struct App {
+    world: World,
+    schedule: Schedule,
+}
+
+impl App {
+    /// Creates a new [application](App) builder.
+    pub fn builder() -> AppBuilder {
+        AppBuilder::new()
+    }
+
+    /// Executes the [schedule](Schedule) of this application once.
+    pub fn run(&mut self) {
+        self.schedule.run(&mut self.world);
+    }
+
+    /// Returns **shared** reference to the [world](World) of this application.
+    pub fn world(&self) -> &World {
+        &self.world
+    }
+
+    /// Returns **unique** reference to the [world](World) of this application.
+    pub fn world_mut(&mut self) -> &mut World {
+        &mut self.world
+    }
+
+    /// Returns **shared** reference to the [schedule](Schedule) of this application.
+    pub fn schedule(&self) -> &Schedule {
+        &self.schedule
+    }
+
+    /// Returns **unique** reference to the [schedule](Schedule) of this application.
+    pub fn schedule_mut(&mut self) -> &mut Schedule {
+        &mut self.schedule
+    }
+
+    /// Splits this application into the [world](World) and the [schedule](Schedule).
+    pub fn into_parts(self) -> (World, Schedule) {
+        (self.world, self.schedule)
+    }
+}
@@ -0,0 +1,86 @@
+use std::any::{type_name, TypeId};
+
+use crate::app::AppBuilder;
+
+/// Reusable part of the [application](crate::app::App) configuration.
+///
+/// Plugin can register components, create resources
+/// and add systems to the [builder](AppBuilder) of the application.
+///
+/// # Examples
+///
+/// ```
+/// use toucan_ecs::app::Dependencies;
+/// use toucan_ecs::prelude::*;
+///
+/// #[derive(Copy, Clone, Component)]
+/// struct Position(f32);
+///
+/// struct PositionPlugin;
+///
+/// impl Plugin for PositionPlugin {
+///     fn build(&self, app: &mut AppBuilder) {
+///         app.register::<Position>();
+///     }
+/// }
+///
+/// struct PrintPlugin;
+///
+/// impl Plugin for PrintPlugin {
+///     fn build(&self, app: &mut AppBuilder) {
+///         app.foreach_system(|position: &Position| println!("position: {}", position.0));
+///     }
+///
+///     fn dependencies(&self) -> Dependencies {
+///         Dependencies::new().with::<PositionPlugin>()
+///     }
+/// }
+///
+/// let mut builder = App::builder();
+/// builder.add_plugin(PositionPlugin).add_plugin(PrintPlugin);
+/// let app = builder.build();
+/// ```
+pub trait Plugin: 'static {
+    /// Configures the [application](crate::app::App) through its builder.
+    fn build(&self, app: &mut AppBuilder);
+
+    /// Returns name of the plugin which is used in panic messages.
+    ///
+    /// By default, it is the type name of the plugin.
+    fn name(&self) -> &'static str {
+        type_name::<Self>()
+    }
+
+    /// Returns plugins which must be added to the [builder](AppBuilder) before this plugin.
+    ///
+    /// By default, plugin has no dependencies.
+    fn dependencies(&self) -> Dependencies {
+        Dependencies::new()
+    }
+}
+
+/// List of [plugins](Plugin) which are required by another plugin.
+#[derive(Debug, Clone, Default)]
+pub struct Dependencies {
+    plugins: Vec<(TypeId, &'static str)>,
+}
+
+impl Dependencies {
+    /// Creates an empty list of dependencies.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the plugin to the list of dependencies.
+    pub fn with<P>(mut self) -> Self
+    where
+        P: Plugin,
+    {
+        self.plugins.push((TypeId::of::<P>(), type_name::<P>()));
+        self
+    }
+
+    pub(super) fn iter(&self) -> impl Iterator<Item = (TypeId, &'static str)> + '_ {
+        self.plugins.iter().copied()
+    }
+}
@@ -0,0 +1,45 @@
+use std::alloc::Layout;
+use std::borrow::Cow;
+
+/// Descriptor of the dynamic component type defined at runtime.
+///
+/// Unlike [components](super::Component) known at compile time,
+/// data of dynamic components is stored as raw bytes,
+/// so descriptor only defines name and [layout](Layout) of the component type.
+///
+/// # Examples
+///
+/// ```
+/// use std::alloc::Layout;
+/// use toucan_ecs::component::ComponentDescriptor;
+///
+/// let descriptor = ComponentDescriptor::new("Health", Layout::new::<u32>());
+/// assert_eq!(descriptor.name(), "Health");
+/// assert_eq!(descriptor.layout().size(), 4);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ComponentDescriptor {
+    name: Cow<'static, str>,
+    layout: Layout,
+}
+
+impl ComponentDescriptor {
+    /// Creates new descriptor of the dynamic component type.
+    pub fn new<N>(name: N, layout: Layout) -> Self
+    where
+        N: Into<Cow<'static, str>>,
+    {
+        let name = name.into();
+        Self { name, layout }
+    }
+
+    /// Returns name of the dynamic component type.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns layout of the dynamic component type.
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+}
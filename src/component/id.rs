@@ -0,0 +1,81 @@
+use std::any::TypeId;
+
+use super::{Component, ComponentTypeId};
+
+/// Runtime identifier of the component type.
+///
+/// Identifier can be obtained either for the [component](Component) type known at compile time
+/// by [`ComponentId::of`] function or for the dynamic component type defined at runtime by
+/// [`World::register_dynamic`](crate::world::World::register_dynamic) function.
+///
+/// Identifiers of dynamic components are unique only inside of the world which registered them.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub struct ComponentId(Repr);
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+enum Repr {
+    Static(ComponentTypeId),
+    Dynamic(usize),
+}
+
+impl ComponentId {
+    /// Returns identifier of the component type known at compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// use toucan_ecs::component::ComponentId;
+    ///
+    /// #[derive(Copy, Clone, Component)]
+    /// struct Name(&'static str);
+    ///
+    /// let id = ComponentId::of::<Name>();
+    /// assert!(!id.is_dynamic());
+    /// assert_eq!(id.type_id(), Some(std::any::TypeId::of::<Name>()));
+    /// ```
+    pub fn of<C>() -> Self
+    where
+        C: Component,
+    {
+        Self(Repr::Static(ComponentTypeId::of::<C>()))
+    }
+
+    /// Returns `true` if this identifier belongs to the dynamic component type.
+    pub fn is_dynamic(&self) -> bool {
+        matches!(self.0, Repr::Dynamic(_))
+    }
+
+    /// Returns [type identifier](TypeId) of the component type known at compile time
+    /// or [`None`](Option::None) if this identifier belongs to the dynamic component type.
+    pub fn type_id(&self) -> Option<TypeId> {
+        match self.0 {
+            Repr::Static(type_id) => Some(type_id.into()),
+            Repr::Dynamic(_) => None,
+        }
+    }
+
+    pub(crate) fn dynamic(index: usize) -> Self {
+        Self(Repr::Dynamic(index))
+    }
+
+    pub(crate) fn as_static(&self) -> Option<ComponentTypeId> {
+        match self.0 {
+            Repr::Static(type_id) => Some(type_id),
+            Repr::Dynamic(_) => None,
+        }
+    }
+
+    pub(crate) fn as_dynamic(&self) -> Option<usize> {
+        match self.0 {
+            Repr::Static(_) => None,
+            Repr::Dynamic(index) => Some(index),
+        }
+    }
+}
+
+impl From<ComponentTypeId> for ComponentId {
+    fn from(type_id: ComponentTypeId) -> Self {
+        Self(Repr::Static(type_id))
+    }
+}
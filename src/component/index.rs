@@ -0,0 +1,195 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::hash::{BuildHasherDefault, Hash};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{PoisonError, RwLock};
+
+use slotmap::SecondaryMap;
+
+use crate::component::storage::Storage;
+use crate::component::{Component, ComponentTypeId};
+use crate::entity::Entity;
+use crate::hash::TypeIdHasher;
+
+/// Type which can be used as a key of the [component index](crate::world::World::index_by).
+///
+/// This trait is implemented for all types which satisfy its bounds.
+pub trait IndexKey: Hash + Eq + Clone + Send + Sync + 'static {}
+
+impl<K> IndexKey for K where K: Hash + Eq + Clone + Send + Sync + 'static {}
+
+pub type Indices = HashMap<ComponentTypeId, Box<dyn ErasedIndex>, BuildHasherDefault<TypeIdHasher>>;
+
+pub trait ErasedIndex: Send + Sync + 'static {
+    fn touch(&mut self, entity: Entity);
+
+    fn touch_shared(&self, entity: Entity);
+
+    fn mark_dirty(&self);
+
+    fn as_any(&self) -> &dyn Any;
+}
+
+#[derive(Copy, Clone, Default)]
+pub struct Touch<'data>(Option<&'data dyn ErasedIndex>);
+
+impl<'data> Touch<'data> {
+    pub fn new(indices: Option<&'data Indices>, type_id: ComponentTypeId) -> Self {
+        let index = indices.and_then(|indices| indices.get(&type_id));
+        Self(index.map(AsRef::as_ref))
+    }
+
+    pub fn touch(self, entity: Entity) {
+        if let Some(index) = self.0 {
+            index.touch_shared(entity)
+        }
+    }
+}
+
+pub struct Index<C, K>
+where
+    C: Component,
+    K: IndexKey,
+{
+    key_fn: fn(&C) -> K,
+    state: RwLock<State<K>>,
+    dirty: AtomicBool,
+}
+
+struct State<K>
+where
+    K: IndexKey,
+{
+    entities: HashMap<K, Vec<Entity>>,
+    keys: SecondaryMap<Entity, K>,
+    pending: Vec<Entity>,
+}
+
+impl<C, K> Index<C, K>
+where
+    C: Component,
+    K: IndexKey,
+{
+    pub fn new(key_fn: fn(&C) -> K) -> Self {
+        let state = State {
+            entities: HashMap::new(),
+            keys: SecondaryMap::new(),
+            pending: Vec::new(),
+        };
+        Self {
+            key_fn,
+            state: RwLock::new(state),
+            // components attached before the index was created must be indexed too
+            dirty: AtomicBool::new(true),
+        }
+    }
+
+    pub fn lookup(&self, storage: Option<&C::Storage>, key: &K) -> Vec<Entity> {
+        let mut state = self.state.write().unwrap_or_else(PoisonError::into_inner);
+        if self.dirty.swap(false, Ordering::Relaxed) {
+            state.rebuild(storage, self.key_fn);
+        } else {
+            state.sync_pending(storage, self.key_fn);
+        }
+        state.entities.get(key).cloned().unwrap_or_default()
+    }
+}
+
+impl<C, K> ErasedIndex for Index<C, K>
+where
+    C: Component,
+    K: IndexKey,
+{
+    fn touch(&mut self, entity: Entity) {
+        if *self.dirty.get_mut() {
+            return;
+        }
+        let state = self.state.get_mut().unwrap_or_else(PoisonError::into_inner);
+        if !state.push_pending(entity) {
+            *self.dirty.get_mut() = true;
+        }
+    }
+
+    fn touch_shared(&self, entity: Entity) {
+        if self.dirty.load(Ordering::Relaxed) {
+            return;
+        }
+        let mut state = self.state.write().unwrap_or_else(PoisonError::into_inner);
+        if !state.push_pending(entity) {
+            self.dirty.store(true, Ordering::Relaxed);
+        }
+    }
+
+    fn mark_dirty(&self) {
+        self.dirty.store(true, Ordering::Relaxed);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl<K> State<K>
+where
+    K: IndexKey,
+{
+    fn rebuild<S>(&mut self, storage: Option<&S>, key_fn: fn(&S::Item) -> K)
+    where
+        S: Storage,
+    {
+        self.entities.clear();
+        self.keys.clear();
+        self.pending.clear();
+        let storage = match storage {
+            Some(storage) => storage,
+            None => return,
+        };
+        for (entity, component) in storage.iter() {
+            self.insert(entity, key_fn(component));
+        }
+    }
+
+    fn push_pending(&mut self, entity: Entity) -> bool {
+        // rebuilding is cheaper than syncing more entities than there are in the index
+        if self.pending.len() > self.keys.len() {
+            self.pending.clear();
+            return false;
+        }
+        self.pending.push(entity);
+        true
+    }
+
+    fn sync_pending<S>(&mut self, storage: Option<&S>, key_fn: fn(&S::Item) -> K)
+    where
+        S: Storage,
+    {
+        let pending = std::mem::take(&mut self.pending);
+        for &entity in &pending {
+            self.remove(entity);
+            let component = storage.and_then(|storage| storage.get(entity));
+            if let Some(component) = component {
+                self.insert(entity, key_fn(component));
+            }
+        }
+        self.pending = pending;
+        self.pending.clear();
+    }
+
+    fn insert(&mut self, entity: Entity, key: K) {
+        self.entities.entry(key.clone()).or_default().push(entity);
+        self.keys.insert(entity, key);
+    }
+
+    fn remove(&mut self, entity: Entity) {
+        let key = match self.keys.remove(entity) {
+            Some(key) => key,
+            None => return,
+        };
+        if let Some(entities) = self.entities.get_mut(&key) {
+            entities.retain(|&other| other != entity);
+            if entities.is_empty() {
+                self.entities.remove(&key);
+            }
+        }
+    }
+}
@@ -1,7 +1,13 @@
 //! Provides trait for components and borrow types for them.
 
+pub use descriptor::ComponentDescriptor;
+pub use id::ComponentId;
+pub use index::IndexKey;
+pub(crate) use index::Touch;
 pub(crate) use registry::Registry;
 pub(crate) use registry_refs::RegistryRefs;
+pub(crate) use removed::Reader as RemovalReader;
+pub use removed::RemovedComponents;
 pub(crate) use set::ComponentSet;
 /// Derive macro that implements [`Component`] trait.
 ///
@@ -51,8 +57,12 @@ pub(crate) use set::ComponentSet;
 pub use toucan_ecs_macro::Component;
 pub(crate) use type_id::ComponentTypeId;
 
+mod descriptor;
+mod id;
+mod index;
 mod registry;
 mod registry_refs;
+mod removed;
 mod set;
 mod type_id;
 
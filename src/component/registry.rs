@@ -1,22 +1,130 @@
-use std::collections::HashMap;
+use std::collections::{hash_map, HashMap};
 use std::hash::BuildHasherDefault;
 
-use crate::component::storage::{ErasedStorageHolder, Storage};
-use crate::component::{Component, ComponentSet, ComponentTypeId};
-use crate::entity::Entity;
+use crate::component::index::{Index, IndexKey, Indices, Touch};
+use crate::component::removed::Removed;
+use crate::component::storage::{DynamicStorage, ErasedStorageHolder, Storage};
+use crate::component::{
+    Component, ComponentDescriptor, ComponentId, ComponentSet, ComponentTypeId, RemovalReader,
+};
+use crate::entity::{Entity, EntityMap, MapEntities, Parent};
 use crate::hash::TypeIdHasher;
+use crate::world::ComponentInfo;
+
+type StorageIter<'data> = hash_map::Iter<'data, ComponentTypeId, ErasedStorageHolder>;
+type StorageIterMut<'data> = hash_map::IterMut<'data, ComponentTypeId, ErasedStorageHolder>;
+type MapEntitiesFn = fn(&mut Registry, &[Entity], &EntityMap);
 
-#[derive(Default)]
-#[repr(transparent)]
 pub struct Registry {
     storages: HashMap<ComponentTypeId, ErasedStorageHolder, BuildHasherDefault<TypeIdHasher>>,
+    dynamic: Vec<DynamicStorage>,
+    mappers: HashMap<ComponentTypeId, MapEntitiesFn, BuildHasherDefault<TypeIdHasher>>,
+    indices: Indices,
+    removed: Removed,
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        let mut registry = Self {
+            storages: HashMap::default(),
+            dynamic: Vec::default(),
+            mappers: HashMap::default(),
+            indices: Indices::default(),
+            removed: Removed::default(),
+        };
+        registry.register_map_entities::<Parent>();
+        registry
+    }
 }
 
 impl Registry {
     pub fn clear(&mut self) {
-        self.storages
-            .values_mut()
-            .for_each(ErasedStorageHolder::clear);
+        for (&type_id, storage) in &mut self.storages {
+            for entity in storage.entities() {
+                self.removed.record(type_id, entity);
+            }
+            storage.clear();
+        }
+        self.dynamic.iter_mut().for_each(DynamicStorage::clear);
+        self.mark_indices_dirty();
+    }
+
+    pub fn snapshot(&self) -> Self {
+        let storages = self
+            .storages
+            .iter()
+            .map(|(&type_id, storage)| (type_id, storage.snapshot()))
+            .collect();
+        Self {
+            storages,
+            dynamic: self.dynamic.clone(),
+            mappers: self.mappers.clone(),
+            indices: Indices::default(),
+            removed: Removed::default(),
+        }
+    }
+
+    pub fn restore(&mut self, snapshot: &Registry) {
+        for (&type_id, storage) in &mut self.storages {
+            let restored = snapshot.storages.get(&type_id);
+            for entity in storage.entities() {
+                if !restored.is_some_and(|restored| restored.attached(entity)) {
+                    self.removed.record(type_id, entity);
+                }
+            }
+            if restored.is_none() {
+                storage.clear();
+            }
+        }
+        for (&type_id, storage) in &snapshot.storages {
+            self.storages.insert(type_id, storage.snapshot());
+        }
+        for (index, storage) in self.dynamic.iter_mut().enumerate() {
+            match snapshot.dynamic.get(index) {
+                Some(snapshot) => storage.clone_from(snapshot),
+                None => storage.clear(),
+            }
+        }
+        let restored = self.dynamic.len();
+        if let Some(rest) = snapshot.dynamic.get(restored..) {
+            self.dynamic.extend_from_slice(rest);
+        }
+        self.mark_indices_dirty();
+    }
+
+    pub fn register_dynamic(&mut self, descriptor: ComponentDescriptor) -> ComponentId {
+        let id = ComponentId::dynamic(self.dynamic.len());
+        let storage = DynamicStorage::new(id, descriptor);
+        self.dynamic.push(storage);
+        id
+    }
+
+    fn dynamic_storage_by_descriptor(
+        &mut self,
+        descriptor: &ComponentDescriptor,
+    ) -> &mut DynamicStorage {
+        let index = self
+            .dynamic
+            .iter()
+            .position(|storage| storage.descriptor() == descriptor);
+        let index = match index {
+            Some(index) => index,
+            None => {
+                self.register_dynamic(descriptor.clone());
+                self.dynamic.len() - 1
+            }
+        };
+        &mut self.dynamic[index]
+    }
+
+    pub fn get_dynamic_storage(&self, id: ComponentId) -> Option<&DynamicStorage> {
+        let index = id.as_dynamic()?;
+        self.dynamic.get(index)
+    }
+
+    pub fn get_dynamic_storage_mut(&mut self, id: ComponentId) -> Option<&mut DynamicStorage> {
+        let index = id.as_dynamic()?;
+        self.dynamic.get_mut(index)
     }
 
     pub fn register<C>(&mut self)
@@ -35,8 +143,9 @@ impl Registry {
         C: Component,
     {
         self.register::<C>();
-        let storage = self.get_storage_mut::<C>().unwrap();
+        let storage = self.storage_mut::<C>().unwrap();
         storage.attach(entity, component);
+        self.touch::<C>(entity);
     }
 
     pub fn attach<S>(&mut self, entity: Entity, set: S)
@@ -67,16 +176,20 @@ impl Registry {
         self.storages
             .values()
             .all(|storage| !storage.attached(entity))
+            && self.dynamic.iter().all(|storage| !storage.attached(entity))
     }
 
     pub(super) fn remove_one<C>(&mut self, entity: Entity)
     where
         C: Component,
     {
-        let storage = self.get_storage_mut::<C>();
-        if let Some(storage) = storage {
-            storage.remove(entity)
-        }
+        let storage = match self.storage_mut::<C>() {
+            Some(storage) if storage.attached(entity) => storage,
+            _ => return,
+        };
+        storage.remove(entity);
+        self.touch::<C>(entity);
+        self.removed.record(ComponentTypeId::of::<C>(), entity);
     }
 
     pub fn remove<S>(&mut self, entity: Entity)
@@ -87,9 +200,135 @@ impl Registry {
     }
 
     pub fn remove_all(&mut self, entity: Entity) {
+        for (&type_id, storage) in &mut self.storages {
+            if storage.attached(entity) {
+                storage.remove(entity);
+                self.removed.record(type_id, entity);
+            }
+        }
+        self.dynamic
+            .iter_mut()
+            .for_each(|storage| storage.remove(entity));
+        self.touch_all(entity);
+    }
+
+    pub fn retain<S>(&mut self, entity: Entity)
+    where
+        S: ComponentSet,
+    {
+        for (&type_id, storage) in &mut self.storages {
+            if !S::contains_type(type_id) && storage.attached(entity) {
+                storage.remove(entity);
+                self.removed.record(type_id, entity);
+            }
+        }
+        self.dynamic
+            .iter_mut()
+            .for_each(|storage| storage.remove(entity));
+        self.touch_all(entity);
+    }
+
+    pub(super) fn copy_one<C>(&mut self, from: Entity, to: Entity)
+    where
+        C: Component,
+    {
+        let component = self.get::<C>(from).copied();
+        if let Some(component) = component {
+            self.attach_one(to, component)
+        }
+    }
+
+    pub fn copy<S>(&mut self, from: Entity, to: Entity)
+    where
+        S: ComponentSet,
+    {
+        S::copy(self, from, to)
+    }
+
+    pub fn copy_all(&mut self, from: Entity, to: Entity) {
         self.storages
             .values_mut()
-            .for_each(|storage| storage.remove(entity))
+            .for_each(|storage| storage.copy(from, to));
+        self.dynamic
+            .iter_mut()
+            .for_each(|storage| storage.copy(from, to));
+        self.touch_all(to);
+    }
+
+    pub fn transfer(&mut self, from: Entity, other: &mut Registry, to: Entity) {
+        for (&type_id, storage) in &mut self.storages {
+            if storage.attached(from) {
+                storage.transfer(from, other, to);
+                self.removed.record(type_id, from);
+            }
+        }
+        // identifiers of dynamic components are valid only in their world,
+        // so dynamic components are matched by their descriptors
+        for storage in &mut self.dynamic {
+            if let Some(component) = storage.get(from) {
+                other
+                    .dynamic_storage_by_descriptor(storage.descriptor())
+                    .attach(to, component);
+                storage.remove(from);
+            }
+        }
+        self.touch_all(from);
+        other.touch_all(to);
+    }
+
+    pub fn rekey(&mut self, from: Entity, to: Entity) {
+        for (&type_id, storage) in &mut self.storages {
+            if storage.attached(from) {
+                storage.rekey(from, to);
+                self.removed.record(type_id, from);
+            }
+        }
+        self.dynamic
+            .iter_mut()
+            .for_each(|storage| storage.rekey(from, to));
+        self.touch_all(from);
+        self.touch_all(to);
+    }
+
+    pub fn register_map_entities<C>(&mut self)
+    where
+        C: Component + MapEntities,
+    {
+        let type_id = ComponentTypeId::of::<C>();
+        self.mappers.insert(type_id, Self::map_entities_one::<C>);
+    }
+
+    fn map_entities_one<C>(&mut self, entities: &[Entity], map: &EntityMap)
+    where
+        C: Component + MapEntities,
+    {
+        let storage = match self.storage_mut::<C>() {
+            Some(storage) => storage,
+            None => return,
+        };
+        for &entity in entities {
+            if let Some(component) = storage.get_mut(entity) {
+                component.map_entities(map)
+            }
+        }
+        for &entity in entities {
+            self.touch::<C>(entity);
+        }
+    }
+
+    pub fn map_entities(&mut self, entities: &[Entity], map: &EntityMap) {
+        let mappers = self.mappers.values().copied().collect::<Vec<_>>();
+        mappers
+            .into_iter()
+            .for_each(|mapper| mapper(self, entities, map));
+    }
+
+    pub fn map_entities_with(&mut self, other: &Registry, entities: &[Entity], map: &EntityMap) {
+        let mut mappers = self.mappers.clone();
+        mappers.extend(other.mappers.iter());
+        mappers
+            .into_values()
+            .for_each(|mapper| mapper(self, entities, map));
     }
 
     pub fn get<C>(&self, entity: Entity) -> Option<&C>
@@ -104,7 +343,8 @@ impl Registry {
     where
         C: Component,
     {
-        let storage = self.get_storage_mut::<C>()?;
+        self.touch::<C>(entity);
+        let storage = self.storage_mut::<C>()?;
         storage.get_mut(entity)
     }
 
@@ -118,7 +358,18 @@ impl Registry {
         Some(storage)
     }
 
-    pub fn get_storage_mut<C>(&mut self) -> Option<&mut C::Storage>
+    pub fn get_storage_mut<C>(&mut self) -> (Option<&mut C::Storage>, Touch<'_>)
+    where
+        C: Component,
+    {
+        let type_id = ComponentTypeId::of::<C>();
+        let touch = Touch::new(Some(&self.indices), type_id);
+        let storage = self.storages.get_mut(&type_id);
+        let storage = storage.map(|storage| storage.as_storage_mut().expect("downcast error"));
+        (storage, touch)
+    }
+
+    fn storage_mut<C>(&mut self) -> Option<&mut C::Storage>
     where
         C: Component,
     {
@@ -147,15 +398,99 @@ impl Registry {
         self.storages.insert(type_id, erased);
     }
 
-    pub(super) fn iter(
-        &self,
-    ) -> impl Iterator<Item = (&ComponentTypeId, &ErasedStorageHolder)> + '_ {
-        self.storages.iter()
+    pub fn infos(&self) -> impl Iterator<Item = ComponentInfo> + '_ {
+        let storages = self.storages.values().map(ErasedStorageHolder::info);
+        let dynamic = self.dynamic.iter().map(DynamicStorage::info);
+        storages.chain(dynamic)
+    }
+
+    pub(super) fn iter(&self) -> (StorageIter<'_>, std::slice::Iter<'_, DynamicStorage>) {
+        (self.storages.iter(), self.dynamic.iter())
     }
 
     pub(super) fn iter_mut(
         &mut self,
-    ) -> impl Iterator<Item = (&ComponentTypeId, &mut ErasedStorageHolder)> + '_ {
-        self.storages.iter_mut()
+    ) -> (
+        StorageIterMut<'_>,
+        std::slice::IterMut<'_, DynamicStorage>,
+        &Indices,
+        &Removed,
+    ) {
+        (
+            self.storages.iter_mut(),
+            self.dynamic.iter_mut(),
+            &self.indices,
+            &self.removed,
+        )
+    }
+
+    pub fn removed(&self) -> &Removed {
+        &self.removed
+    }
+
+    pub fn clear_removed(&mut self) {
+        self.removed.clear();
+    }
+
+    pub fn removal_reader(&mut self) -> RemovalReader {
+        self.removed.reader()
+    }
+
+    pub fn prune_removed(&mut self) {
+        self.removed.prune();
+    }
+
+    pub fn index_by<C, K>(&mut self, key_fn: fn(&C) -> K)
+    where
+        C: Component,
+        K: IndexKey,
+    {
+        let type_id = ComponentTypeId::of::<C>();
+        let index = Index::new(key_fn);
+        self.indices.insert(type_id, Box::new(index));
+    }
+
+    pub fn lookup<C, K>(&self, key: &K) -> Vec<Entity>
+    where
+        C: Component,
+        K: IndexKey,
+    {
+        let type_id = ComponentTypeId::of::<C>();
+        let index = self.indices.get(&type_id).unwrap_or_else(|| {
+            let type_name = std::any::type_name::<C>();
+            panic!("component {} is not indexed", type_name)
+        });
+        let index = index
+            .as_any()
+            .downcast_ref::<Index<C, K>>()
+            .unwrap_or_else(|| {
+                let type_name = std::any::type_name::<C>();
+                let key_name = std::any::type_name::<K>();
+                panic!("component {} is not indexed by {}", type_name, key_name)
+            });
+        index.lookup(self.get_storage::<C>(), key)
+    }
+
+    fn touch<C>(&mut self, entity: Entity)
+    where
+        C: Component,
+    {
+        if self.indices.is_empty() {
+            return;
+        }
+        let type_id = ComponentTypeId::of::<C>();
+        if let Some(index) = self.indices.get_mut(&type_id) {
+            index.touch(entity);
+        }
+    }
+
+    fn touch_all(&mut self, entity: Entity) {
+        self.indices
+            .values_mut()
+            .for_each(|index| index.touch(entity));
+    }
+
+    fn mark_indices_dirty(&self) {
+        self.indices.values().for_each(|index| index.mark_dirty());
     }
 }
@@ -1,13 +1,15 @@
+use std::collections::hash_map::RandomState;
 use std::hash::BuildHasherDefault;
 
 use hashbrown::HashMap;
 use ref_kind::{Many, RefKind};
 
-use crate::component::storage::ErasedStorageHolder;
-use crate::component::{Component, ComponentTypeId, Registry};
+use crate::component::index::{Indices, Touch};
+use crate::component::removed::{Removed, RemovedComponents};
+use crate::component::storage::{DynamicStorage, ErasedStorageHolder};
+use crate::component::{Component, ComponentId, ComponentTypeId, Registry};
 use crate::hash::TypeIdHasher;
 
-#[repr(transparent)]
 #[derive(Default)]
 pub struct RegistryRefs<'data> {
     refs: HashMap<
@@ -15,25 +17,49 @@ pub struct RegistryRefs<'data> {
         Option<RefKind<'data, ErasedStorageHolder>>,
         BuildHasherDefault<TypeIdHasher>,
     >,
+    dynamic: HashMap<usize, Option<RefKind<'data, DynamicStorage>>, RandomState>,
+    indices: Option<&'data Indices>,
+    removed: Option<&'data Removed>,
+    last_removal: u64,
 }
 
 impl<'data> From<&'data Registry> for RegistryRefs<'data> {
     fn from(registry: &'data Registry) -> Self {
-        let refs = registry
-            .iter()
+        let (storages, dynamic) = registry.iter();
+        let refs = storages
             .map(|(&type_id, erased)| (type_id, Some(RefKind::Ref(erased))))
             .collect();
-        Self { refs }
+        let dynamic = dynamic
+            .enumerate()
+            .map(|(index, storage)| (index, Some(RefKind::Ref(storage))))
+            .collect();
+        Self {
+            refs,
+            dynamic,
+            indices: None,
+            removed: Some(registry.removed()),
+            last_removal: 0,
+        }
     }
 }
 
 impl<'data> From<&'data mut Registry> for RegistryRefs<'data> {
     fn from(registry: &'data mut Registry) -> Self {
-        let refs = registry
-            .iter_mut()
+        let (storages, dynamic, indices, removed) = registry.iter_mut();
+        let refs = storages
             .map(|(&type_id, erased)| (type_id, Some(RefKind::Mut(erased))))
             .collect();
-        Self { refs }
+        let dynamic = dynamic
+            .enumerate()
+            .map(|(index, storage)| (index, Some(RefKind::Mut(storage))))
+            .collect();
+        Self {
+            refs,
+            dynamic,
+            indices: Some(indices),
+            removed: Some(removed),
+            last_removal: 0,
+        }
     }
 }
 
@@ -58,13 +84,49 @@ impl<'data> RegistryRefs<'data> {
         Some(storage)
     }
 
-    pub fn move_mut<C>(&mut self) -> Option<&'data mut C::Storage>
+    pub fn move_mut<C>(&mut self) -> Option<(&'data mut C::Storage, Touch<'data>)>
     where
         C: Component,
     {
         let type_id = ComponentTypeId::of::<C>();
-        let erased = self.refs.move_mut(type_id)?;
+        let (erased, touch) = self.move_erased_mut(type_id)?;
         let storage = erased.as_storage_mut().expect("downcast error");
-        Some(storage)
+        Some((storage, touch))
+    }
+
+    pub fn move_erased_ref(
+        &mut self,
+        type_id: ComponentTypeId,
+    ) -> Option<&'data ErasedStorageHolder> {
+        self.refs.move_ref(type_id)
+    }
+
+    pub fn move_erased_mut(
+        &mut self,
+        type_id: ComponentTypeId,
+    ) -> Option<(&'data mut ErasedStorageHolder, Touch<'data>)> {
+        let erased = self.refs.move_mut(type_id)?;
+        Some((erased, Touch::new(self.indices, type_id)))
+    }
+
+    pub fn set_last_removal(&mut self, last_removal: u64) -> u64 {
+        self.last_removal = last_removal;
+        self.removed.map_or(last_removal, Removed::counter)
+    }
+
+    pub fn removed<C>(&self) -> Option<RemovedComponents<'data, C>>
+    where
+        C: Component,
+    {
+        let removed = self.removed?;
+        Some(removed.since(self.last_removal))
+    }
+
+    pub fn move_dynamic_ref(&mut self, id: ComponentId) -> Option<&'data DynamicStorage> {
+        self.dynamic.move_ref(id.as_dynamic()?)
+    }
+
+    pub fn move_dynamic_mut(&mut self, id: ComponentId) -> Option<&'data mut DynamicStorage> {
+        self.dynamic.move_mut(id.as_dynamic()?)
     }
 }
@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+use std::hash::BuildHasherDefault;
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::slice;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Weak};
+
+use crate::component::{Component, ComponentTypeId};
+use crate::entity::Entity;
+use crate::hash::TypeIdHasher;
+
+type Removals = Vec<(u64, Entity)>;
+
+#[derive(Default)]
+pub struct Removed {
+    removals: HashMap<ComponentTypeId, Removals, BuildHasherDefault<TypeIdHasher>>,
+    counter: u64,
+    retained: u64,
+    readers: Vec<Weak<AtomicU64>>,
+}
+
+pub struct Reader(Arc<AtomicU64>);
+
+impl Reader {
+    pub fn set_last_seen(&self, last_seen: u64) {
+        self.0.store(last_seen, Ordering::Relaxed);
+    }
+}
+
+impl Removed {
+    pub fn record(&mut self, type_id: ComponentTypeId, entity: Entity) {
+        let id = self.counter;
+        self.counter += 1;
+        self.removals.entry(type_id).or_default().push((id, entity));
+    }
+
+    pub fn counter(&self) -> u64 {
+        self.counter
+    }
+
+    pub fn reader(&mut self) -> Reader {
+        let last_seen = Arc::new(AtomicU64::new(self.counter));
+        self.readers.push(Arc::downgrade(&last_seen));
+        Reader(last_seen)
+    }
+
+    pub fn since<C>(&self, last_seen: u64) -> RemovedComponents<'_, C>
+    where
+        C: Component,
+    {
+        let type_id = ComponentTypeId::of::<C>();
+        let removals = match self.removals.get(&type_id) {
+            Some(removals) => removals.as_slice(),
+            None => &[],
+        };
+        // ids are pushed in increasing order, so the removals are sorted by them
+        let start = removals.partition_point(|&(id, _)| id < last_seen);
+        RemovedComponents::new(&removals[start..])
+    }
+
+    pub fn clear(&mut self) {
+        self.drop_before(self.retained);
+        self.retained = self.counter;
+    }
+
+    pub fn prune(&mut self) {
+        // readers are released when their systems are dropped
+        self.readers.retain(|reader| reader.strong_count() > 0);
+        let seen = self
+            .readers
+            .iter()
+            .filter_map(Weak::upgrade)
+            .map(|reader| reader.load(Ordering::Relaxed))
+            .min()
+            .unwrap_or(self.counter);
+        self.drop_before(seen);
+    }
+
+    fn drop_before(&mut self, id: u64) {
+        self.removals.retain(|_, removals| {
+            removals.retain(|&(removal, _)| removal >= id);
+            !removals.is_empty()
+        });
+    }
+}
+
+/// Iterator which returns [entities](Entity) the component of generic type
+/// was removed from.
+///
+/// Components are considered removed when they are [removed](crate::world::World::remove)
+/// from the entity or when the entity is [destroyed](crate::world::World::destroy).
+///
+/// When used as a [system](crate::system::System) argument,
+/// it returns entities which lost the component since the last run of this system.
+/// On the first run of the system, removals which happened before are not returned.
+/// Removals are kept until every system which reads them has seen them,
+/// so systems of different schedules or schedules which run several times per frame
+/// do not miss any of them. A system which does not run holds removals back
+/// until it is run or its schedule is dropped.
+///
+/// # Examples
+///
+/// ```
+/// # use toucan_ecs::prelude::*;
+/// #[derive(Copy, Clone, Component)]
+/// struct Body(u32);
+///
+/// fn despawn_bodies(removed: RemovedComponents<Body>) {
+///     for entity in removed {
+///         println!("remove body of {:?} from the physics engine", entity);
+///     }
+/// }
+///
+/// let mut world = World::new();
+/// let entity = world.create_with(Body(0));
+///
+/// let mut schedule = Schedule::builder().system(despawn_bodies).build();
+/// schedule.run(&mut world);
+/// world.destroy(entity);
+/// schedule.run(&mut world);
+/// ```
+pub struct RemovedComponents<'data, C>
+where
+    C: Component,
+{
+    iter: slice::Iter<'data, (u64, Entity)>,
+    _ph: PhantomData<C>,
+}
+
+impl<'data, C> RemovedComponents<'data, C>
+where
+    C: Component,
+{
+    fn new(removals: &'data [(u64, Entity)]) -> Self {
+        Self {
+            iter: removals.iter(),
+            _ph: PhantomData,
+        }
+    }
+}
+
+impl<'data, C> Clone for RemovedComponents<'data, C>
+where
+    C: Component,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            _ph: PhantomData,
+        }
+    }
+}
+
+impl<'data, C> Iterator for RemovedComponents<'data, C>
+where
+    C: Component,
+{
+    type Item = Entity;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|&(_, entity)| entity)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'data, C> FusedIterator for RemovedComponents<'data, C> where C: Component {}
+
+impl<'data, C> ExactSizeIterator for RemovedComponents<'data, C> where C: Component {}
@@ -1,4 +1,4 @@
-use crate::component::{Component, Registry};
+use crate::component::{Component, ComponentTypeId, Registry};
 use crate::entity::Entity;
 
 mod tuple;
@@ -9,6 +9,10 @@ pub trait ComponentSet {
     fn remove(registry: &mut Registry, entity: Entity);
 
     fn attached(registry: &Registry, entity: Entity) -> bool;
+
+    fn copy(registry: &mut Registry, from: Entity, to: Entity);
+
+    fn contains_type(type_id: ComponentTypeId) -> bool;
 }
 
 impl<C> ComponentSet for C
@@ -26,4 +30,12 @@ where
     fn attached(registry: &Registry, entity: Entity) -> bool {
         registry.attached_one::<Self>(entity)
     }
+
+    fn copy(registry: &mut Registry, from: Entity, to: Entity) {
+        registry.copy_one::<Self>(from, to)
+    }
+
+    fn contains_type(type_id: ComponentTypeId) -> bool {
+        type_id == ComponentTypeId::of::<Self>()
+    }
 }
@@ -19,6 +19,14 @@ macro_rules! component_set {
             fn attached(registry: &Registry, entity: Entity) -> bool {
                 $($types::attached(registry, entity))&&*
             }
+
+            fn copy(registry: &mut Registry, from: Entity, to: Entity) {
+                $($types::copy(registry, from, to);)*
+            }
+
+            fn contains_type(type_id: ComponentTypeId) -> bool {
+                $($types::contains_type(type_id))||*
+            }
         }
     }
 }
@@ -0,0 +1,141 @@
+use std::any::type_name;
+
+use slotmap::SecondaryMap;
+
+use crate::component::{ComponentDescriptor, ComponentId};
+use crate::entity::Entity;
+use crate::world::ComponentInfo;
+
+#[derive(Clone)]
+pub struct DynamicStorage {
+    id: ComponentId,
+    descriptor: ComponentDescriptor,
+    entities: Vec<Entity>,
+    data: Vec<u8>,
+    entity_to_index: SecondaryMap<Entity, usize>,
+}
+
+impl DynamicStorage {
+    pub fn new(id: ComponentId, descriptor: ComponentDescriptor) -> Self {
+        Self {
+            id,
+            descriptor,
+            entities: Vec::new(),
+            data: Vec::new(),
+            entity_to_index: SecondaryMap::new(),
+        }
+    }
+
+    pub fn descriptor(&self) -> &ComponentDescriptor {
+        &self.descriptor
+    }
+
+    pub fn attach(&mut self, entity: Entity, component: &[u8]) {
+        let size = self.size();
+        assert_eq!(
+            component.len(),
+            size,
+            "size of component data does not match layout of {}",
+            self.descriptor.name(),
+        );
+        if let Some(data) = self.get_mut(entity) {
+            data.copy_from_slice(component);
+            return;
+        }
+        let index = self.entities.len();
+        self.entities.push(entity);
+        self.data.extend_from_slice(component);
+        self.entity_to_index.insert(entity, index);
+    }
+
+    pub fn attached(&self, entity: Entity) -> bool {
+        self.entity_to_index.contains_key(entity)
+    }
+
+    pub fn get(&self, entity: Entity) -> Option<&[u8]> {
+        let index = *self.entity_to_index.get(entity)?;
+        let size = self.size();
+        Some(&self.data[index * size..(index + 1) * size])
+    }
+
+    pub fn get_mut(&mut self, entity: Entity) -> Option<&mut [u8]> {
+        let index = *self.entity_to_index.get(entity)?;
+        let size = self.size();
+        Some(&mut self.data[index * size..(index + 1) * size])
+    }
+
+    pub fn copy(&mut self, from: Entity, to: Entity) {
+        if let Some(component) = self.get(from) {
+            let component = component.to_vec();
+            self.attach(to, &component)
+        }
+    }
+
+    pub fn rekey(&mut self, from: Entity, to: Entity) {
+        if let Some(component) = self.get(from) {
+            let component = component.to_vec();
+            self.remove(from);
+            self.attach(to, &component)
+        }
+    }
+
+    pub fn remove(&mut self, entity: Entity) {
+        let index = match self.entity_to_index.remove(entity) {
+            None => return,
+            Some(index) => index,
+        };
+        let size = self.size();
+        let last = self.entities.len() - 1;
+        if index != last {
+            let moved = self.entities[last];
+            self.entities[index] = moved;
+            self.data
+                .copy_within(last * size..(last + 1) * size, index * size);
+            self.entity_to_index.insert(moved, index);
+        }
+        self.entities.pop();
+        self.data.truncate(last * size);
+    }
+
+    pub fn clear(&mut self) {
+        self.entities.clear();
+        self.data.clear();
+        self.entity_to_index.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (Entity, &[u8])> + '_ {
+        let size = self.size();
+        self.entities
+            .iter()
+            .enumerate()
+            .map(move |(index, &entity)| (entity, &self.data[index * size..(index + 1) * size]))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Entity, &mut [u8])> + '_ {
+        let size = self.size();
+        let chunks: Box<dyn Iterator<Item = &mut [u8]>> = match size {
+            0 => Box::new(std::iter::repeat_with(<&mut [u8]>::default)),
+            size => Box::new(self.data.chunks_exact_mut(size)),
+        };
+        self.entities.iter().copied().zip(chunks)
+    }
+
+    pub fn info(&self) -> ComponentInfo {
+        let name = self.descriptor.name().to_owned();
+        ComponentInfo::new(
+            self.id,
+            name.into(),
+            self.size(),
+            type_name::<Self>(),
+            self.len(),
+        )
+    }
+
+    fn size(&self) -> usize {
+        self.descriptor.layout().size()
+    }
+}
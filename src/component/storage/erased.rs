@@ -1,8 +1,12 @@
+use std::any::{type_name, Any};
+use std::mem::size_of;
+
 use as_any::{AsAny, Downcast};
 
 use crate::component::storage::Storage;
-use crate::component::Component;
+use crate::component::{Component, ComponentId, Registry};
 use crate::entity::Entity;
+use crate::world::ComponentInfo;
 
 #[repr(transparent)]
 pub struct ErasedStorageHolder(Box<dyn Holdable>);
@@ -20,6 +24,42 @@ impl ErasedStorageHolder {
         self.0.clear()
     }
 
+    pub fn copy(&mut self, from: Entity, to: Entity) {
+        self.0.copy(from, to)
+    }
+
+    pub fn snapshot(&self) -> Self {
+        self.0.snapshot()
+    }
+
+    pub fn transfer(&mut self, from: Entity, registry: &mut Registry, to: Entity) {
+        self.0.transfer(from, registry, to)
+    }
+
+    pub fn rekey(&mut self, from: Entity, to: Entity) {
+        self.0.rekey(from, to)
+    }
+
+    pub fn info(&self) -> ComponentInfo {
+        self.0.info()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn get_any(&self, entity: Entity) -> Option<&dyn Any> {
+        self.0.get_any(entity)
+    }
+
+    pub fn entities(&self) -> Box<dyn Iterator<Item = Entity> + '_> {
+        self.0.entities()
+    }
+
+    pub fn get_any_mut(&mut self, entity: Entity) -> Option<&mut dyn Any> {
+        self.0.get_any_mut(entity)
+    }
+
     pub fn as_storage_ref<C, S>(&self) -> Option<&S>
     where
         S: Storage<Item = C>,
@@ -52,6 +92,24 @@ pub trait Holdable: AsAny + Send + Sync {
     fn remove(&mut self, entity: Entity);
 
     fn clear(&mut self);
+
+    fn copy(&mut self, from: Entity, to: Entity);
+
+    fn snapshot(&self) -> ErasedStorageHolder;
+
+    fn transfer(&mut self, from: Entity, registry: &mut Registry, to: Entity);
+
+    fn rekey(&mut self, from: Entity, to: Entity);
+
+    fn info(&self) -> ComponentInfo;
+
+    fn len(&self) -> usize;
+
+    fn get_any(&self, entity: Entity) -> Option<&dyn Any>;
+
+    fn entities(&self) -> Box<dyn Iterator<Item = Entity> + '_>;
+
+    fn get_any_mut(&mut self, entity: Entity) -> Option<&mut dyn Any>;
 }
 
 impl<T> Holdable for T
@@ -69,4 +127,57 @@ where
     fn clear(&mut self) {
         self.clear()
     }
+
+    fn copy(&mut self, from: Entity, to: Entity) {
+        if let Some(&component) = Storage::get(self, from) {
+            Storage::attach(self, to, component)
+        }
+    }
+
+    fn snapshot(&self) -> ErasedStorageHolder {
+        Storage::snapshot(self).into()
+    }
+
+    fn transfer(&mut self, from: Entity, registry: &mut Registry, to: Entity) {
+        if let Some(&component) = Storage::get(self, from) {
+            Storage::remove(self, from);
+            registry.attach_one(to, component)
+        }
+    }
+
+    fn rekey(&mut self, from: Entity, to: Entity) {
+        if let Some(&component) = Storage::get(self, from) {
+            Storage::remove(self, from);
+            Storage::attach(self, to, component)
+        }
+    }
+
+    fn info(&self) -> ComponentInfo {
+        ComponentInfo::new(
+            ComponentId::of::<T::Item>(),
+            type_name::<T::Item>().into(),
+            size_of::<T::Item>(),
+            type_name::<T>(),
+            Holdable::len(self),
+        )
+    }
+
+    fn len(&self) -> usize {
+        Storage::iter(self).len()
+    }
+
+    fn get_any(&self, entity: Entity) -> Option<&dyn Any> {
+        let component = Storage::get(self, entity)?;
+        Some(component)
+    }
+
+    fn entities(&self) -> Box<dyn Iterator<Item = Entity> + '_> {
+        let entities = Storage::iter(self).map(|(entity, _)| entity);
+        Box::new(entities)
+    }
+
+    fn get_any_mut(&mut self, entity: Entity) -> Option<&mut dyn Any> {
+        let component = Storage::get_mut(self, entity)?;
+        Some(component)
+    }
 }
@@ -42,8 +42,14 @@ where
     type Item = C;
 
     fn attach(&mut self, entity: Entity, component: Self::Item) {
-        let component = self.components.insert((entity, component));
-        self.entity_to_key.insert(entity, component);
+        let key = self.entity_to_key.get(entity);
+        match key.and_then(|&key| self.components.get_mut(key)) {
+            Some((_, attached)) => *attached = component,
+            None => {
+                let component = self.components.insert((entity, component));
+                self.entity_to_key.insert(entity, component);
+            }
+        }
     }
 
     fn attached(&self, entity: Entity) -> bool {
@@ -74,17 +80,24 @@ where
         self.components.clear();
     }
 
-    fn iter(&self) -> Box<storage::DynIter<Self::Item>> {
+    fn iter(&self) -> Box<storage::DynIter<'_, Self::Item>> {
         let iter = self.components.iter();
         let iter = Iter { iter };
         Box::new(iter)
     }
 
-    fn iter_mut(&mut self) -> Box<storage::DynIterMut<Self::Item>> {
+    fn iter_mut(&mut self) -> Box<storage::DynIterMut<'_, Self::Item>> {
         let iter_mut = self.components.iter_mut();
         let iter_mut = IterMut { iter_mut };
         Box::new(iter_mut)
     }
+
+    fn snapshot(&self) -> Self {
+        Self {
+            components: self.components.clone(),
+            entity_to_key: self.entity_to_key.clone(),
+        }
+    }
 }
 
 pub struct Iter<'data, C>
@@ -1,11 +1,13 @@
 //! Utilities for [storages](Storage) of components in ECS.
 
+pub(crate) use dynamic::DynamicStorage;
 pub(crate) use erased::ErasedStorageHolder;
 pub use impls::DefaultStorage;
 
 use crate::component::Component;
 use crate::entity::Entity;
 
+mod dynamic;
 mod erased;
 mod impls;
 
@@ -89,9 +91,21 @@ pub trait Storage: Default + Send + Sync + 'static {
 
     /// Returns iterator over *immutable* data.
     // fixme move to associated type when GATs are stabilized
-    fn iter(&self) -> Box<DynIter<Self::Item>>;
+    fn iter(&self) -> Box<DynIter<'_, Self::Item>>;
 
     /// Returns iterator over *mutable* data.
     // fixme move to associated type when GATs are stabilized
-    fn iter_mut(&mut self) -> Box<DynIterMut<Self::Item>>;
+    fn iter_mut(&mut self) -> Box<DynIterMut<'_, Self::Item>>;
+
+    /// Creates copy of this storage with the same components attached to the same entities.
+    ///
+    /// Default implementation attaches each component to the new storage one by one.
+    /// Override it if the storage can be copied more efficiently.
+    fn snapshot(&self) -> Self {
+        let mut snapshot = Self::default();
+        for (entity, &component) in self.iter() {
+            snapshot.attach(entity, component);
+        }
+        snapshot
+    }
 }
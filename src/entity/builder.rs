@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 use std::hash::BuildHasherDefault;
 
+use as_any::{AsAny, Downcast};
+
 use crate::component::{Component, ComponentTypeId};
 use crate::entity::Entity;
 use crate::hash::TypeIdHasher;
@@ -49,7 +51,7 @@ impl<'data> EntityBuilder<'data> {
         let entity = self.world.create();
         let world = self.world;
         self.data
-            .into_values()
+            .values()
             .for_each(|holder| holder.attach(entity, world));
         entity
     }
@@ -67,22 +69,40 @@ where
     }
 }
 
+impl Clone for ErasedComponentHolder {
+    fn clone(&self) -> Self {
+        Self(self.0.clone_boxed())
+    }
+}
+
 impl ErasedComponentHolder {
-    pub fn attach(self, entity: Entity, world: &mut World) {
+    pub fn attach(&self, entity: Entity, world: &mut World) {
         self.0.attach(entity, world)
     }
+
+    pub fn as_component_ref<C>(&self) -> Option<&C>
+    where
+        C: Component,
+    {
+        self.0.as_ref().downcast_ref()
+    }
 }
 
-trait Holdable: Send + Sync + 'static {
-    fn attach(self: Box<Self>, entity: Entity, world: &mut World);
+trait Holdable: AsAny + Send + Sync {
+    fn attach(&self, entity: Entity, world: &mut World);
+
+    fn clone_boxed(&self) -> Box<dyn Holdable>;
 }
 
 impl<C> Holdable for C
 where
     C: Component,
 {
-    fn attach(self: Box<Self>, entity: Entity, world: &mut World) {
-        let component = *self;
-        world.attach(entity, component)
+    fn attach(&self, entity: Entity, world: &mut World) {
+        world.attach(entity, *self)
+    }
+
+    fn clone_boxed(&self) -> Box<dyn Holdable> {
+        Box::new(*self)
     }
 }
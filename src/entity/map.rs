@@ -0,0 +1,141 @@
+use slotmap::{secondary, SecondaryMap};
+
+use crate::entity::Entity;
+
+/// Table which maps entity handles of one [world](crate::world::World)
+/// to the entity handles of another world.
+///
+/// It is returned by the functions which transfer entities between worlds,
+/// such as [`World::move_entities_to`](crate::world::World::move_entities_to).
+///
+/// # Examples
+///
+/// ```
+/// # use toucan_ecs::prelude::*;
+/// let mut staging = World::new();
+/// let mut world = World::new();
+///
+/// let entities = staging.extend(3);
+/// let map = staging.move_entities_to(entities.clone(), &mut world);
+///
+/// assert_eq!(map.len(), 3);
+/// for entity in entities {
+///     let moved = map.get(entity).unwrap();
+///     assert!(world.contains(moved));
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct EntityMap(SecondaryMap<Entity, Entity>);
+
+impl EntityMap {
+    /// Creates an empty entity map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts mapping from the old entity handle to the new one.
+    ///
+    /// Returns previous new handle of the old entity, if any.
+    pub fn insert(&mut self, old: Entity, new: Entity) -> Option<Entity> {
+        self.0.insert(old, new)
+    }
+
+    /// Removes mapping of the old entity and returns its new handle, if any.
+    pub fn remove(&mut self, old: Entity) -> Option<Entity> {
+        self.0.remove(old)
+    }
+
+    /// Returns new handle of the old entity, if any.
+    pub fn get(&self, old: Entity) -> Option<Entity> {
+        self.0.get(old).copied()
+    }
+
+    /// Returns new handle of the old entity or the old handle itself
+    /// if the entity is not mapped.
+    pub fn map(&self, old: Entity) -> Entity {
+        self.get(old).unwrap_or(old)
+    }
+
+    /// Returns `true` if the map contains new handle of the old entity.
+    pub fn contains(&self, old: Entity) -> bool {
+        self.0.contains_key(old)
+    }
+
+    /// Returns count of the mapped entities.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the map contains no entities.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an iterator over pairs of old and new entity handles.
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, Entity)> + '_ {
+        self.0.iter().map(|(old, &new)| (old, new))
+    }
+}
+
+impl IntoIterator for EntityMap {
+    type Item = (Entity, Entity);
+    type IntoIter = secondary::IntoIter<Entity, Entity>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl FromIterator<(Entity, Entity)> for EntityMap {
+    fn from_iter<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = (Entity, Entity)>,
+    {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl Extend<(Entity, Entity)> for EntityMap {
+    fn extend<T>(&mut self, iter: T)
+    where
+        T: IntoIterator<Item = (Entity, Entity)>,
+    {
+        self.0.extend(iter)
+    }
+}
+
+/// Trait for components which contain [entity](Entity) handles
+/// that should be fixed up when entities are transferred into another world.
+///
+/// To be fixed up, type of component must be registered
+/// with [`World::register_map_entities`](crate::world::World::register_map_entities).
+/// Components of type [`Parent`](super::Parent) are always fixed up.
+///
+/// # Examples
+///
+/// ```
+/// # use toucan_ecs::prelude::*;
+/// #[derive(Copy, Clone, Component)]
+/// struct Target(Entity);
+///
+/// impl MapEntities for Target {
+///     fn map_entities(&mut self, map: &EntityMap) {
+///         self.0 = map.map(self.0);
+///     }
+/// }
+///
+/// let mut level = World::new();
+/// let enemy = level.create();
+/// let turret = level.create_with(Target(enemy));
+///
+/// let mut world = World::new();
+/// world.register_map_entities::<Target>();
+/// let map = world.merge(level, MergePolicy::Keep).unwrap();
+///
+/// let turret = map.get(turret).unwrap();
+/// assert_eq!(world.get::<Target>(turret).unwrap().0, map.get(enemy).unwrap());
+/// ```
+pub trait MapEntities {
+    /// Replaces all the entity handles stored in this data with the new ones from the map.
+    fn map_entities(&mut self, map: &EntityMap);
+}
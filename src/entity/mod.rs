@@ -1,9 +1,22 @@
 //! Provides entity handle in the world.
 
 pub use builder::EntityBuilder;
+#[cfg(not(feature = "serde"))]
+pub(crate) use builder::ErasedComponentHolder;
+pub use map::{EntityMap, MapEntities};
+pub use names::EntityDebug;
+pub(crate) use names::Names;
+pub use parent::Parent;
+pub use pool::{EntityPool, GenerationPolicy};
+pub use prefab::Prefab;
 pub(crate) use registry::{Iter, Registry};
 
 mod builder;
+mod map;
+mod names;
+mod parent;
+mod pool;
+mod prefab;
 mod registry;
 
 slotmap::new_key_type! {
@@ -13,3 +26,113 @@ slotmap::new_key_type! {
     /// to obtain components attached to the entity.
     pub struct Entity;
 }
+
+impl Entity {
+    /// Converts the entity handle into its raw bit representation.
+    ///
+    /// Lower 32 bits hold [index](Entity::index) of the entity
+    /// and upper 32 bits hold its [generation](Entity::generation).
+    /// This representation is stable and can be sent over the network,
+    /// stored in GPU buffers or passed through FFI.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// let mut world = World::new();
+    /// let entity = world.create();
+    ///
+    /// let bits = entity.to_bits();
+    /// assert_eq!(Entity::from_bits(bits), entity);
+    /// ```
+    pub fn to_bits(self) -> u64 {
+        self.0.as_ffi()
+    }
+
+    /// Creates the entity handle from bits returned by [`Entity::to_bits`].
+    ///
+    /// Handle created from arbitrary bits is safe to use, but it is not validated:
+    /// if its index and generation match some live entity of the world,
+    /// the handle refers to that entity.
+    ///
+    /// Generations of the entity handles are always odd, so the conversion is lossy
+    /// for bits with even generation: it is replaced with the next odd one
+    /// and `Entity::from_bits(bits).to_bits()` is not equal to `bits`.
+    /// Use [`Entity::try_from_bits`] to reject such bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// let mut world = World::new();
+    /// let entity = world.create();
+    ///
+    /// let entity = Entity::from_bits(entity.to_bits());
+    /// assert!(world.contains(entity));
+    /// ```
+    pub fn from_bits(bits: u64) -> Self {
+        slotmap::KeyData::from_ffi(bits).into()
+    }
+
+    /// Creates the entity handle from bits returned by [`Entity::to_bits`].
+    ///
+    /// Returns [`None`](Option::None) if the bits cannot be produced by [`Entity::to_bits`],
+    /// so the conversion back into bits would be lossy.
+    /// As with [`Entity::from_bits`], the returned handle may refer to some live entity of the world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// let mut world = World::new();
+    /// let entity = world.create();
+    ///
+    /// assert_eq!(Entity::try_from_bits(entity.to_bits()), Some(entity));
+    /// assert_eq!(Entity::try_from_bits(2 << 32), None);
+    /// ```
+    pub fn try_from_bits(bits: u64) -> Option<Self> {
+        let entity = Self::from_bits(bits);
+        (entity.to_bits() == bits).then_some(entity)
+    }
+
+    /// Returns index of the entity slot in the world.
+    ///
+    /// Index can be reused by the world after the entity was destroyed,
+    /// use [`World::resolve`](crate::world::World::resolve)
+    /// to look up the live entity at the index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// let mut world = World::new();
+    /// let entity = world.create();
+    ///
+    /// assert_eq!(world.resolve(entity.index()), Some(entity));
+    /// ```
+    pub fn index(self) -> u32 {
+        self.to_bits() as u32
+    }
+
+    /// Returns generation of the entity slot in the world.
+    ///
+    /// Generation is changed each time the slot is reused,
+    /// so entities with the same index can be distinguished.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.create();
+    /// world.destroy(entity);
+    /// let other = world.create();
+    ///
+    /// assert_eq!(other.index(), entity.index());
+    /// assert_ne!(other.generation(), entity.generation());
+    /// ```
+    pub fn generation(self) -> u32 {
+        (self.to_bits() >> 32) as u32
+    }
+}
@@ -0,0 +1,80 @@
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter, Result};
+use std::sync::Arc;
+
+use slotmap::SecondaryMap;
+
+use crate::entity::Entity;
+
+#[derive(Clone, Default)]
+pub struct Names(Option<Box<Inner>>);
+
+#[derive(Clone, Default)]
+struct Inner {
+    names: SecondaryMap<Entity, Arc<str>>,
+    index: HashMap<Arc<str>, Vec<Entity>>,
+}
+
+impl Names {
+    pub fn set(&mut self, entity: Entity, name: &str) {
+        if self.get(entity) == Some(name) {
+            return;
+        }
+        self.remove(entity);
+        let inner = self.0.get_or_insert_with(Default::default);
+        let name: Arc<str> = Arc::from(name);
+        inner.names.insert(entity, Arc::clone(&name));
+        inner.index.entry(name).or_default().push(entity);
+    }
+
+    pub fn get(&self, entity: Entity) -> Option<&str> {
+        self.0.as_ref()?.names.get(entity).map(AsRef::as_ref)
+    }
+
+    pub fn find(&self, name: &str) -> Option<Entity> {
+        self.0.as_ref()?.index.get(name)?.first().copied()
+    }
+
+    pub fn remove(&mut self, entity: Entity) -> Option<String> {
+        let inner = self.0.as_mut()?;
+        let name = inner.names.remove(entity)?;
+        if let Some(entities) = inner.index.get_mut(&name) {
+            entities.retain(|&other| other != entity);
+            if entities.is_empty() {
+                inner.index.remove(&name);
+            }
+        }
+        Some(name.as_ref().to_owned())
+    }
+
+    pub fn clear(&mut self) {
+        self.0 = None;
+    }
+}
+
+/// Wrapper of the entity handle which includes [name](crate::world::World::set_name)
+/// of the entity into its [`Debug`] output.
+///
+/// Returned by [`World::debug_entity`](crate::world::World::debug_entity).
+#[derive(Copy, Clone)]
+pub struct EntityDebug<'world> {
+    entity: Entity,
+    name: Option<&'world str>,
+}
+
+impl<'world> EntityDebug<'world> {
+    pub(crate) fn new(entity: Entity, name: Option<&'world str>) -> Self {
+        Self { entity, name }
+    }
+}
+
+impl<'world> Debug for EntityDebug<'world> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let mut tuple = f.debug_tuple("Entity");
+        tuple.field(&self.entity.0);
+        if let Some(name) = self.name {
+            tuple.field(&name);
+        }
+        tuple.finish()
+    }
+}
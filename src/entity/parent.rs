@@ -0,0 +1,31 @@
+use crate::component::storage::DefaultStorage;
+use crate::component::Component;
+use crate::entity::{Entity, EntityMap, MapEntities};
+
+/// Component which links the entity to its parent entity.
+///
+/// It is attached to the entities spawned from the child [prefabs](super::Prefab),
+/// so the hierarchy of the prefab is preserved in the world.
+///
+/// # Examples
+///
+/// ```
+/// # use toucan_ecs::prelude::*;
+/// let mut world = World::new();
+///
+/// let parent = world.create();
+/// let child = world.create_with(Parent(parent));
+/// assert_eq!(world.get::<Parent>(child), Some(&Parent(parent)));
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Parent(pub Entity);
+
+impl Component for Parent {
+    type Storage = DefaultStorage<Self>;
+}
+
+impl MapEntities for Parent {
+    fn map_entities(&mut self, map: &EntityMap) {
+        self.0 = map.map(self.0);
+    }
+}
@@ -0,0 +1,218 @@
+use slotmap::SecondaryMap;
+
+use crate::component::ComponentSet;
+use crate::entity::Entity;
+use crate::world::World;
+
+/// Policy which defines how the pool treats generation of released entities.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum GenerationPolicy {
+    /// Generation of the released entity is bumped,
+    /// so stale handles of the released entity are no longer valid in the world.
+    ///
+    /// Components of the old handle are tracked as [removed](crate::component::RemovedComponents),
+    /// so systems which mirror entities outside of the world can forget the old handle.
+    #[default]
+    Renew,
+    /// Generation of the released entity is kept,
+    /// so stale handles alias the entity when it is acquired again.
+    ///
+    /// This avoids moving components of the released entity between storage slots,
+    /// but owner of the released entity should drop its handle
+    /// to not mix it up with the next acquired entity.
+    Keep,
+}
+
+/// Pool of pre-allocated entities which can be acquired and released cheaply.
+///
+/// Each entity of the pool is created with the copy of the template set of components.
+/// Released entities are not destroyed: they are [disabled](World::disable)
+/// and keep components of the template attached, so component storages stay allocated.
+/// Components which are not in the template are removed from the entity on release.
+/// Acquired entity is enabled again and its components are reset to the template.
+///
+/// By default generation of the released entity is bumped,
+/// so handle of the released entity is not valid anymore.
+/// See [`GenerationPolicy`] for details.
+///
+/// # Examples
+///
+/// ```
+/// # use toucan_ecs::prelude::*;
+/// #[derive(Copy, Clone, Component, Debug, PartialEq)]
+/// struct Position(f32, f32);
+///
+/// #[derive(Copy, Clone, Component, Debug, PartialEq)]
+/// struct Bullet;
+///
+/// let mut world = World::new();
+/// let mut pool = EntityPool::new(&mut world, 100, (Bullet, Position(0.0, 0.0)));
+/// assert_eq!(world.view_one::<Bullet>().count(), 0);
+///
+/// let bullet = pool.acquire(&mut world);
+/// world.get_mut::<Position>(bullet).unwrap().0 = 10.0;
+/// assert_eq!(world.view_one::<Bullet>().count(), 1);
+///
+/// pool.release(&mut world, bullet);
+/// assert!(!world.contains(bullet));
+/// assert_eq!(world.view_one::<Bullet>().count(), 0);
+/// assert_eq!(pool.available(), 100);
+/// ```
+pub struct EntityPool<S>
+where
+    S: ComponentSet + Copy,
+{
+    template: S,
+    policy: GenerationPolicy,
+    free: Vec<Entity>,
+    entities: SecondaryMap<Entity, bool>,
+}
+
+impl<S> EntityPool<S>
+where
+    S: ComponentSet + Copy,
+{
+    /// Creates new pool with provided count of entities
+    /// which are created in the world with the copy of the template.
+    ///
+    /// Released entities of the pool are renewed with the [default](GenerationPolicy::Renew) policy.
+    pub fn new(world: &mut World, count: u32, template: S) -> Self {
+        Self::with_policy(world, count, template, GenerationPolicy::default())
+    }
+
+    /// Creates new pool with provided count of entities
+    /// and the policy of generation of released entities.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// #[derive(Copy, Clone, Component)]
+    /// struct Bullet;
+    ///
+    /// let mut world = World::new();
+    /// let mut pool = EntityPool::with_policy(&mut world, 1, Bullet, GenerationPolicy::Keep);
+    ///
+    /// let bullet = pool.acquire(&mut world);
+    /// pool.release(&mut world, bullet);
+    /// assert_eq!(pool.acquire(&mut world), bullet);
+    /// ```
+    pub fn with_policy(
+        world: &mut World,
+        count: u32,
+        template: S,
+        policy: GenerationPolicy,
+    ) -> Self {
+        let mut pool = Self {
+            template,
+            policy,
+            free: Vec::new(),
+            entities: SecondaryMap::new(),
+        };
+        pool.grow(world, count);
+        pool
+    }
+
+    /// Creates additional count of entities in the pool.
+    pub fn grow(&mut self, world: &mut World, additional: u32) {
+        self.free.reserve(additional as usize);
+        for _ in 0..additional {
+            let entity = world.create_with(self.template);
+            world.disable(entity);
+            self.entities.insert(entity, false);
+            self.free.push(entity);
+        }
+    }
+
+    /// Acquires free entity of the pool and resets its components to the template.
+    ///
+    /// If there are no free entities, the pool grows by one entity.
+    /// Pooled entities which were destroyed in the world are forgotten by the pool.
+    pub fn acquire(&mut self, world: &mut World) -> Entity {
+        loop {
+            let entity = match self.free.pop() {
+                Some(entity) => entity,
+                None => {
+                    self.grow(world, 1);
+                    continue;
+                }
+            };
+            if !world.contains(entity) {
+                self.entities.remove(entity);
+                continue;
+            }
+            world.attach(entity, self.template);
+            world.enable(entity);
+            self.entities.insert(entity, true);
+            return entity;
+        }
+    }
+
+    /// Releases the acquired entity back to the pool.
+    ///
+    /// Components which are not in the template are removed from the released entity.
+    /// With the [renew](GenerationPolicy::Renew) policy handle of the released entity
+    /// is not valid anymore, and the entity is acquired later with the new generation.
+    ///
+    /// Returns `false` if the entity was not acquired from this pool
+    /// or was destroyed in the world.
+    pub fn release(&mut self, world: &mut World, entity: Entity) -> bool {
+        if !self.is_acquired(entity) {
+            return false;
+        }
+        if !world.contains(entity) {
+            self.entities.remove(entity);
+            return false;
+        }
+        world.retain_components::<S>(entity);
+        world.disable(entity);
+        let entity = match self.policy {
+            GenerationPolicy::Renew => {
+                self.entities.remove(entity);
+                match world.renew(entity) {
+                    Some(renewed) => renewed,
+                    None => return false,
+                }
+            }
+            GenerationPolicy::Keep => entity,
+        };
+        self.entities.insert(entity, false);
+        self.free.push(entity);
+        true
+    }
+
+    /// Returns `true` if the entity belongs to the pool.
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.entities.contains_key(entity)
+    }
+
+    /// Returns `true` if the entity belongs to the pool and it is acquired now.
+    pub fn is_acquired(&self, entity: Entity) -> bool {
+        self.entities.get(entity).copied().unwrap_or(false)
+    }
+
+    /// Returns count of the entities which belong to the pool.
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    /// Returns `true` if the pool has no entities.
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    /// Returns count of the free entities which can be acquired without growing the pool.
+    pub fn available(&self) -> usize {
+        self.free.len()
+    }
+
+    /// Returns the policy of generation of released entities.
+    pub fn policy(&self) -> GenerationPolicy {
+        self.policy
+    }
+
+    /// Returns the template of components which entities of the pool are reset to.
+    pub fn template(&self) -> &S {
+        &self.template
+    }
+}
@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+use std::hash::BuildHasherDefault;
+
+use crate::component::{Component, ComponentTypeId};
+use crate::entity::builder::ErasedComponentHolder;
+use crate::entity::{Entity, Parent};
+use crate::hash::TypeIdHasher;
+use crate::world::World;
+
+/// Reusable template of the entity which can be [spawned](World::spawn_prefab)
+/// in the world many times.
+///
+/// Unlike [entity builder](super::EntityBuilder), prefab does not borrow the world,
+/// so it can be stored and cloned freely.
+/// Prefab can contain child prefabs: each of them will be spawned as separate entity
+/// with [`Parent`] component which refers to the entity spawned from the parent prefab.
+///
+/// # Examples
+///
+/// ```
+/// # use toucan_ecs::prelude::*;
+/// #[derive(Copy, Clone, Component, Debug, PartialEq)]
+/// struct Name(&'static str);
+///
+/// #[derive(Copy, Clone, Component, Debug, PartialEq)]
+/// struct Health(u32);
+///
+/// let weapon = Prefab::new().with(Name("Sword"));
+/// let knight = Prefab::new()
+///     .with(Name("Knight"))
+///     .with(Health(100))
+///     .with_child(weapon);
+///
+/// let mut world = World::new();
+/// let first = world.spawn_prefab(&knight);
+/// let second = world.spawn_prefab_with(&knight, Health(50));
+///
+/// assert_eq!(world.get::<Health>(first), Some(&Health(100)));
+/// assert_eq!(world.get::<Health>(second), Some(&Health(50)));
+/// assert_eq!(world.view_one::<Parent>().count(), 2);
+/// ```
+#[derive(Clone, Default)]
+pub struct Prefab {
+    data: HashMap<ComponentTypeId, ErasedComponentHolder, BuildHasherDefault<TypeIdHasher>>,
+    children: Vec<Prefab>,
+}
+
+impl Prefab {
+    /// Creates an empty prefab with no components and no children.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Saves provided component in the prefab.
+    ///
+    /// Repeated function call with the same type of component
+    /// will replace previous value with new one.
+    pub fn with<C>(mut self, component: C) -> Self
+    where
+        C: Component,
+    {
+        self.insert(component);
+        self
+    }
+
+    /// Adds child prefab which will be spawned together with this prefab.
+    pub fn with_child(mut self, child: Prefab) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Saves provided component in the prefab.
+    ///
+    /// Repeated function call with the same type of component
+    /// will replace previous value with new one.
+    pub fn insert<C>(&mut self, component: C)
+    where
+        C: Component,
+    {
+        let type_id = ComponentTypeId::of::<C>();
+        self.data.insert(type_id, component.into());
+    }
+
+    /// Removes component of generic type from the prefab.
+    pub fn remove<C>(&mut self)
+    where
+        C: Component,
+    {
+        let type_id = ComponentTypeId::of::<C>();
+        self.data.remove(&type_id);
+    }
+
+    /// Retrieves the shared borrow for the component of generic type saved in the prefab.
+    pub fn get<C>(&self) -> Option<&C>
+    where
+        C: Component,
+    {
+        let type_id = ComponentTypeId::of::<C>();
+        self.data.get(&type_id)?.as_component_ref()
+    }
+
+    /// Returns child prefabs of this prefab.
+    pub fn children(&self) -> &[Prefab] {
+        &self.children
+    }
+
+    pub(crate) fn spawn(&self, world: &mut World, parent: Option<Entity>) -> Entity {
+        let entity = world.create();
+        self.data
+            .values()
+            .for_each(|holder| holder.attach(entity, world));
+        if let Some(parent) = parent {
+            world.attach(entity, Parent(parent));
+        }
+        for child in &self.children {
+            child.spawn(world, Some(entity));
+        }
+        entity
+    }
+}
@@ -1,49 +1,274 @@
-use std::iter::FusedIterator;
+use std::iter::{Copied, FusedIterator};
+use std::slice;
+use std::sync::atomic::{AtomicI64, Ordering};
 
-use slotmap::dense::Keys;
-use slotmap::DenseSlotMap;
+use crate::component::storage::Storage;
 
 use super::Entity;
 
-#[repr(transparent)]
-#[derive(Default)]
-pub struct Registry(DenseSlotMap<Entity, ()>);
+#[derive(Copy, Clone, Default)]
+struct Slot {
+    version: u32,
+    dense: u32,
+    disabled: Option<u32>,
+}
+
+impl Slot {
+    fn is_alive(&self) -> bool {
+        self.version % 2 == 1
+    }
+}
+
+pub struct Registry {
+    slots: Vec<Slot>,
+    dense: Vec<Entity>,
+    free: Vec<u32>,
+    disabled: Vec<Entity>,
+    cursor: AtomicI64,
+}
 
 impl Registry {
     pub fn create(&mut self) -> Entity {
-        self.0.insert(())
+        self.flush();
+        let index = match self.free.pop() {
+            Some(index) => index,
+            None => self.push_slot(),
+        };
+        self.sync_cursor();
+        self.make_alive(index)
+    }
+
+    pub fn reserve(&self) -> Entity {
+        let cursor = self.cursor.fetch_sub(1, Ordering::Relaxed);
+        if cursor > 0 {
+            let index = self.free[cursor as usize - 1];
+            let version = self.slots[index as usize].version.wrapping_add(1);
+            to_entity(index, version)
+        } else {
+            let index = self.slots.len() as i64 - cursor;
+            to_entity(index as u32, 1)
+        }
+    }
+
+    pub fn reserve_many(&self, count: u32) -> Vec<Entity> {
+        let count = i64::from(count);
+        let end = self.cursor.fetch_sub(count, Ordering::Relaxed);
+        let start = end - count;
+
+        let reused = self.free[start.max(0) as usize..end.max(0) as usize]
+            .iter()
+            .rev()
+            .map(|&index| {
+                let version = self.slots[index as usize].version.wrapping_add(1);
+                to_entity(index, version)
+            });
+        let len = self.slots.len() as i64;
+        let created =
+            (len - end.min(0)..len - start.min(0)).map(|index| to_entity(index as u32, 1));
+        reused.chain(created).collect()
+    }
+
+    pub fn flush(&mut self) {
+        let cursor = *self.cursor.get_mut();
+        if cursor == self.free.len() as i64 {
+            return;
+        }
+        for _ in cursor..0 {
+            let index = self.push_slot();
+            self.make_alive(index);
+        }
+        let reserved = self.free.split_off(cursor.max(0) as usize);
+        for index in reserved {
+            self.make_alive(index);
+        }
+        self.sync_cursor();
     }
 
     pub fn contains(&self, entity: Entity) -> bool {
-        self.0.contains_key(entity)
+        self.slots
+            .get(entity.index() as usize)
+            .is_some_and(|slot| slot.version == entity.generation())
+    }
+
+    pub fn resolve(&self, index: u32) -> Option<Entity> {
+        let slot = self.slots.get(index as usize)?;
+        slot.is_alive().then(|| to_entity(index, slot.version))
+    }
+
+    pub fn disable(&mut self, entity: Entity) {
+        if !self.contains(entity) || self.is_disabled(entity) {
+            return;
+        }
+        let index = self.disabled.len() as u32;
+        self.slots[entity.index() as usize].disabled = Some(index);
+        self.disabled.push(entity);
+    }
+
+    pub fn enable(&mut self, entity: Entity) {
+        if !self.contains(entity) {
+            return;
+        }
+        let index = match self.slots[entity.index() as usize].disabled.take() {
+            Some(index) => index as usize,
+            None => return,
+        };
+        self.disabled.swap_remove(index);
+        if let Some(&moved) = self.disabled.get(index) {
+            self.slots[moved.index() as usize].disabled = Some(index as u32);
+        }
+    }
+
+    pub fn is_disabled(&self, entity: Entity) -> bool {
+        self.contains(entity) && self.slots[entity.index() as usize].disabled.is_some()
+    }
+
+    pub fn disabled(&self) -> &[Entity] {
+        &self.disabled
+    }
+
+    pub fn count_disabled<S>(&self, storage: &S) -> usize
+    where
+        S: Storage,
+    {
+        self.disabled
+            .iter()
+            .filter(|&&entity| storage.attached(entity))
+            .count()
+    }
+
+    pub fn renew(&mut self, entity: Entity) -> Option<Entity> {
+        if !self.contains(entity) {
+            return None;
+        }
+        let index = entity.index();
+        let slot = &mut self.slots[index as usize];
+        // keep the version odd, so the slot stays alive
+        slot.version = slot.version.wrapping_add(2);
+        let renewed = to_entity(index, slot.version);
+        self.dense[slot.dense as usize] = renewed;
+        if let Some(disabled) = slot.disabled {
+            self.disabled[disabled as usize] = renewed;
+        }
+        Some(renewed)
     }
 
     pub fn destroy(&mut self, entity: Entity) {
-        self.0.remove(entity);
+        self.flush();
+        if !self.contains(entity) {
+            return;
+        }
+        self.enable(entity);
+        let index = entity.index();
+        let slot = &mut self.slots[index as usize];
+        slot.version = slot.version.wrapping_add(1);
+        let dense = slot.dense as usize;
+        self.dense.swap_remove(dense);
+        if let Some(&moved) = self.dense.get(dense) {
+            self.slots[moved.index() as usize].dense = dense as u32;
+        }
+        self.free.push(index);
+        self.sync_cursor();
+    }
+
+    pub fn len(&self) -> usize {
+        self.dense.len()
     }
 
     pub fn is_empty(&self) -> bool {
-        self.0.is_empty()
+        self.dense.is_empty()
     }
 
     pub fn clear(&mut self) {
-        self.0.clear();
+        self.flush();
+        for entity in self.disabled.drain(..) {
+            self.slots[entity.index() as usize].disabled = None;
+        }
+        for entity in self.dense.drain(..) {
+            let index = entity.index();
+            let slot = &mut self.slots[index as usize];
+            slot.version = slot.version.wrapping_add(1);
+            self.free.push(index);
+        }
+        self.sync_cursor();
+    }
+
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            iter: self.dense.iter().copied(),
+            registry: self,
+        }
+    }
+
+    fn push_slot(&mut self) -> u32 {
+        let index = self.slots.len() as u32;
+        self.slots.push(Slot::default());
+        index
+    }
+
+    fn make_alive(&mut self, index: u32) -> Entity {
+        let slot = &mut self.slots[index as usize];
+        slot.version = slot.version.wrapping_add(1);
+        slot.dense = self.dense.len() as u32;
+        let entity = to_entity(index, slot.version);
+        self.dense.push(entity);
+        entity
+    }
+
+    fn sync_cursor(&mut self) {
+        *self.cursor.get_mut() = self.free.len() as i64;
     }
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        Self {
+            // sentinel slot which is never alive, same as in slotmap
+            slots: vec![Slot::default()],
+            dense: Vec::new(),
+            free: Vec::new(),
+            disabled: Vec::new(),
+            cursor: AtomicI64::new(0),
+        }
+    }
+}
 
-    pub fn iter(&self) -> Iter {
-        Iter(self.0.keys())
+impl Clone for Registry {
+    fn clone(&self) -> Self {
+        Self {
+            slots: self.slots.clone(),
+            dense: self.dense.clone(),
+            free: self.free.clone(),
+            disabled: self.disabled.clone(),
+            cursor: AtomicI64::new(self.cursor.load(Ordering::Relaxed)),
+        }
     }
 }
 
-#[repr(transparent)]
+fn to_entity(index: u32, version: u32) -> Entity {
+    Entity::from_bits((u64::from(version) << 32) | u64::from(index))
+}
+
 #[derive(Clone)]
-pub struct Iter<'data>(Keys<'data, Entity, ()>);
+pub struct Iter<'data> {
+    iter: Copied<slice::Iter<'data, Entity>>,
+    registry: &'data Registry,
+}
+
+impl<'data> Iter<'data> {
+    pub fn registry(&self) -> &'data Registry {
+        self.registry
+    }
+}
 
 impl<'data> Iterator for Iter<'data> {
     type Item = Entity;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.0.next()
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
     }
 }
 
@@ -9,12 +9,14 @@
 //! Simple and safe ECS library for Rust.
 //!
 //! Provides basic features, such as:
-//! - create and destroy entities;
+//! - create, destroy and disable entities;
 //! - attach, get or remove components from the entity;
 //! - use [entry](crate::world::Entry) of the entity to modify it;
 //! - view components of different types
-//! [immutably][crate::world::World::view()] or [mutably][crate::world::World::view_mut()];
-//! - use [systems](crate::system::System) to get and update data efficiently.
+//!   [immutably][crate::world::World::view()] or [mutably][crate::world::World::view_mut()];
+//! - use [systems](crate::system::System) to get and update data efficiently;
+//! - bundle systems and resources into reusable [plugins](crate::app::Plugin);
+//! - inspect and modify components at runtime through [reflection](crate::reflect::Reflect).
 //!
 //! # Examples
 //!
@@ -119,7 +121,7 @@
 //! let mut schedule = Schedule::builder()
 //!     .system(|| println!("Hello, World"))
 //!     // This system will be executed once
-//!     .system(|resource: Res<MyResource>| println!("Resource value: {}", (*resource).0))
+//!     .system(|resource: Res<MyResource>| println!("Resource value: {}", resource.0))
 //!     // This system will be executed for each entity with `Name` and `ID` components
 //!     .foreach_system(|name: &Name, id: &mut ID| {
 //!         id.0 += 100;
@@ -136,10 +138,12 @@ mod hash;
 mod mutability_check;
 mod type_id;
 
+pub mod app;
 pub mod component;
 pub mod entity;
 pub mod marker;
 pub mod prelude;
+pub mod reflect;
 #[cfg(feature = "resource")]
 #[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
 pub mod resource;
@@ -0,0 +1,5 @@
+/// Marker zero-sized type for retrieving [disabled](crate::world::World::disable())
+/// entities along with enabled ones.
+/// It must be used in query to be retrieved.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct IncludeDisabled;
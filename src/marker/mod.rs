@@ -1,9 +1,16 @@
 //! Special marker types for views and systems.
 
 pub use component::Not;
+pub use disabled::IncludeDisabled;
 #[cfg(feature = "resource")]
-pub use resource::{Res, ResMut};
+pub use {
+    non_send::{NonSend, NonSendMut},
+    resource::{Res, ResMut},
+};
 
 mod component;
+mod disabled;
+#[cfg(feature = "resource")]
+mod non_send;
 #[cfg(feature = "resource")]
 mod resource;
@@ -0,0 +1,96 @@
+use std::hash::Hash;
+use std::ops::{Deref, DerefMut};
+
+/// Marker for retrieving **shared** borrow of non-send resource from the world.
+/// It must be used in query of the [system](crate::system::System) to be retrieved.
+///
+/// Unlike [resources](crate::resource::Resource), non-send resources
+/// are not required to implement [`Send`] and [`Sync`] traits,
+/// so they can be accessed only on the thread they were created on.
+///
+/// This struct is just a wrapper around **shared** [reference] of the non-send resource.
+///
+/// [reference]: prim@reference
+#[repr(transparent)]
+#[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonSend<'data, R>(pub &'data R)
+where
+    R: 'static;
+
+impl<'data, R> Deref for NonSend<'data, R>
+where
+    R: 'static,
+{
+    type Target = R;
+
+    fn deref(&self) -> &Self::Target {
+        self.0
+    }
+}
+
+impl<'data, R> Clone for NonSend<'data, R>
+where
+    R: 'static,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'data, R> Copy for NonSend<'data, R> where R: 'static {}
+
+impl<'data, R> From<&'data R> for NonSend<'data, R>
+where
+    R: 'static,
+{
+    fn from(shared: &'data R) -> Self {
+        Self(shared)
+    }
+}
+
+/// Marker for retrieving **unique** borrow of non-send resource from the world.
+/// It must be used in query of the [system](crate::system::System) to be retrieved.
+///
+/// Unlike [resources](crate::resource::Resource), non-send resources
+/// are not required to implement [`Send`] and [`Sync`] traits,
+/// so they can be accessed only on the thread they were created on.
+///
+/// This struct is just a wrapper around **unique** [reference] of the non-send resource.
+///
+/// [reference]: prim@reference
+#[repr(transparent)]
+#[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonSendMut<'data, R>(pub &'data mut R)
+where
+    R: 'static;
+
+impl<'data, R> Deref for NonSendMut<'data, R>
+where
+    R: 'static,
+{
+    type Target = R;
+
+    fn deref(&self) -> &Self::Target {
+        self.0
+    }
+}
+
+impl<'data, R> DerefMut for NonSendMut<'data, R>
+where
+    R: 'static,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.0
+    }
+}
+
+impl<'data, R> From<&'data mut R> for NonSendMut<'data, R>
+where
+    R: 'static,
+{
+    fn from(unique: &'data mut R) -> Self {
+        Self(unique)
+    }
+}
@@ -1,21 +1,101 @@
-use std::hash::Hash;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
 use std::ops::{Deref, DerefMut};
 
-use crate::resource::Resource;
+use crate::resource::{Resource, Tick, Ticks};
 
 /// Marker for retrieving **shared** borrow of [resource] from the world.
 /// It must be used in query to be retrieved.
 ///
-/// This struct is just a wrapper around **shared** [reference] of the resource.
+/// This struct is a wrapper around **shared** [reference] of the resource
+/// which also knows when the resource was added or changed last time.
+///
+/// Inside of [systems](crate::system::System) resource is considered to be
+/// [added](Res::is_added) or [changed](Res::is_changed) if it happened after
+/// the last run of the system. Outside of systems these functions are relative to
+/// the creation of the [world](crate::world::World).
+/// Marker [created](From) from the plain reference is considered both added and changed.
 ///
 /// [resource]: Resource
 /// [reference]: prim@reference
-#[repr(transparent)]
 #[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Res<'data, R>(pub &'data R)
+pub struct Res<'data, R>
 where
-    R: Resource;
+    R: Resource,
+{
+    resource: &'data R,
+    ticks: Ticks,
+    last_run: Tick,
+}
+
+impl<'data, R> Res<'data, R>
+where
+    R: Resource,
+{
+    pub(crate) fn new(resource: &'data R, ticks: &'data Ticks, last_run: Tick) -> Self {
+        Self {
+            resource,
+            ticks: *ticks,
+            last_run,
+        }
+    }
+
+    /// Returns `true` if the resource was added since the last run of the system.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// #[derive(Resource)]
+    /// struct Settings(u32);
+    ///
+    /// let mut world = World::new();
+    /// world.create_resources(Settings(42));
+    ///
+    /// let mut schedule = Schedule::builder()
+    ///     .system(|settings: Res<Settings>| println!("added: {}", settings.is_added()))
+    ///     .build();
+    ///
+    /// schedule.run(&mut world); // added: true
+    /// schedule.run(&mut world); // added: false
+    /// ```
+    pub fn is_added(&self) -> bool {
+        self.ticks.is_added(self.last_run)
+    }
+
+    /// Returns `true` if the resource was added or mutably dereferenced
+    /// through [`ResMut`] since the last run of the system.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// #[derive(Resource)]
+    /// struct Settings(u32);
+    ///
+    /// let mut world = World::new();
+    /// world.create_resources(Settings(42));
+    ///
+    /// let mut schedule = Schedule::builder()
+    ///     .system(|settings: Res<Settings>| println!("changed: {}", settings.is_changed()))
+    ///     .build();
+    ///
+    /// schedule.run(&mut world); // changed: true
+    /// schedule.run(&mut world); // changed: false
+    ///
+    /// world.get_resource_mut::<Settings>().unwrap().0 = 10;
+    /// schedule.run(&mut world); // changed: true
+    /// ```
+    pub fn is_changed(&self) -> bool {
+        self.ticks.is_changed(self.last_run)
+    }
+
+    /// Converts this marker into the **shared** reference of the resource.
+    pub fn into_inner(self) -> &'data R {
+        self.resource
+    }
+}
 
 impl<'data, R> Deref for Res<'data, R>
 where
@@ -24,43 +104,134 @@ where
     type Target = R;
 
     fn deref(&self) -> &Self::Target {
-        self.0
+        self.resource
     }
 }
 
-impl<'data, R> Clone for Res<'data, R>
+impl<'data, R> From<&'data R> for Res<'data, R>
 where
     R: Resource,
 {
-    fn clone(&self) -> Self {
-        Self(self.0)
+    fn from(resource: &'data R) -> Self {
+        let last_run = Tick::default();
+        Self {
+            resource,
+            ticks: Ticks::new(last_run.next()),
+            last_run,
+        }
     }
 }
 
-impl<'data, R> Copy for Res<'data, R> where R: Resource {}
-
-impl<'data, R> From<&'data R> for Res<'data, R>
+impl<'data, R> Clone for Res<'data, R>
 where
     R: Resource,
 {
-    fn from(shared: &'data R) -> Self {
-        Self(shared)
+    fn clone(&self) -> Self {
+        *self
     }
 }
 
+impl<'data, R> Copy for Res<'data, R> where R: Resource {}
+
 /// Marker for retrieving **unique** borrow of [resource] from the world.
 /// It must be used in query to be retrieved.
 ///
-/// This struct is just a wrapper around **mutable** [reference] of the resource.
+/// This struct is a wrapper around **mutable** [reference] of the resource.
+/// Each [mutable dereference](DerefMut) marks the resource as changed,
+/// so other systems could see it [changed](Res::is_changed) on their next run.
+/// Marker [created](From) from the plain reference is considered both added and changed.
 ///
 /// [resource]: Resource
 /// [reference]: prim@reference
-#[repr(transparent)]
 #[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct ResMut<'data, R>(pub &'data mut R)
+pub struct ResMut<'data, R>
+where
+    R: Resource,
+{
+    resource: &'data mut R,
+    ticks: TicksMut<'data>,
+    last_run: Tick,
+    this_run: Tick,
+}
+
+enum TicksMut<'data> {
+    Borrowed(&'data mut Ticks),
+    Owned(Ticks),
+}
+
+impl<'data> Deref for TicksMut<'data> {
+    type Target = Ticks;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            TicksMut::Borrowed(ticks) => ticks,
+            TicksMut::Owned(ticks) => ticks,
+        }
+    }
+}
+
+impl<'data> DerefMut for TicksMut<'data> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            TicksMut::Borrowed(ticks) => ticks,
+            TicksMut::Owned(ticks) => ticks,
+        }
+    }
+}
+
+impl<'data, R> ResMut<'data, R>
 where
-    R: Resource;
+    R: Resource,
+{
+    pub(crate) fn new(
+        resource: &'data mut R,
+        ticks: &'data mut Ticks,
+        last_run: Tick,
+        this_run: Tick,
+    ) -> Self {
+        Self {
+            resource,
+            ticks: TicksMut::Borrowed(ticks),
+            last_run,
+            this_run,
+        }
+    }
+
+    pub(crate) fn reborrow(&mut self) -> ResMut<'_, R> {
+        ResMut {
+            resource: self.resource,
+            ticks: TicksMut::Borrowed(&mut self.ticks),
+            last_run: self.last_run,
+            this_run: self.this_run,
+        }
+    }
+
+    /// Returns `true` if the resource was added since the last run of the system.
+    ///
+    /// See [`Res::is_added`] for more details.
+    pub fn is_added(&self) -> bool {
+        self.ticks.is_added(self.last_run)
+    }
+
+    /// Returns `true` if the resource was added or changed since the last run of the system.
+    ///
+    /// See [`Res::is_changed`] for more details.
+    pub fn is_changed(&self) -> bool {
+        self.ticks.is_changed(self.last_run)
+    }
+
+    /// Marks the resource as changed without dereferencing it mutably.
+    pub fn set_changed(&mut self) {
+        self.ticks.set_changed(self.this_run)
+    }
+
+    /// Converts this marker into the **mutable** reference of the resource
+    /// marking the resource as changed.
+    pub fn into_inner(mut self) -> &'data mut R {
+        self.ticks.set_changed(self.this_run);
+        self.resource
+    }
+}
 
 impl<'data, R> Deref for ResMut<'data, R>
 where
@@ -69,24 +240,88 @@ where
     type Target = R;
 
     fn deref(&self) -> &Self::Target {
-        self.0
+        self.resource
     }
 }
 
-impl<'data, R> DerefMut for ResMut<'data, R>
+impl<'data, R> From<&'data mut R> for ResMut<'data, R>
 where
     R: Resource,
 {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        self.0
+    fn from(resource: &'data mut R) -> Self {
+        let last_run = Tick::default();
+        let this_run = last_run.next();
+        Self {
+            resource,
+            ticks: TicksMut::Owned(Ticks::new(this_run)),
+            last_run,
+            this_run,
+        }
     }
 }
 
-impl<'data, R> From<&'data mut R> for ResMut<'data, R>
+impl<'data, R> DerefMut for ResMut<'data, R>
 where
     R: Resource,
 {
-    fn from(unique: &'data mut R) -> Self {
-        Self(unique)
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.set_changed();
+        self.resource
     }
 }
+
+macro_rules! delegate_traits {
+    ($($marker:ident),*) => {$(
+        impl<'data, R> Debug for $marker<'data, R>
+        where
+            R: Resource + Debug,
+        {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                f.debug_tuple(stringify!($marker)).field(&&**self).finish()
+            }
+        }
+
+        impl<'data, R> PartialEq for $marker<'data, R>
+        where
+            R: Resource + PartialEq,
+        {
+            fn eq(&self, other: &Self) -> bool {
+                **self == **other
+            }
+        }
+
+        impl<'data, R> Eq for $marker<'data, R> where R: Resource + Eq {}
+
+        impl<'data, R> PartialOrd for $marker<'data, R>
+        where
+            R: Resource + PartialOrd,
+        {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                (**self).partial_cmp(&**other)
+            }
+        }
+
+        impl<'data, R> Ord for $marker<'data, R>
+        where
+            R: Resource + Ord,
+        {
+            fn cmp(&self, other: &Self) -> Ordering {
+                (**self).cmp(&**other)
+            }
+        }
+
+        impl<'data, R> Hash for $marker<'data, R>
+        where
+            R: Resource + Hash,
+        {
+            fn hash<H>(&self, state: &mut H)
+            where
+                H: Hasher,
+            {
+                (**self).hash(state)
+            }
+        }
+    )*};
+}
+
+delegate_traits!(Res, ResMut);
@@ -2,7 +2,7 @@ use std::marker::PhantomData;
 
 use crate::mutability_check::{CheckMap, MutabilityCheck};
 
-pub struct MutabilityChecked<T>(PhantomData<T>)
+pub struct MutabilityChecked<T>(PhantomData<fn() -> T>)
 where
     T: MutabilityCheck;
 
@@ -1,8 +1,8 @@
-use crate::component::{Component, ComponentTypeId};
+use crate::component::{Component, ComponentTypeId, RemovedComponents};
 use crate::entity::Entity;
 use crate::marker::*;
 #[cfg(feature = "resource")]
-use crate::resource::{Resource, ResourceTypeId};
+use crate::resource::{NonSendTypeId, Resource, ResourceTypeId};
 use crate::system::foreach::{ForeachHolder, Query as ForeachQuery};
 use crate::world::query::{Query, QueryMut};
 use crate::world::view::{View, ViewMut, ViewOne, ViewOneMut};
@@ -21,7 +21,7 @@ impl MutabilityCheck for Entity {
     fn check(_: &mut CheckMap) {}
 }
 
-impl<'data, C> MutabilityCheck for &'data C
+impl<C> MutabilityCheck for &C
 where
     C: Component,
 {
@@ -42,7 +42,7 @@ where
     }
 }
 
-impl<'data, C> MutabilityCheck for &'data mut C
+impl<C> MutabilityCheck for &mut C
 where
     C: Component,
 {
@@ -52,17 +52,14 @@ where
         let mutability = Mutability::Mutable;
         let type_id = ComponentTypeId::of::<C>().into();
         let prev = check_map.insert(type_id, mutability);
-        match prev {
-            Some(_) => {
-                let type_name = core::any::type_name::<C>();
-                panic!("multiple mutable borrows occur for {}", type_name)
-            }
-            None => (),
+        if prev.is_some() {
+            let type_name = core::any::type_name::<C>();
+            panic!("multiple mutable borrows occur for {}", type_name)
         }
     }
 }
 
-impl<'data, C> MutabilityCheck for Option<&'data C>
+impl<C> MutabilityCheck for Option<&C>
 where
     C: Component,
 {
@@ -73,7 +70,7 @@ where
     }
 }
 
-impl<'data, C> MutabilityCheck for Option<&'data mut C>
+impl<C> MutabilityCheck for Option<&mut C>
 where
     C: Component,
 {
@@ -84,6 +81,12 @@ where
     }
 }
 
+impl MutabilityCheck for IncludeDisabled {
+    const LENGTH: usize = 0;
+
+    fn check(_: &mut CheckMap) {}
+}
+
 impl<C> MutabilityCheck for Not<C>
 where
     C: Component,
@@ -128,12 +131,9 @@ where
         let mutability = Mutability::Mutable;
         let type_id = ResourceTypeId::of::<R>().into();
         let prev = check_map.insert(type_id, mutability);
-        match prev {
-            Some(_) => {
-                let type_name = core::any::type_name::<R>();
-                panic!("multiple mutable borrows occur for {}", type_name)
-            }
-            None => (),
+        if prev.is_some() {
+            let type_name = core::any::type_name::<R>();
+            panic!("multiple mutable borrows occur for {}", type_name)
         }
     }
 }
@@ -162,6 +162,66 @@ where
     }
 }
 
+#[cfg(feature = "resource")]
+impl<'data, R> MutabilityCheck for NonSend<'data, R>
+where
+    R: 'static,
+{
+    const LENGTH: usize = 1;
+
+    fn check(check_map: &mut CheckMap) {
+        let mutability = Mutability::Immutable;
+        let type_id = NonSendTypeId::of::<R>().into();
+        let prev = check_map.insert(type_id, mutability);
+        if let Some(Mutability::Mutable) = prev {
+            let type_name = core::any::type_name::<R>();
+            panic!("immutable and mutable borrows occur for {}", type_name)
+        }
+    }
+}
+
+#[cfg(feature = "resource")]
+impl<'data, R> MutabilityCheck for NonSendMut<'data, R>
+where
+    R: 'static,
+{
+    const LENGTH: usize = 1;
+
+    fn check(check_map: &mut CheckMap) {
+        let mutability = Mutability::Mutable;
+        let type_id = NonSendTypeId::of::<R>().into();
+        let prev = check_map.insert(type_id, mutability);
+        if prev.is_some() {
+            let type_name = core::any::type_name::<R>();
+            panic!("multiple mutable borrows occur for {}", type_name)
+        }
+    }
+}
+
+#[cfg(feature = "resource")]
+impl<'data, R> MutabilityCheck for Option<NonSend<'data, R>>
+where
+    R: 'static,
+{
+    const LENGTH: usize = 1;
+
+    fn check(check_map: &mut CheckMap) {
+        <NonSend<R> as MutabilityCheck>::check(check_map)
+    }
+}
+
+#[cfg(feature = "resource")]
+impl<'data, R> MutabilityCheck for Option<NonSendMut<'data, R>>
+where
+    R: 'static,
+{
+    const LENGTH: usize = 1;
+
+    fn check(check_map: &mut CheckMap) {
+        <NonSendMut<R> as MutabilityCheck>::check(check_map)
+    }
+}
+
 impl<'data, C> MutabilityCheck for ViewOne<'data, C>
 where
     C: Component,
@@ -184,6 +244,15 @@ where
     }
 }
 
+impl<'data, C> MutabilityCheck for RemovedComponents<'data, C>
+where
+    C: Component,
+{
+    const LENGTH: usize = 0;
+
+    fn check(_: &mut CheckMap) {}
+}
+
 impl<'data, Q> MutabilityCheck for View<'data, Q>
 where
     Q: Query<'data>,
@@ -15,7 +15,7 @@ mod tuple;
 
 type CheckMap = HashMap<DataTypeId, Mutability, BuildHasherDefault<TypeIdHasher>>;
 
-pub trait MutabilityCheck: Send + Sync {
+pub trait MutabilityCheck {
     const LENGTH: usize;
 
     fn check(check_map: &mut CheckMap);
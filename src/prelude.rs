@@ -1,13 +1,17 @@
 //! Prelude module contains the most commonly used crate items.
 
 pub use crate::{
+    app::{App, AppBuilder, Plugin},
     component::storage::{DefaultStorage, DynIter, DynIterMut, Storage},
-    component::Component,
-    entity::{Entity, EntityBuilder},
+    component::{Component, RemovedComponents},
+    entity::{
+        Entity, EntityBuilder, EntityMap, EntityPool, GenerationPolicy, MapEntities, Parent, Prefab,
+    },
     marker::*,
-    system::{Schedule, ScheduleBuilder, System},
+    reflect::Reflect,
+    system::{Schedule, ScheduleBuilder, System, SystemId},
     world::view::{View, ViewMut, ViewOne, ViewOneMut},
-    world::{Components, ComponentsMut, Entry, World},
+    world::{Components, ComponentsMut, Entry, FromWorld, MergePolicy, World},
 };
 #[cfg(feature = "resource")]
 pub use crate::{
@@ -0,0 +1,50 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Error which can occur while accessing the data through [reflection](super::Reflect).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ReflectError {
+    /// Type with provided name was not registered for reflection.
+    UnknownType {
+        /// Name of the type.
+        name: String,
+    },
+    /// Type has no field with provided name.
+    UnknownField {
+        /// Name of the type.
+        type_name: &'static str,
+        /// Name of the field.
+        name: String,
+    },
+    /// Type cannot be set from provided kind of value.
+    Unsupported {
+        /// Name of the type.
+        type_name: &'static str,
+    },
+    /// Provided value cannot be converted into the type.
+    InvalidValue {
+        /// Name of the type.
+        type_name: &'static str,
+        /// Description of the conversion error.
+        message: String,
+    },
+}
+
+impl Display for ReflectError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownType { name } => write!(f, "Type `{}` is not registered", name),
+            Self::UnknownField { type_name, name } => {
+                write!(f, "Type `{}` has no field `{}`", type_name, name)
+            }
+            Self::Unsupported { type_name } => {
+                write!(f, "Type `{}` cannot be set from the value", type_name)
+            }
+            Self::InvalidValue { type_name, message } => {
+                write!(f, "Invalid value of type `{}`: {}", type_name, message)
+            }
+        }
+    }
+}
+
+impl Error for ReflectError {}
@@ -0,0 +1,37 @@
+use std::any::type_name;
+use std::str::FromStr;
+
+use crate::reflect::{Reflect, ReflectError};
+
+macro_rules! reflect_value {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Reflect for $ty {
+                fn display(&self) -> Option<String> {
+                    Some(self.to_string())
+                }
+
+                fn set_from_str(&mut self, value: &str) -> Result<(), ReflectError> {
+                    *self = <$ty>::from_str(value).map_err(|error| ReflectError::InvalidValue {
+                        type_name: type_name::<Self>(),
+                        message: error.to_string(),
+                    })?;
+                    Ok(())
+                }
+
+                #[cfg(feature = "serde")]
+                fn set_value(&mut self, value: serde_value::Value) -> Result<(), ReflectError> {
+                    *self = value.deserialize_into().map_err(|error| ReflectError::InvalidValue {
+                        type_name: type_name::<Self>(),
+                        message: error.to_string(),
+                    })?;
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+reflect_value!(
+    bool, char, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, String,
+);
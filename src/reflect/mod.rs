@@ -0,0 +1,182 @@
+//! Provides trait for runtime reflection of the data and registry of reflected components.
+
+pub use error::ReflectError;
+pub(crate) use registry::Registry;
+/// Value of any type supported by serde which can be used to [set](Reflect::set_value)
+/// the reflected data.
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub use serde_value::Value;
+/// Derive macro that implements [`Reflect`] trait for structs.
+///
+/// Every field of the struct must implement [`Reflect`] trait
+/// unless it is marked with `#[reflect(skip)]` helper attribute.
+/// Fields of tuple structs are named by their indices.
+///
+/// # Examples
+///
+/// ```
+/// use toucan_ecs::prelude::*;
+///
+/// #[derive(Copy, Clone, Component, Reflect)]
+/// pub struct Position {
+///     x: f32,
+///     y: f32,
+///     #[reflect(skip)]
+///     cached: Option<(f32, f32)>,
+/// }
+///
+/// #[derive(Copy, Clone, Component, Reflect)]
+/// pub struct Velocity(f32, f32);
+///
+/// let velocity = Velocity(1.0, 2.0);
+/// assert_eq!(velocity.fields(), ["0", "1"]);
+/// ```
+pub use toucan_ecs_macro::Reflect;
+
+mod error;
+mod impls;
+mod registry;
+
+/// Trait for data which can be inspected and modified at runtime
+/// without knowing its type at compile time.
+///
+/// Structs expose their fields by name, while primitive types and [`String`]
+/// can be read and written from string representation.
+/// With enabled feature `serde`, data can also be set from [serde value](Value).
+///
+/// ## How can I implement `Reflect`?
+///
+/// You can implement this trait with [derive macro][`macro@Reflect`]:
+///
+/// ```
+/// use toucan_ecs::prelude::*;
+///
+/// #[derive(Copy, Clone, Component, Reflect)]
+/// pub struct Position {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// let mut position = Position { x: 0.0, y: 0.0 };
+/// assert_eq!(position.fields(), ["x", "y"]);
+///
+/// let x = position.field_mut("x").unwrap();
+/// x.set_from_str("10").unwrap();
+/// assert_eq!(x.display().as_deref(), Some("10"));
+/// assert_eq!(position.x, 10.0);
+/// ```
+pub trait Reflect: as_any::AsAny + Send + Sync {
+    /// Returns name of the type of this data.
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+
+    /// Returns names of the fields of this data.
+    ///
+    /// Default implementation returns no fields.
+    fn fields(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Returns **shared** borrow of the field by its name.
+    ///
+    /// Default implementation returns [`None`].
+    fn field(&self, name: &str) -> Option<&dyn Reflect> {
+        let _ = name;
+        None
+    }
+
+    /// Returns **unique** borrow of the field by its name.
+    ///
+    /// Default implementation returns [`None`].
+    fn field_mut(&mut self, name: &str) -> Option<&mut dyn Reflect> {
+        let _ = name;
+        None
+    }
+
+    /// Returns string representation of this data if it has any.
+    ///
+    /// Default implementation returns [`None`].
+    fn display(&self) -> Option<String> {
+        None
+    }
+
+    /// Replaces this data with the value parsed from the string.
+    ///
+    /// Default implementation returns [error](ReflectError::Unsupported).
+    fn set_from_str(&mut self, value: &str) -> Result<(), ReflectError> {
+        let _ = value;
+        Err(ReflectError::Unsupported {
+            type_name: Reflect::type_name(self),
+        })
+    }
+
+    /// Replaces this data with the value deserialized from [serde value](Value).
+    ///
+    /// Default implementation expects map of the field names to their values
+    /// (or sequence of the values for tuple structs)
+    /// and sets each of the provided fields recursively.
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    fn set_value(&mut self, value: Value) -> Result<(), ReflectError> {
+        let type_name = Reflect::type_name(self);
+        let entries: Vec<_> = match value {
+            Value::Map(map) => map
+                .into_iter()
+                .map(|(key, value)| match key {
+                    Value::String(key) => Ok((key, value)),
+                    key => Err(ReflectError::InvalidValue {
+                        type_name,
+                        message: format!("expected field name, found {:?}", key),
+                    }),
+                })
+                .collect::<Result<_, _>>()?,
+            Value::Seq(seq) => seq
+                .into_iter()
+                .enumerate()
+                .map(|(index, value)| (index.to_string(), value))
+                .collect(),
+            Value::Unit if self.fields().is_empty() => Vec::new(),
+            value => {
+                return Err(ReflectError::InvalidValue {
+                    type_name,
+                    message: format!("expected map of the fields, found {:?}", value),
+                })
+            }
+        };
+        for (name, value) in entries {
+            let field = self
+                .field_mut(&name)
+                .ok_or(ReflectError::UnknownField { type_name, name })?;
+            field.set_value(value)?;
+        }
+        Ok(())
+    }
+}
+
+impl dyn Reflect {
+    /// Returns `true` if the reflected data is of generic type.
+    pub fn is<T>(&self) -> bool
+    where
+        T: Reflect,
+    {
+        as_any::AsAny::as_any(self).is::<T>()
+    }
+
+    /// Returns **shared** borrow of the reflected data if it is of generic type.
+    pub fn downcast_ref<T>(&self) -> Option<&T>
+    where
+        T: Reflect,
+    {
+        as_any::AsAny::as_any(self).downcast_ref()
+    }
+
+    /// Returns **unique** borrow of the reflected data if it is of generic type.
+    pub fn downcast_mut<T>(&mut self) -> Option<&mut T>
+    where
+        T: Reflect,
+    {
+        as_any::AsAny::as_any_mut(self).downcast_mut()
+    }
+}
@@ -0,0 +1,91 @@
+use std::any::type_name;
+use std::collections::BTreeMap;
+
+use crate::component::{Component, Registry as ComponentRegistry};
+use crate::entity::Entity;
+use crate::reflect::{Reflect, ReflectError};
+
+type GetFn = for<'a> fn(&'a ComponentRegistry, Entity) -> Option<&'a dyn Reflect>;
+type GetMutFn = for<'a> fn(&'a mut ComponentRegistry, Entity) -> Option<&'a mut dyn Reflect>;
+
+#[derive(Default)]
+pub struct Registry {
+    types: BTreeMap<&'static str, ReflectComponent>,
+}
+
+impl Registry {
+    pub fn register<C>(&mut self)
+    where
+        C: Component + Reflect + Default,
+    {
+        self.types
+            .insert(type_name::<C>(), ReflectComponent::new::<C>());
+    }
+
+    pub fn type_names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.types.keys().copied()
+    }
+
+    pub fn get(&self, name: &str) -> Result<&ReflectComponent, ReflectError> {
+        if let Some(component) = self.types.get(name) {
+            return Ok(component);
+        }
+        let mut candidates = self
+            .types
+            .iter()
+            .filter(|(type_name, _)| short_name(type_name) == name);
+        match (candidates.next(), candidates.next()) {
+            (Some((_, component)), None) => Ok(component),
+            _ => Err(ReflectError::UnknownType {
+                name: name.to_string(),
+            }),
+        }
+    }
+}
+
+fn short_name(type_name: &str) -> &str {
+    let path = type_name.split('<').next().unwrap_or(type_name);
+    let start = path.rfind("::").map_or(0, |index| index + 2);
+    &type_name[start..]
+}
+
+pub struct ReflectComponent {
+    get: GetFn,
+    get_mut: GetMutFn,
+    attach_default: fn(&mut ComponentRegistry, Entity),
+}
+
+impl ReflectComponent {
+    fn new<C>() -> Self
+    where
+        C: Component + Reflect + Default,
+    {
+        Self {
+            get: |registry, entity| registry.get::<C>(entity).map(|c| c as &dyn Reflect),
+            get_mut: |registry, entity| {
+                registry.get_mut::<C>(entity).map(|c| c as &mut dyn Reflect)
+            },
+            attach_default: |registry, entity| registry.attach(entity, C::default()),
+        }
+    }
+
+    pub fn get<'a>(
+        &self,
+        registry: &'a ComponentRegistry,
+        entity: Entity,
+    ) -> Option<&'a dyn Reflect> {
+        (self.get)(registry, entity)
+    }
+
+    pub fn get_mut<'a>(
+        &self,
+        registry: &'a mut ComponentRegistry,
+        entity: Entity,
+    ) -> Option<&'a mut dyn Reflect> {
+        (self.get_mut)(registry, entity)
+    }
+
+    pub fn attach_default(&self, registry: &mut ComponentRegistry, entity: Entity) {
+        (self.attach_default)(registry, entity)
+    }
+}
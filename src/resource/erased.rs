@@ -1,35 +1,64 @@
+use std::any::{type_name, TypeId};
+
 use as_any::{AsAny, Downcast};
 
-use super::Resource;
+use super::{Resource, Tick, Ticks};
+use crate::world::ResourceInfo;
 
-#[repr(transparent)]
-pub struct ErasedResourceHolder(Box<dyn Holdable>);
+pub struct ErasedResourceHolder {
+    resource: Box<dyn Holdable>,
+    ticks: Ticks,
+}
 
 impl ErasedResourceHolder {
-    pub fn as_resource_ref<R>(&self) -> Option<&R>
+    pub fn new<R>(resource: R, tick: Tick) -> Self
     where
         R: Resource,
     {
-        self.0.as_ref().downcast_ref()
+        Self {
+            resource: Box::new(resource),
+            ticks: Ticks::new(tick),
+        }
     }
 
-    pub fn as_resource_mut<R>(&mut self) -> Option<&mut R>
+    pub fn info(&self) -> ResourceInfo {
+        self.resource.info()
+    }
+
+    pub fn ticks(&self) -> &Ticks {
+        &self.ticks
+    }
+
+    pub fn reset_ticks(&mut self, tick: Tick) {
+        self.ticks = Ticks::new(tick)
+    }
+
+    pub fn as_resource_ref<R>(&self) -> Option<(&R, &Ticks)>
     where
         R: Resource,
     {
-        self.0.as_mut().downcast_mut()
+        let resource = self.resource.as_ref().downcast_ref()?;
+        Some((resource, &self.ticks))
+    }
+
+    pub fn as_resource_mut<R>(&mut self) -> Option<(&mut R, &mut Ticks)>
+    where
+        R: Resource,
+    {
+        let resource = self.resource.as_mut().downcast_mut()?;
+        Some((resource, &mut self.ticks))
     }
 }
 
-impl<R> From<(R,)> for ErasedResourceHolder
+trait Holdable: AsAny + Send + Sync {
+    fn info(&self) -> ResourceInfo;
+}
+
+impl<R> Holdable for R
 where
     R: Resource,
 {
-    fn from(resource: (R,)) -> Self {
-        Self(Box::new(resource.0))
+    fn info(&self) -> ResourceInfo {
+        ResourceInfo::new(TypeId::of::<R>(), type_name::<R>())
     }
 }
-
-trait Holdable: AsAny + Send + Sync {}
-
-impl<R> Holdable for R where R: Resource {}
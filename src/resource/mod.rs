@@ -1,9 +1,13 @@
 //! Provides trait for resources and borrow types for them.
 
 use erased::ErasedResourceHolder;
+pub(crate) use non_send::{
+    NonSendTypeId, Registry as NonSendRegistry, RegistryRefs as NonSendRefs,
+};
 pub(crate) use registry::Registry;
 pub(crate) use registry_refs::RegistryRefs;
 pub(crate) use set::ResourceSet;
+pub(crate) use tick::{Tick, Ticks};
 /// Derive macro that implements [`Resource`] trait.
 ///
 /// # Examples
@@ -16,13 +20,38 @@ pub(crate) use set::ResourceSet;
 ///     start: std::time::Instant,
 /// }
 /// ```
+///
+/// Use `#[resource(init = "...")]` helper attribute to implement
+/// [`FromWorld`](crate::world::FromWorld) trait with provided function:
+///
+/// ```
+/// use toucan_ecs::prelude::*;
+///
+/// #[derive(Resource)]
+/// #[resource(init = "Time::new")]
+/// pub struct Time {
+///     start: std::time::Instant,
+/// }
+///
+/// impl Time {
+///     fn new(_: &mut World) -> Self {
+///         let start = std::time::Instant::now();
+///         Self { start }
+///     }
+/// }
+///
+/// let mut world = World::new();
+/// world.init_resource::<Time>();
+/// ```
 pub use toucan_ecs_macro::Resource;
 pub(crate) use type_id::ResourceTypeId;
 
 mod erased;
+mod non_send;
 mod registry;
 mod registry_refs;
 mod set;
+mod tick;
 mod type_id;
 
 /// Trait for data that can be stored as singleton in ECS.
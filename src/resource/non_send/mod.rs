@@ -0,0 +1,31 @@
+use std::any::{Any, TypeId};
+
+pub use registry::Registry;
+pub use registry_refs::RegistryRefs;
+
+use crate::marker::NonSend;
+
+mod registry;
+mod registry_refs;
+
+type ErasedNonSend = Box<dyn Any>;
+
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub struct NonSendTypeId(TypeId);
+
+impl NonSendTypeId {
+    // Type of the marker is used to not interfere with resources of the same type
+    pub fn of<R>() -> Self
+    where
+        R: 'static,
+    {
+        Self(TypeId::of::<NonSend<'static, R>>())
+    }
+}
+
+impl From<NonSendTypeId> for TypeId {
+    fn from(type_id: NonSendTypeId) -> Self {
+        type_id.0
+    }
+}
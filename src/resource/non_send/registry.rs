@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+use std::hash::BuildHasherDefault;
+use std::thread::{self, ThreadId};
+
+use crate::hash::TypeIdHasher;
+use crate::resource::non_send::{ErasedNonSend, NonSendTypeId};
+
+#[derive(Default)]
+pub struct Registry {
+    resources: HashMap<NonSendTypeId, ErasedNonSend, BuildHasherDefault<TypeIdHasher>>,
+    thread: Option<ThreadId>,
+}
+
+// SAFETY: resources are accessed and dropped only on the thread they were created on,
+// which is checked on each access
+unsafe impl Send for Registry {}
+
+// SAFETY: resources are accessed and dropped only on the thread they were created on,
+// which is checked on each access
+unsafe impl Sync for Registry {}
+
+impl Registry {
+    pub fn is_empty(&self) -> bool {
+        self.resources.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        if self.is_empty() {
+            return;
+        }
+        self.check_thread();
+        self.resources.clear();
+        self.thread = None;
+    }
+
+    pub fn create<R>(&mut self, resource: R)
+    where
+        R: 'static,
+    {
+        match self.thread {
+            None => self.thread = Some(thread::current().id()),
+            Some(_) => self.check_thread(),
+        }
+        let type_id = NonSendTypeId::of::<R>();
+        self.resources.insert(type_id, Box::new(resource));
+    }
+
+    pub fn destroy<R>(&mut self)
+    where
+        R: 'static,
+    {
+        let type_id = NonSendTypeId::of::<R>();
+        if !self.resources.contains_key(&type_id) {
+            return;
+        }
+        self.check_thread();
+        self.resources.remove(&type_id);
+        if self.is_empty() {
+            self.thread = None;
+        }
+    }
+
+    pub fn contains<R>(&self) -> bool
+    where
+        R: 'static,
+    {
+        let type_id = NonSendTypeId::of::<R>();
+        self.resources.contains_key(&type_id)
+    }
+
+    pub fn get<R>(&self) -> Option<&R>
+    where
+        R: 'static,
+    {
+        self.check_thread();
+        let type_id = NonSendTypeId::of::<R>();
+        let resource = self.resources.get(&type_id)?;
+        let resource = resource.downcast_ref().expect("downcast error");
+        Some(resource)
+    }
+
+    pub fn get_mut<R>(&mut self) -> Option<&mut R>
+    where
+        R: 'static,
+    {
+        self.check_thread();
+        let type_id = NonSendTypeId::of::<R>();
+        let resource = self.resources.get_mut(&type_id)?;
+        let resource = resource.downcast_mut().expect("downcast error");
+        Some(resource)
+    }
+
+    pub fn thread(&self) -> Option<ThreadId> {
+        self.thread
+    }
+
+    pub fn is_same_thread(&self) -> bool {
+        match self.thread {
+            None => true,
+            Some(thread) => thread == thread::current().id(),
+        }
+    }
+
+    pub fn check_thread(&self) {
+        if !self.is_same_thread() {
+            panic!(
+                "attempted to access non-send resources from thread {:?} other than {:?}",
+                thread::current().id(),
+                self.thread.unwrap(),
+            )
+        }
+    }
+
+    pub(super) fn iter_mut(
+        &mut self,
+    ) -> impl Iterator<Item = (&NonSendTypeId, &mut ErasedNonSend)> + '_ {
+        self.resources.iter_mut()
+    }
+}
+
+impl Drop for Registry {
+    fn drop(&mut self) {
+        if self.is_same_thread() {
+            return;
+        }
+        // leak resources to not drop them on the wrong thread
+        let resources = std::mem::take(&mut self.resources);
+        std::mem::forget(resources);
+        if thread::panicking() {
+            return;
+        }
+        panic!(
+            "attempted to drop non-send resources on thread {:?} other than {:?}",
+            thread::current().id(),
+            self.thread.unwrap(),
+        )
+    }
+}
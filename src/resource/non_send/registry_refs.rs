@@ -0,0 +1,62 @@
+use std::hash::BuildHasherDefault;
+use std::thread::{self, ThreadId};
+
+use hashbrown::HashMap;
+use ref_kind::{Many, RefKind};
+
+use crate::hash::TypeIdHasher;
+use crate::resource::non_send::{ErasedNonSend, NonSendTypeId, Registry};
+
+pub struct RegistryRefs<'data> {
+    refs: HashMap<
+        NonSendTypeId,
+        Option<RefKind<'data, ErasedNonSend>>,
+        BuildHasherDefault<TypeIdHasher>,
+    >,
+    thread: Option<ThreadId>,
+}
+
+impl<'data> From<&'data mut Registry> for RegistryRefs<'data> {
+    fn from(registry: &'data mut Registry) -> Self {
+        let thread = registry.thread();
+        let refs = registry
+            .iter_mut()
+            .map(|(&type_id, erased)| (type_id, Some(RefKind::Mut(erased))))
+            .collect();
+        Self { refs, thread }
+    }
+}
+
+impl<'data> RegistryRefs<'data> {
+    pub fn move_ref<R>(&mut self) -> Option<&'data R>
+    where
+        R: 'static,
+    {
+        self.check_thread();
+        let type_id = NonSendTypeId::of::<R>();
+        let erased = self.refs.move_ref(type_id)?;
+        let resource = erased.downcast_ref().expect("downcast error");
+        Some(resource)
+    }
+
+    pub fn move_mut<R>(&mut self) -> Option<&'data mut R>
+    where
+        R: 'static,
+    {
+        self.check_thread();
+        let type_id = NonSendTypeId::of::<R>();
+        let erased = self.refs.move_mut(type_id)?;
+        let resource = erased.downcast_mut().expect("downcast error");
+        Some(resource)
+    }
+
+    fn check_thread(&self) {
+        let current = thread::current().id();
+        if let Some(thread) = self.thread.filter(|&thread| thread != current) {
+            panic!(
+                "attempted to access non-send resources from thread {:?} other than {:?}",
+                current, thread,
+            )
+        }
+    }
+}
@@ -2,12 +2,13 @@ use std::collections::HashMap;
 use std::hash::BuildHasherDefault;
 
 use crate::hash::TypeIdHasher;
-use crate::resource::{ErasedResourceHolder, Resource, ResourceSet, ResourceTypeId};
+use crate::resource::{ErasedResourceHolder, Resource, ResourceSet, ResourceTypeId, Tick, Ticks};
+use crate::world::ResourceInfo;
 
 #[derive(Default)]
-#[repr(transparent)]
 pub struct Registry {
     resources: HashMap<ResourceTypeId, ErasedResourceHolder, BuildHasherDefault<TypeIdHasher>>,
+    change_tick: Tick,
 }
 
 impl Registry {
@@ -31,7 +32,8 @@ impl Registry {
         R: Resource,
     {
         let type_id = ResourceTypeId::of::<R>();
-        let erased = (resource,).into();
+        let tick = self.increment_change_tick();
+        let erased = ErasedResourceHolder::new(resource, tick);
         self.resources.insert(type_id, erased);
     }
 
@@ -71,7 +73,7 @@ impl Registry {
     {
         let type_id = ResourceTypeId::of::<R>();
         let resource = self.resources.get(&type_id)?;
-        let resource = resource.as_resource_ref().expect("downcast error");
+        let (resource, _) = resource.as_resource_ref().expect("downcast error");
         Some(resource)
     }
 
@@ -80,11 +82,71 @@ impl Registry {
         R: Resource,
     {
         let type_id = ResourceTypeId::of::<R>();
+        let tick = self.change_tick.next();
         let resource = self.resources.get_mut(&type_id)?;
-        let resource = resource.as_resource_mut().expect("downcast error");
+        let (resource, ticks) = resource.as_resource_mut().expect("downcast error");
+        ticks.set_changed(tick);
+        self.change_tick = tick;
         Some(resource)
     }
 
+    pub fn take<R>(&mut self) -> Option<ErasedResourceHolder>
+    where
+        R: Resource,
+    {
+        let type_id = ResourceTypeId::of::<R>();
+        self.resources.remove(&type_id)
+    }
+
+    pub fn restore<R>(&mut self, erased: ErasedResourceHolder)
+    where
+        R: Resource,
+    {
+        let type_id = ResourceTypeId::of::<R>();
+        self.resources.insert(type_id, erased);
+    }
+
+    pub fn conflict(&self, other: &Registry) -> Option<ResourceInfo> {
+        other
+            .resources
+            .iter()
+            .find(|(type_id, _)| self.resources.contains_key(type_id))
+            .map(|(_, erased)| erased.info())
+    }
+
+    pub fn merge(&mut self, other: Registry, replace: bool) {
+        for (type_id, mut erased) in other.resources {
+            if !replace && self.resources.contains_key(&type_id) {
+                continue;
+            }
+            let tick = self.increment_change_tick();
+            erased.reset_ticks(tick);
+            self.resources.insert(type_id, erased);
+        }
+    }
+
+    pub fn ticks<R>(&self) -> Option<&Ticks>
+    where
+        R: Resource,
+    {
+        let type_id = ResourceTypeId::of::<R>();
+        let resource = self.resources.get(&type_id)?;
+        Some(resource.ticks())
+    }
+
+    pub fn change_tick(&self) -> Tick {
+        self.change_tick
+    }
+
+    pub fn increment_change_tick(&mut self) -> Tick {
+        self.change_tick = self.change_tick.next();
+        self.change_tick
+    }
+
+    pub fn infos(&self) -> impl Iterator<Item = ResourceInfo> + '_ {
+        self.resources.values().map(ErasedResourceHolder::info)
+    }
+
     pub(super) fn iter(
         &self,
     ) -> impl Iterator<Item = (&ResourceTypeId, &ErasedResourceHolder)> + '_ {
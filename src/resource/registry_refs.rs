@@ -4,10 +4,10 @@ use hashbrown::HashMap;
 use ref_kind::{Many, RefKind};
 
 use crate::hash::TypeIdHasher;
+use crate::marker::{Res, ResMut};
 use crate::resource::erased::ErasedResourceHolder;
-use crate::resource::{Registry, Resource, ResourceTypeId};
+use crate::resource::{Registry, Resource, ResourceTypeId, Tick};
 
-#[repr(transparent)]
 #[derive(Default)]
 pub struct RegistryRefs<'data> {
     refs: HashMap<
@@ -15,56 +15,73 @@ pub struct RegistryRefs<'data> {
         Option<RefKind<'data, ErasedResourceHolder>>,
         BuildHasherDefault<TypeIdHasher>,
     >,
+    last_run: Tick,
+    this_run: Tick,
 }
 
 impl<'data> From<&'data Registry> for RegistryRefs<'data> {
     fn from(registry: &'data Registry) -> Self {
+        let this_run = registry.change_tick();
         let refs = registry
             .iter()
             .map(|(&type_id, erased)| (type_id, Some(RefKind::Ref(erased))))
             .collect();
-        Self { refs }
+        Self {
+            refs,
+            last_run: Tick::default(),
+            this_run,
+        }
     }
 }
 
 impl<'data> From<&'data mut Registry> for RegistryRefs<'data> {
     fn from(registry: &'data mut Registry) -> Self {
+        let this_run = registry.increment_change_tick();
         let refs = registry
             .iter_mut()
             .map(|(&type_id, erased)| (type_id, Some(RefKind::Mut(erased))))
             .collect();
-        Self { refs }
+        Self {
+            refs,
+            last_run: Tick::default(),
+            this_run,
+        }
     }
 }
 
 impl<'data> RegistryRefs<'data> {
+    pub fn set_last_run(&mut self, last_run: Tick) -> Tick {
+        self.last_run = last_run;
+        self.this_run
+    }
+
     pub fn get_ref<R>(&self) -> Option<&R>
     where
         R: Resource,
     {
         let type_id = ResourceTypeId::of::<R>();
         let erased = self.refs.get(&type_id)?.as_ref().unwrap().get_ref();
-        let resource = erased.as_resource_ref().expect("downcast error");
+        let (resource, _) = erased.as_resource_ref().expect("downcast error");
         Some(resource)
     }
 
-    pub fn move_ref<R>(&mut self) -> Option<&'data R>
+    pub fn move_ref<R>(&mut self) -> Option<Res<'data, R>>
     where
         R: Resource,
     {
         let type_id = ResourceTypeId::of::<R>();
         let erased = self.refs.move_ref(type_id)?;
-        let resource = erased.as_resource_ref().expect("downcast error");
-        Some(resource)
+        let (resource, ticks) = erased.as_resource_ref().expect("downcast error");
+        Some(Res::new(resource, ticks, self.last_run))
     }
 
-    pub fn move_mut<R>(&mut self) -> Option<&'data mut R>
+    pub fn move_mut<R>(&mut self) -> Option<ResMut<'data, R>>
     where
         R: Resource,
     {
         let type_id = ResourceTypeId::of::<R>();
         let erased = self.refs.move_mut(type_id)?;
-        let resource = erased.as_resource_mut().expect("downcast error");
-        Some(resource)
+        let (resource, ticks) = erased.as_resource_mut().expect("downcast error");
+        Some(ResMut::new(resource, ticks, self.last_run, self.this_run))
     }
 }
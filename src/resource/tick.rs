@@ -0,0 +1,40 @@
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[repr(transparent)]
+pub struct Tick(u64);
+
+impl Tick {
+    pub fn next(self) -> Self {
+        Self(self.0 + 1)
+    }
+
+    pub fn is_newer_than(self, other: Tick) -> bool {
+        self > other
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct Ticks {
+    added: Tick,
+    changed: Tick,
+}
+
+impl Ticks {
+    pub fn new(tick: Tick) -> Self {
+        Self {
+            added: tick,
+            changed: tick,
+        }
+    }
+
+    pub fn is_added(&self, last_run: Tick) -> bool {
+        self.added.is_newer_than(last_run)
+    }
+
+    pub fn is_changed(&self, last_run: Tick) -> bool {
+        self.changed.is_newer_than(last_run)
+    }
+
+    pub fn set_changed(&mut self, tick: Tick) {
+        self.changed = tick;
+    }
+}
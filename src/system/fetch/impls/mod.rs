@@ -1,13 +1,14 @@
 pub use foreach_holder::*;
+pub use removed::*;
 #[cfg(feature = "resource")]
 pub use resource::*;
-pub use unit::*;
 pub use view::*;
 pub use view_mut::*;
 pub use view_one::*;
 pub use view_one_mut::*;
 
 mod foreach_holder;
+mod removed;
 #[cfg(feature = "resource")]
 mod resource;
 mod unit;
@@ -0,0 +1,27 @@
+use std::marker::PhantomData;
+
+use crate::component::{Component, RemovedComponents};
+use crate::entity::Iter;
+use crate::error::{FetchError, FetchResult};
+use crate::system::fetch::Fetch;
+use crate::world::WorldRefs;
+
+#[repr(transparent)]
+pub struct FetchRemoved<C>
+where
+    C: Component,
+{
+    _ph: PhantomData<C>,
+}
+
+impl<'data, C> Fetch<'data> for FetchRemoved<C>
+where
+    C: Component,
+{
+    type Item = RemovedComponents<'data, C>;
+
+    fn fetch(_: &Iter<'data>, data: &mut WorldRefs<'data>) -> FetchResult<Self::Item> {
+        let removed = data.removed().ok_or(FetchError)?;
+        Ok(removed)
+    }
+}
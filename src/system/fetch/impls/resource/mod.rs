@@ -1,8 +1,16 @@
+pub use non_send_option_read::FetchNonSendOptionRead;
+pub use non_send_option_write::FetchNonSendOptionWrite;
+pub use non_send_read::FetchNonSendRead;
+pub use non_send_write::FetchNonSendWrite;
 pub use option_read::FetchResourceOptionRead;
 pub use option_write::FetchResourceOptionWrite;
 pub use read::FetchResourceRead;
 pub use write::FetchResourceWrite;
 
+mod non_send_option_read;
+mod non_send_option_write;
+mod non_send_read;
+mod non_send_write;
 mod option_read;
 mod option_write;
 mod read;
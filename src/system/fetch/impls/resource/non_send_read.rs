@@ -0,0 +1,28 @@
+use std::marker::PhantomData;
+
+use crate::entity::Iter;
+use crate::error::{FetchError, FetchResult};
+use crate::marker::NonSend;
+use crate::system::fetch::Fetch;
+use crate::world::WorldRefs;
+
+#[repr(transparent)]
+pub struct FetchNonSendRead<R>
+where
+    R: 'static,
+{
+    _ph: PhantomData<R>,
+}
+
+impl<'data, R> Fetch<'data> for FetchNonSendRead<R>
+where
+    R: 'static,
+{
+    type Item = NonSend<'data, R>;
+
+    fn fetch(_: &Iter<'data>, data: &mut WorldRefs<'data>) -> FetchResult<Self::Item> {
+        let resource = data.move_non_send_ref().ok_or(FetchError)?;
+        let resource = NonSend(resource);
+        Ok(resource)
+    }
+}
@@ -0,0 +1,28 @@
+use std::marker::PhantomData;
+
+use crate::entity::Iter;
+use crate::error::{FetchError, FetchResult};
+use crate::marker::NonSendMut;
+use crate::system::fetch::Fetch;
+use crate::world::WorldRefs;
+
+#[repr(transparent)]
+pub struct FetchNonSendWrite<R>
+where
+    R: 'static,
+{
+    _ph: PhantomData<R>,
+}
+
+impl<'data, R> Fetch<'data> for FetchNonSendWrite<R>
+where
+    R: 'static,
+{
+    type Item = NonSendMut<'data, R>;
+
+    fn fetch(_: &Iter<'data>, data: &mut WorldRefs<'data>) -> FetchResult<Self::Item> {
+        let resource = data.move_non_send_mut().ok_or(FetchError)?;
+        let resource = NonSendMut(resource);
+        Ok(resource)
+    }
+}
@@ -23,7 +23,6 @@ where
 
     fn fetch(_: &Iter<'data>, data: &mut WorldRefs<'data>) -> FetchResult<Self::Item> {
         let resource = data.move_resource_ref();
-        let resource = resource.map(Res);
         Ok(resource)
     }
 }
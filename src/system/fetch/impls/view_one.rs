@@ -21,9 +21,9 @@ where
 {
     type Item = ViewOne<'data, C>;
 
-    fn fetch(_: &Iter<'data>, data: &mut WorldRefs<'data>) -> FetchResult<Self::Item> {
+    fn fetch(entities: &Iter<'data>, data: &mut WorldRefs<'data>) -> FetchResult<Self::Item> {
         let storage = data.move_storage_ref::<C>();
-        let view_one = ViewOne::new(storage);
+        let view_one = ViewOne::new(storage, entities.registry());
         Ok(view_one)
     }
 }
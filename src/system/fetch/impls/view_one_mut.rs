@@ -1,6 +1,6 @@
 use std::marker::PhantomData;
 
-use crate::component::Component;
+use crate::component::{Component, Touch};
 use crate::entity::Iter;
 use crate::error::FetchResult;
 use crate::system::fetch::Fetch;
@@ -21,9 +21,12 @@ where
 {
     type Item = ViewOneMut<'data, C>;
 
-    fn fetch(_: &Iter<'data>, data: &mut WorldRefs<'data>) -> FetchResult<Self::Item> {
-        let storage = data.move_storage_mut::<C>();
-        let view_one_mut = ViewOneMut::new(storage);
+    fn fetch(entities: &Iter<'data>, data: &mut WorldRefs<'data>) -> FetchResult<Self::Item> {
+        let (storage, touch) = match data.move_storage_mut::<C>() {
+            Some((storage, touch)) => (Some(storage), touch),
+            None => (None, Touch::default()),
+        };
+        let view_one_mut = ViewOneMut::new(storage, entities.registry(), touch);
         Ok(view_one_mut)
     }
 }
@@ -8,7 +8,7 @@ mod impls;
 mod tuple;
 
 pub trait Fetch<'data>: 'data {
-    type Item: Send + Sync + 'data;
+    type Item: 'data;
 
     fn fetch(entities: &Iter<'data>, data: &mut WorldRefs<'data>) -> FetchResult<Self::Item>;
 }
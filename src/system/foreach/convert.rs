@@ -5,19 +5,40 @@ use crate::system::System;
 
 /// Allows to convert [`ForeachSystem`](ForeachSystem) trait into the type
 /// that implements [`System`](System) trait.
-pub struct FromForeachSystem<'data, S, Q>
+pub struct FromForeachSystem<S, Q>
 where
-    S: ForeachSystem<'data, Q>,
-    Q: Query<'data>,
+    Q: Query<'static>,
 {
     system: S,
-    _ph: PhantomData<&'data Q>,
+    _ph: PhantomData<fn() -> Q>,
 }
 
-impl<'data, S, Q> From<S> for FromForeachSystem<'data, S, Q>
+impl<S, Q> FromForeachSystem<S, Q>
 where
-    S: ForeachSystem<'data, Q>,
-    Q: Query<'data>,
+    Q: Query<'static>,
+{
+    /// Runs inner foreach system for each item of the holder
+    /// and returns count of visited entities.
+    pub(crate) fn run_counted<'data>(
+        &mut self,
+        holder: ForeachHolder<'data, Q::Rebind<'data>>,
+    ) -> usize
+    where
+        S: ForeachSystem<'data, Q::Rebind<'data>>,
+    {
+        let mut visited = 0;
+        for args in holder {
+            self.system.run(args);
+            visited += 1;
+        }
+        visited
+    }
+}
+
+impl<S, Q> From<S> for FromForeachSystem<S, Q>
+where
+    S: ForeachSystem<'static, Q>,
+    Q: Query<'static>,
 {
     fn from(system: S) -> Self {
         Self {
@@ -27,14 +48,12 @@ where
     }
 }
 
-impl<'data, S, Q> System<'data, ForeachHolder<'data, Q>> for FromForeachSystem<'data, S, Q>
+impl<'data, S, Q> System<'data, ForeachHolder<'data, Q::Rebind<'data>>> for FromForeachSystem<S, Q>
 where
-    S: ForeachSystem<'data, Q>,
-    Q: Query<'data>,
+    S: ForeachSystem<'data, Q::Rebind<'data>>,
+    Q: Query<'static>,
 {
-    fn run(&mut self, holder: ForeachHolder<'data, Q>) {
-        for args in holder {
-            self.system.run(args)
-        }
+    fn run(&mut self, holder: ForeachHolder<'data, Q::Rebind<'data>>) {
+        self.run_counted(holder);
     }
 }
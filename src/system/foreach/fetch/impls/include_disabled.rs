@@ -0,0 +1,43 @@
+use crate::component::ComponentTypeId;
+use crate::entity::Entity;
+use crate::error::{FetchError, FetchResult};
+use crate::marker::IncludeDisabled;
+use crate::system::foreach::fetch::{Fetch, FetchData, FetchStrategy};
+use crate::world::WorldRefs;
+
+#[repr(transparent)]
+pub struct FetchIncludeDisabled;
+
+impl<'data> Fetch<'data> for FetchIncludeDisabled {
+    type Item = IncludeDisabled;
+
+    fn push_fetch_data(_: &WorldRefs<'data>, _: &mut Vec<FetchData>) {}
+
+    fn new(_: &mut WorldRefs<'data>, _: Option<ComponentTypeId>) -> FetchResult<Self> {
+        Ok(Self)
+    }
+
+    fn is_iter(&self) -> bool {
+        false
+    }
+
+    fn fetch_entity(&'data mut self, _: Entity) -> FetchResult<Self::Item> {
+        Ok(IncludeDisabled)
+    }
+
+    fn fetch_iter(
+        &'data mut self,
+        strategy: FetchStrategy<'data>,
+    ) -> FetchResult<Option<(Entity, Self::Item)>> {
+        match strategy {
+            FetchStrategy::Optimized => Err(FetchError),
+            FetchStrategy::All(entities) => {
+                let entity = match entities.next() {
+                    None => return Ok(None),
+                    Some(entity) => entity,
+                };
+                Ok(Some((entity, IncludeDisabled)))
+            }
+        }
+    }
+}
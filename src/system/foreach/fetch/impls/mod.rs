@@ -1,14 +1,15 @@
 pub use entity::*;
+pub use include_disabled::*;
 pub use not::*;
 pub use option_read::*;
 pub use option_write::*;
 pub use read::*;
 #[cfg(feature = "resource")]
 pub use resource::*;
-pub use unit::*;
 pub use write::*;
 
 mod entity;
+mod include_disabled;
 mod not;
 mod option_read;
 mod option_write;
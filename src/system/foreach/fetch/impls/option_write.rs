@@ -1,16 +1,16 @@
 use crate::component::storage::Storage;
-use crate::component::{Component, ComponentTypeId};
+use crate::component::{Component, ComponentTypeId, Touch};
 use crate::entity::Entity;
 use crate::error::{FetchError, FetchResult};
 use crate::system::foreach::fetch::{Fetch, FetchData, FetchStrategy};
 use crate::world::WorldRefs;
 
-#[repr(transparent)]
 pub struct FetchOptionWrite<'data, C>
 where
     C: Component,
 {
     storage: Option<&'data mut C::Storage>,
+    touch: Touch<'data>,
 }
 
 impl<'data, C> Fetch<'data> for FetchOptionWrite<'data, C>
@@ -22,8 +22,11 @@ where
     fn push_fetch_data(_: &WorldRefs<'data>, _: &mut Vec<FetchData>) {}
 
     fn new(data: &mut WorldRefs<'data>, _: Option<ComponentTypeId>) -> FetchResult<Self> {
-        let storage = data.move_storage_mut::<C>();
-        Ok(Self { storage })
+        let (storage, touch) = match data.move_storage_mut::<C>() {
+            Some((storage, touch)) => (Some(storage), touch),
+            None => (None, Touch::default()),
+        };
+        Ok(Self { storage, touch })
     }
 
     fn is_iter(&self) -> bool {
@@ -33,6 +36,9 @@ where
     fn fetch_entity(&'data mut self, entity: Entity) -> FetchResult<Self::Item> {
         let storage = self.storage.as_deref_mut();
         let item = storage.and_then(|storage| storage.get_mut(entity));
+        if item.is_some() {
+            self.touch.touch(entity);
+        }
         Ok(item)
     }
 
@@ -11,7 +11,7 @@ pub struct FetchResourceOptionRead<'data, R>
 where
     R: Resource,
 {
-    resource: Option<&'data R>,
+    resource: Option<Res<'data, R>>,
 }
 
 impl<'data, R> Fetch<'data> for FetchResourceOptionRead<'data, R>
@@ -32,7 +32,7 @@ where
     }
 
     fn fetch_entity(&'data mut self, _: Entity) -> FetchResult<Self::Item> {
-        let resource = self.resource.map(Res);
+        let resource = self.resource;
         Ok(resource)
     }
 
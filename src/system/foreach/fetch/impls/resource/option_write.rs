@@ -11,7 +11,7 @@ pub struct FetchResourceOptionWrite<'data, R>
 where
     R: Resource,
 {
-    resource: Option<&'data mut R>,
+    resource: Option<ResMut<'data, R>>,
 }
 
 impl<'data, R> Fetch<'data> for FetchResourceOptionWrite<'data, R>
@@ -32,7 +32,7 @@ where
     }
 
     fn fetch_entity(&'data mut self, _: Entity) -> FetchResult<Self::Item> {
-        let resource = self.resource.as_deref_mut().map(ResMut);
+        let resource = self.resource.as_mut().map(ResMut::reborrow);
         Ok(resource)
     }
 
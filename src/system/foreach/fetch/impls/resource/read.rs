@@ -11,7 +11,7 @@ pub struct FetchResourceRead<'data, R>
 where
     R: Resource,
 {
-    resource: &'data R,
+    resource: Res<'data, R>,
 }
 
 impl<'data, R> Fetch<'data> for FetchResourceRead<'data, R>
@@ -32,7 +32,7 @@ where
     }
 
     fn fetch_entity(&'data mut self, _: Entity) -> FetchResult<Self::Item> {
-        let resource = Res(self.resource);
+        let resource = self.resource;
         Ok(resource)
     }
 
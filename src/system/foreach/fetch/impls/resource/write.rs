@@ -11,7 +11,7 @@ pub struct FetchResourceWrite<'data, R>
 where
     R: Resource,
 {
-    resource: &'data mut R,
+    resource: ResMut<'data, R>,
 }
 
 impl<'data, R> Fetch<'data> for FetchResourceWrite<'data, R>
@@ -32,7 +32,7 @@ where
     }
 
     fn fetch_entity(&'data mut self, _: Entity) -> FetchResult<Self::Item> {
-        let resource = ResMut(self.resource);
+        let resource = self.resource.reborrow();
         Ok(resource)
     }
 
@@ -1,5 +1,5 @@
 use crate::component::storage::{DynIterMut, Storage};
-use crate::component::{Component, ComponentTypeId};
+use crate::component::{Component, ComponentTypeId, Touch};
 use crate::entity::Entity;
 use crate::error::{FetchError, FetchResult};
 use crate::system::foreach::fetch::{Fetch, FetchData, FetchStrategy};
@@ -9,8 +9,8 @@ pub enum FetchWrite<'data, C>
 where
     C: Component,
 {
-    Storage(&'data mut C::Storage),
-    Iter(Box<DynIterMut<'data, C>>),
+    Storage(&'data mut C::Storage, Touch<'data>),
+    Iter(Box<DynIterMut<'data, C>>, Touch<'data>),
 }
 
 impl<'data, C> Fetch<'data> for FetchWrite<'data, C>
@@ -31,24 +31,25 @@ where
     }
 
     fn new(data: &mut WorldRefs<'data>, optimal: Option<ComponentTypeId>) -> FetchResult<Self> {
-        let storage = data.move_storage_mut::<C>().ok_or(FetchError)?;
+        let (storage, touch) = data.move_storage_mut::<C>().ok_or(FetchError)?;
         if optimal == Some(ComponentTypeId::of::<C>()) {
             let iter = storage.iter_mut();
-            Ok(Self::Iter(iter))
+            Ok(Self::Iter(iter, touch))
         } else {
-            Ok(Self::Storage(storage))
+            Ok(Self::Storage(storage, touch))
         }
     }
 
     fn is_iter(&self) -> bool {
-        matches!(self, Self::Iter(_))
+        matches!(self, Self::Iter(..))
     }
 
     fn fetch_entity(&'data mut self, entity: Entity) -> FetchResult<Self::Item> {
         match self {
-            Self::Iter(_) => Err(FetchError),
-            Self::Storage(storage) => {
+            Self::Iter(..) => Err(FetchError),
+            Self::Storage(storage, touch) => {
                 let item = storage.get_mut(entity).ok_or(FetchError)?;
+                touch.touch(entity);
                 Ok(item)
             }
         }
@@ -61,7 +62,7 @@ where
     ) -> FetchResult<Option<(Entity, Self::Item)>> {
         match strategy {
             FetchStrategy::All(entities) => match self {
-                Self::Storage(_) => {
+                Self::Storage(..) => {
                     let entity = match entities.next() {
                         None => return Ok(None),
                         Some(entity) => entity,
@@ -69,11 +70,17 @@ where
                     let item = self.fetch_entity(entity)?;
                     Ok(Some((entity, item)))
                 }
-                Self::Iter(_) => Err(FetchError),
+                Self::Iter(..) => Err(FetchError),
             },
             FetchStrategy::Optimized => match self {
-                Self::Storage(_) => Err(FetchError),
-                Self::Iter(iter) => Ok(iter.next()),
+                Self::Storage(..) => Err(FetchError),
+                Self::Iter(iter, touch) => {
+                    let item = iter.next();
+                    if let Some((entity, _)) = item {
+                        touch.touch(entity);
+                    }
+                    Ok(item)
+                }
             },
         }
     }
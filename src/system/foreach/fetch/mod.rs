@@ -42,8 +42,15 @@ where
 {
     let mut fetch_data = Vec::new();
     F::push_fetch_data(data, &mut fetch_data);
-    fetch_data.sort_unstable_by_key(FetchData::len);
-    fetch_data.into_iter().next()
+    find_smallest(fetch_data, FetchData::len)
+}
+
+pub fn find_smallest<I, F>(items: I, len: F) -> Option<I::Item>
+where
+    I: IntoIterator,
+    F: FnMut(&I::Item) -> usize,
+{
+    items.into_iter().min_by_key(len)
 }
 
 pub enum FetchStrategy<'data> {
@@ -1,6 +1,6 @@
 use std::mem::transmute;
 
-use crate::entity::{Entity, Iter};
+use crate::entity::{Entity, Iter, Registry};
 use crate::system::foreach::fetch::{find_optimal, Fetch, FetchData, FetchStrategy};
 use crate::system::foreach::query::{CheckedQuery, Query};
 use crate::world::WorldRefs;
@@ -11,6 +11,7 @@ where
     Q: Query<'data>,
 {
     entities: Option<Iter<'data>>,
+    disabled: Option<&'data Registry>,
     fetch: Option<Q::Fetch>,
 }
 
@@ -23,7 +24,15 @@ where
         let _checked = CheckedQuery::<'data, Q>::new();
         let optimal = find_optimal::<Q::Fetch>(data).map(FetchData::into_type_id);
         let fetch = Q::Fetch::new(data, optimal).ok();
-        Self { entities, fetch }
+        let disabled = entities
+            .as_ref()
+            .map(Iter::registry)
+            .filter(|registry| !Q::INCLUDE_DISABLED && !registry.disabled().is_empty());
+        Self {
+            entities,
+            disabled,
+            fetch,
+        }
     }
 }
 
@@ -47,17 +56,23 @@ where
                     fetch.fetch_entity(entity).map(|item| Some((entity, item)))
                 }
                 Some(entities) => {
-                    let strategy = fetch
-                        .is_iter()
-                        .then_some(FetchStrategy::Optimized)
-                        .unwrap_or(FetchStrategy::All(entities));
+                    let strategy = if fetch.is_iter() {
+                        FetchStrategy::Optimized
+                    } else {
+                        FetchStrategy::All(entities)
+                    };
                     fetch.fetch_iter(strategy)
                 }
             };
             match result {
                 Ok(item) => {
-                    let (_, item) = item?;
-                    return Some(item.into());
+                    let (entity, item) = item?;
+                    if let Some(registry) = self.disabled {
+                        if registry.is_disabled(entity) {
+                            continue;
+                        }
+                    }
+                    return Some(item);
                 }
                 Err(_) => continue,
             }
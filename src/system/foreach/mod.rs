@@ -4,7 +4,6 @@
 pub use convert::FromForeachSystem;
 pub(crate) use holder::ForeachHolder;
 pub use query::Query;
-pub(crate) use query::QueryItem;
 
 mod convert;
 mod holder;
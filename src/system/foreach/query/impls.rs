@@ -8,10 +8,19 @@ use crate::system::foreach::query::Query;
 
 impl<'data> Query<'data> for () {
     type Fetch = ();
+    type Rebind<'w> = ();
 }
 
 impl<'data> Query<'data> for Entity {
     type Fetch = FetchEntity;
+    type Rebind<'w> = Entity;
+}
+
+impl<'data> Query<'data> for IncludeDisabled {
+    type Fetch = FetchIncludeDisabled;
+    type Rebind<'w> = IncludeDisabled;
+
+    const INCLUDE_DISABLED: bool = true;
 }
 
 impl<'data, C> Query<'data> for &'data C
@@ -19,6 +28,7 @@ where
     C: Component,
 {
     type Fetch = FetchRead<'data, C>;
+    type Rebind<'w> = &'w C;
 }
 
 impl<'data, C> Query<'data> for Option<&'data C>
@@ -26,6 +36,7 @@ where
     C: Component,
 {
     type Fetch = FetchOptionRead<'data, C>;
+    type Rebind<'w> = Option<&'w C>;
 }
 
 impl<'data, C> Query<'data> for Not<C>
@@ -33,6 +44,7 @@ where
     C: Component,
 {
     type Fetch = FetchNot<'data, C>;
+    type Rebind<'w> = Not<C>;
 }
 
 impl<'data, C> Query<'data> for &'data mut C
@@ -40,6 +52,7 @@ where
     C: Component,
 {
     type Fetch = FetchWrite<'data, C>;
+    type Rebind<'w> = &'w mut C;
 }
 
 impl<'data, C> Query<'data> for Option<&'data mut C>
@@ -47,6 +60,7 @@ where
     C: Component,
 {
     type Fetch = FetchOptionWrite<'data, C>;
+    type Rebind<'w> = Option<&'w mut C>;
 }
 
 #[cfg(feature = "resource")]
@@ -56,6 +70,7 @@ where
     R: Resource,
 {
     type Fetch = FetchResourceRead<'data, R>;
+    type Rebind<'w> = Res<'w, R>;
 }
 
 #[cfg(feature = "resource")]
@@ -65,6 +80,7 @@ where
     R: Resource,
 {
     type Fetch = FetchResourceOptionRead<'data, R>;
+    type Rebind<'w> = Option<Res<'w, R>>;
 }
 
 #[cfg(feature = "resource")]
@@ -74,6 +90,7 @@ where
     R: Resource,
 {
     type Fetch = FetchResourceWrite<'data, R>;
+    type Rebind<'w> = ResMut<'w, R>;
 }
 
 #[cfg(feature = "resource")]
@@ -83,4 +100,5 @@ where
     R: Resource,
 {
     type Fetch = FetchResourceOptionWrite<'data, R>;
+    type Rebind<'w> = Option<ResMut<'w, R>>;
 }
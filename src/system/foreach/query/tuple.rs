@@ -4,10 +4,12 @@ macro_rules! foreach_query {
     ($($types:ident),*) => {
         impl<'data, $($types),*> Query<'data> for ($($types,)*)
         where
-            Self: From<($(QueryItem<'data, $types>,)*)>,
             $($types: Query<'data>,)*
         {
             type Fetch = ($($types::Fetch,)*);
+            type Rebind<'w> = ($($types::Rebind<'w>,)*);
+
+            const INCLUDE_DISABLED: bool = $($types::INCLUDE_DISABLED)||*;
         }
     };
 }
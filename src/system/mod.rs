@@ -1,7 +1,9 @@
 //! Provides traits and utilities for systems.
 
 use query::Query;
-pub use schedule::{Schedule, ScheduleBuilder};
+#[cfg(feature = "resource")]
+pub use schedule::{resource_added, resource_changed, resource_exists, RunCondition};
+pub use schedule::{Schedule, ScheduleBuilder, ScheduleStats, SystemId, SystemStats};
 
 mod fetch;
 mod impls;
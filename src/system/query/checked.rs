@@ -8,7 +8,7 @@ where
     Q: Query<'data>,
 {
     _checked: MutabilityChecked<Q>,
-    _ph: PhantomData<&'data Q>,
+    _ph: PhantomData<fn() -> &'data Q>,
 }
 
 impl<'data, Q> CheckedQuery<'data, Q>
@@ -1,4 +1,4 @@
-use crate::component::Component;
+use crate::component::{Component, RemovedComponents};
 use crate::system::fetch::*;
 use crate::system::foreach::{ForeachHolder, Query as ForeachQuery};
 use crate::system::query::Query;
@@ -9,6 +9,7 @@ use crate::{marker::*, resource::Resource};
 
 impl<'data> Query<'data> for () {
     type Fetch = ();
+    type Rebind<'w> = ();
 }
 
 impl<'data, C> Query<'data> for ViewOne<'data, C>
@@ -16,6 +17,7 @@ where
     C: Component,
 {
     type Fetch = FetchViewOne<C>;
+    type Rebind<'w> = ViewOne<'w, C>;
 }
 
 impl<'data, C> Query<'data> for ViewOneMut<'data, C>
@@ -23,6 +25,16 @@ where
     C: Component,
 {
     type Fetch = FetchViewOneMut<C>;
+    type Rebind<'w> = ViewOneMut<'w, C>;
+}
+
+impl<'data, C> Query<'data> for RemovedComponents<'data, C>
+where
+    C: Component,
+{
+    type Fetch = FetchRemoved<C>;
+    type Rebind<'w> = RemovedComponents<'w, C>;
+    const REMOVED: bool = true;
 }
 
 impl<'data, Q> Query<'data> for View<'data, Q>
@@ -30,6 +42,7 @@ where
     Q: query::Query<'data>,
 {
     type Fetch = FetchView<'data, Q>;
+    type Rebind<'w> = View<'w, Q::RebindRef<'w>>;
 }
 
 impl<'data, Q> Query<'data> for ViewMut<'data, Q>
@@ -37,6 +50,7 @@ where
     Q: query::QueryMut<'data>,
 {
     type Fetch = FetchViewMut<'data, Q>;
+    type Rebind<'w> = ViewMut<'w, Q::RebindMut<'w>>;
 }
 
 impl<'data, Q> Query<'data> for ForeachHolder<'data, Q>
@@ -44,6 +58,7 @@ where
     Q: ForeachQuery<'data>,
 {
     type Fetch = FetchForeachHolder<'data, Q>;
+    type Rebind<'w> = ForeachHolder<'w, Q::Rebind<'w>>;
 }
 
 #[cfg(feature = "resource")]
@@ -53,6 +68,7 @@ where
     R: Resource,
 {
     type Fetch = FetchResourceRead<R>;
+    type Rebind<'w> = Res<'w, R>;
 }
 
 #[cfg(feature = "resource")]
@@ -62,6 +78,7 @@ where
     R: Resource,
 {
     type Fetch = FetchResourceWrite<R>;
+    type Rebind<'w> = ResMut<'w, R>;
 }
 
 #[cfg(feature = "resource")]
@@ -71,6 +88,7 @@ where
     R: Resource,
 {
     type Fetch = FetchResourceOptionRead<R>;
+    type Rebind<'w> = Option<Res<'w, R>>;
 }
 
 #[cfg(feature = "resource")]
@@ -80,4 +98,49 @@ where
     R: Resource,
 {
     type Fetch = FetchResourceOptionWrite<R>;
+    type Rebind<'w> = Option<ResMut<'w, R>>;
+}
+
+#[cfg(feature = "resource")]
+#[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
+impl<'data, R> Query<'data> for NonSend<'data, R>
+where
+    R: 'static,
+{
+    type Fetch = FetchNonSendRead<R>;
+    type Rebind<'w> = NonSend<'w, R>;
+    const NON_SEND: bool = true;
+}
+
+#[cfg(feature = "resource")]
+#[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
+impl<'data, R> Query<'data> for NonSendMut<'data, R>
+where
+    R: 'static,
+{
+    type Fetch = FetchNonSendWrite<R>;
+    type Rebind<'w> = NonSendMut<'w, R>;
+    const NON_SEND: bool = true;
+}
+
+#[cfg(feature = "resource")]
+#[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
+impl<'data, R> Query<'data> for Option<NonSend<'data, R>>
+where
+    R: 'static,
+{
+    type Fetch = FetchNonSendOptionRead<R>;
+    type Rebind<'w> = Option<NonSend<'w, R>>;
+    const NON_SEND: bool = true;
+}
+
+#[cfg(feature = "resource")]
+#[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
+impl<'data, R> Query<'data> for Option<NonSendMut<'data, R>>
+where
+    R: 'static,
+{
+    type Fetch = FetchNonSendOptionWrite<R>;
+    type Rebind<'w> = Option<NonSendMut<'w, R>>;
+    const NON_SEND: bool = true;
 }
@@ -5,10 +5,14 @@
 //! - immutable and mutable [references] of [components];
 //! - immutable and mutable [references] of [components] wrapped in [`Option`];
 //! - immutable and mutable [references] of [resources] via [markers](crate::marker)
-//! (if enabled by `resource` feature);
+//!   (if enabled by `resource` feature);
+//! - immutable and mutable references of non-send resources via
+//!   [`NonSend`](crate::marker::NonSend) and [`NonSendMut`](crate::marker::NonSendMut) markers
+//!   (if enabled by `resource` feature);
 //! - [`Not`](crate::marker::Not) marker type of components;
 //! - world views, such as [`ViewOne`][view_one], [`ViewOneMut`][view_one_mut],
-//! [`View`][view] and [`ViewMut`][view_mut];
+//!   [`View`][view] and [`ViewMut`][view_mut];
+//! - [`RemovedComponents`](crate::component::RemovedComponents) of the component type;
 //! - and [tuples] of arity 12 or less of types listed above.
 //!
 //! [references]: prim@reference
@@ -29,17 +33,19 @@ mod checked;
 mod impls;
 mod tuple;
 
-#[cfg(doc)]
-#[doc(hidden)]
-pub type QueryItem<'data, Q> = <<Q as Query<'data>>::Fetch as Fetch<'data>>::Item;
-
-#[cfg(not(doc))]
-type QueryItem<'data, Q> = <<Q as Query<'data>>::Fetch as Fetch<'data>>::Item;
-
 /// Type which can be queried by the [system](crate::system::System).
 ///
 /// This trait is **sealed** and cannot be implemented for types outside of `toucan_ecs`.
-pub trait Query<'data>: Sealed + From<QueryItem<'data, Self>> + 'data {
+pub trait Query<'data>: Sealed + 'data {
+    #[doc(hidden)]
+    type Fetch: Fetch<'data, Item = Self>;
+
+    #[doc(hidden)]
+    type Rebind<'w>: Query<'w>;
+
+    #[doc(hidden)]
+    const NON_SEND: bool = false;
+
     #[doc(hidden)]
-    type Fetch: Fetch<'data>;
+    const REMOVED: bool = false;
 }
@@ -4,10 +4,12 @@ macro_rules! system_query {
     ($($types:ident),*) => {
         impl<'data, $($types),*> Query<'data> for ($($types,)*)
         where
-            Self: From<($(QueryItem<'data, $types>,)*)>,
             $($types: Query<'data>,)*
         {
             type Fetch = ($($types::Fetch,)*);
+            type Rebind<'w> = ($($types::Rebind<'w>,)*);
+            const NON_SEND: bool = false $(|| $types::NON_SEND)*;
+            const REMOVED: bool = false $(|| $types::REMOVED)*;
         }
     };
 }
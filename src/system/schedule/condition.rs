@@ -0,0 +1,111 @@
+use crate::resource::{Resource, Tick, Ticks};
+use crate::world::World;
+
+type ErasedCondition = Box<dyn FnMut(&World, Tick) -> bool + Send>;
+
+/// Condition which determines if the system should be executed by the [schedule].
+///
+/// Condition is checked each time before the system is about to run.
+/// If condition is not satisfied, the system is skipped, so its
+/// [changes tracking](crate::marker::Res::is_changed) stays relative
+/// to the last run when it was actually executed.
+///
+/// Conditions can be created by [`resource_exists`], [`resource_added`]
+/// and [`resource_changed`] functions or from a custom function with [`RunCondition::new`].
+///
+/// # Examples
+///
+/// ```
+/// # use toucan_ecs::prelude::*;
+/// use toucan_ecs::system::resource_changed;
+///
+/// #[derive(Resource)]
+/// struct Settings {
+///     volume: u32,
+/// }
+///
+/// let mut world = World::new();
+/// world.create_resources(Settings { volume: 10 });
+///
+/// let mut schedule = Schedule::builder()
+///     .system(|settings: Res<Settings>| println!("volume: {}", settings.volume))
+///     .run_if(resource_changed::<Settings>())
+///     .build();
+///
+/// schedule.run(&mut world); // volume: 10
+/// schedule.run(&mut world); // skipped
+///
+/// world.get_resource_mut::<Settings>().unwrap().volume = 20;
+/// schedule.run(&mut world); // volume: 20
+/// ```
+///
+/// [schedule]: crate::system::Schedule
+#[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
+pub struct RunCondition(ErasedCondition);
+
+impl RunCondition {
+    /// Creates new condition from the function which checks the state of the [world](World).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// use toucan_ecs::system::RunCondition;
+    ///
+    /// let has_entities = RunCondition::new(|world: &World| !world.is_empty());
+    /// ```
+    pub fn new<F>(mut condition: F) -> Self
+    where
+        F: FnMut(&World) -> bool + Send + 'static,
+    {
+        Self(Box::new(move |world, _| condition(world)))
+    }
+
+    pub(super) fn check(&mut self, world: &World, last_run: Tick) -> bool {
+        (self.0)(world, last_run)
+    }
+}
+
+/// Creates [run condition](RunCondition) which is satisfied
+/// if the world contains resource of generic type.
+#[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
+pub fn resource_exists<R>() -> RunCondition
+where
+    R: Resource,
+{
+    RunCondition::new(|world| world.contains_resources::<R>())
+}
+
+/// Creates [run condition](RunCondition) which is satisfied
+/// if resource of generic type was added since the last run of the system.
+#[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
+pub fn resource_added<R>() -> RunCondition
+where
+    R: Resource,
+{
+    from_ticks::<R>(|ticks, last_run| ticks.is_added(last_run))
+}
+
+/// Creates [run condition](RunCondition) which is satisfied
+/// if resource of generic type was added or changed since the last run of the system.
+///
+/// This is useful for systems which should react only on changes of some state,
+/// for example, to rebuild user interface when settings were changed.
+#[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
+pub fn resource_changed<R>() -> RunCondition
+where
+    R: Resource,
+{
+    from_ticks::<R>(|ticks, last_run| ticks.is_changed(last_run))
+}
+
+fn from_ticks<R>(check: fn(&Ticks, Tick) -> bool) -> RunCondition
+where
+    R: Resource,
+{
+    RunCondition(Box::new(move |world, last_run| {
+        world
+            .resource_ticks::<R>()
+            .is_some_and(|ticks| check(ticks, last_run))
+    }))
+}
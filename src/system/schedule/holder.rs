@@ -1,49 +1,225 @@
-use std::mem::transmute;
-
+use crate::component::RemovalReader;
+#[cfg(feature = "resource")]
+use crate::resource::Tick;
 use crate::system::fetch::Fetch;
+use crate::system::foreach::{
+    ForeachHolder, ForeachSystem, FromForeachSystem, Query as ForeachQuery,
+};
 use crate::system::query::CheckedQuery;
 use crate::system::{Query, System};
 use crate::world::World;
 
 #[repr(transparent)]
-pub struct ErasedSystemHolder<'data>(Box<dyn Holdable<'data>>);
+pub struct ErasedSystemHolder(Box<dyn Holdable>);
 
-impl<'data, H> From<H> for ErasedSystemHolder<'data>
+impl<H> From<H> for ErasedSystemHolder
 where
-    H: Holdable<'data>,
+    H: Holdable,
 {
     fn from(holdable: H) -> Self {
         Self(Box::new(holdable))
     }
 }
 
-impl<'data> ErasedSystemHolder<'data> {
-    pub fn run(&mut self, world: &mut World) {
+impl ErasedSystemHolder {
+    pub fn name(&self) -> &'static str {
+        self.0.name()
+    }
+
+    #[cfg(feature = "resource")]
+    pub fn is_non_send(&self) -> bool {
+        self.0.is_non_send()
+    }
+
+    #[cfg(feature = "resource")]
+    pub fn last_run(&self) -> Tick {
+        self.0.last_run()
+    }
+
+    pub fn run(&mut self, world: &mut World) -> Option<Run> {
         self.0.run(world)
     }
 }
 
-trait Holdable<'data>: 'data {
-    fn run(&mut self, world: &mut World);
+pub struct Run {
+    pub entities: Option<usize>,
 }
 
-impl<'data, S, Q> Holdable<'data> for (S, CheckedQuery<'data, Q>)
+trait Holdable: Send + 'static {
+    fn name(&self) -> &'static str;
+
+    #[cfg(feature = "resource")]
+    fn is_non_send(&self) -> bool;
+
+    #[cfg(feature = "resource")]
+    fn last_run(&self) -> Tick;
+
+    fn run(&mut self, world: &mut World) -> Option<Run>;
+}
+
+pub struct SystemHolder<S, Q>
 where
-    S: System<'data, Q>,
-    Q: Query<'data>,
+    Q: Query<'static>,
 {
-    // noinspection RsUnnecessaryQualifications
-    fn run(&mut self, world: &mut World) {
-        // SAFETY: `world` contains data which is alive for `'data` lifetime
-        let world: &'data mut World = unsafe { transmute(world) };
-        let (system, _) = self;
-
-        let (entities, mut data) = world.split_refs_system_mut();
-        let entities = entities.iter();
-        let args = Q::Fetch::fetch(&entities, &mut data);
-        if let Ok(args) = args {
-            let args = args.into();
-            system.run(args)
+    system: S,
+    last_removal: Option<u64>,
+    reader: Option<RemovalReader>,
+    #[cfg(feature = "resource")]
+    last_run: Tick,
+    _checked: CheckedQuery<'static, Q>,
+}
+
+impl<S, Q> SystemHolder<S, Q>
+where
+    Q: Query<'static>,
+{
+    pub fn new(system: S) -> Self {
+        let _checked = CheckedQuery::new();
+        Self {
+            system,
+            last_removal: None,
+            reader: None,
+            #[cfg(feature = "resource")]
+            last_run: Tick::default(),
+            _checked,
         }
     }
 }
+
+impl<S, Q> Holdable for SystemHolder<S, Q>
+where
+    S: for<'data> System<'data, Q::Rebind<'data>> + Send,
+    Q: Query<'static>,
+{
+    fn name(&self) -> &'static str {
+        core::any::type_name::<S>()
+    }
+
+    #[cfg(feature = "resource")]
+    fn is_non_send(&self) -> bool {
+        Q::NON_SEND
+    }
+
+    #[cfg(feature = "resource")]
+    fn last_run(&self) -> Tick {
+        self.last_run
+    }
+
+    fn run(&mut self, world: &mut World) -> Option<Run> {
+        #[cfg(feature = "resource")]
+        let args = fetch::<Q::Rebind<'_>>(
+            world,
+            &mut self.last_removal,
+            &mut self.reader,
+            &mut self.last_run,
+        )?;
+        #[cfg(not(feature = "resource"))]
+        let args = fetch::<Q::Rebind<'_>>(world, &mut self.last_removal, &mut self.reader)?;
+        self.system.run(args);
+        Some(Run { entities: None })
+    }
+}
+
+pub struct ForeachSystemHolder<S, Q>
+where
+    Q: ForeachQuery<'static>,
+{
+    system: FromForeachSystem<S, Q>,
+    last_removal: Option<u64>,
+    reader: Option<RemovalReader>,
+    #[cfg(feature = "resource")]
+    last_run: Tick,
+    _checked: CheckedQuery<'static, ForeachHolder<'static, Q>>,
+}
+
+impl<S, Q> ForeachSystemHolder<S, Q>
+where
+    S: ForeachSystem<'static, Q>,
+    Q: ForeachQuery<'static>,
+{
+    pub fn new(system: S) -> Self {
+        let system = FromForeachSystem::from(system);
+        let _checked = CheckedQuery::new();
+        Self {
+            system,
+            last_removal: None,
+            reader: None,
+            #[cfg(feature = "resource")]
+            last_run: Tick::default(),
+            _checked,
+        }
+    }
+}
+
+impl<S, Q> Holdable for ForeachSystemHolder<S, Q>
+where
+    S: for<'data> ForeachSystem<'data, Q::Rebind<'data>> + Send,
+    Q: ForeachQuery<'static>,
+{
+    fn name(&self) -> &'static str {
+        core::any::type_name::<S>()
+    }
+
+    #[cfg(feature = "resource")]
+    fn is_non_send(&self) -> bool {
+        false
+    }
+
+    #[cfg(feature = "resource")]
+    fn last_run(&self) -> Tick {
+        self.last_run
+    }
+
+    fn run(&mut self, world: &mut World) -> Option<Run> {
+        #[cfg(feature = "resource")]
+        let holder = fetch::<ForeachHolder<'_, Q::Rebind<'_>>>(
+            world,
+            &mut self.last_removal,
+            &mut self.reader,
+            &mut self.last_run,
+        )?;
+        #[cfg(not(feature = "resource"))]
+        let holder = fetch::<ForeachHolder<'_, Q::Rebind<'_>>>(
+            world,
+            &mut self.last_removal,
+            &mut self.reader,
+        )?;
+        let visited = self.system.run_counted(holder);
+        Some(Run {
+            entities: Some(visited),
+        })
+    }
+}
+
+// noinspection RsUnnecessaryQualifications
+fn fetch<'data, Q>(
+    world: &'data mut World,
+    last_removal: &mut Option<u64>,
+    reader: &mut Option<RemovalReader>,
+    #[cfg(feature = "resource")] last_run: &mut Tick,
+) -> Option<Q>
+where
+    Q: Query<'data>,
+{
+    // new systems do not see removals which happened before their first run
+    let since = *last_removal.get_or_insert_with(|| world.removal_counter());
+    // removals are kept until every system which reads them has seen them
+    if Q::REMOVED && reader.is_none() {
+        *reader = Some(world.removal_reader());
+    }
+    let (entities, mut data) = world.split_refs_system_mut();
+    let this_removal = data.set_last_removal(since);
+    #[cfg(feature = "resource")]
+    let this_run = data.set_last_run(*last_run);
+    let entities = entities.iter();
+    let query = Q::Fetch::fetch(&entities, &mut data).ok()?;
+    *last_removal = Some(this_removal);
+    if let Some(reader) = reader {
+        reader.set_last_seen(this_removal);
+    }
+    #[cfg(feature = "resource")]
+    {
+        *last_run = this_run;
+    }
+    Some(query)
+}
@@ -1,51 +1,462 @@
-use holder::ErasedSystemHolder;
+use std::time::Instant;
 
-use crate::system::foreach::{ForeachSystem, FromForeachSystem, Query as ForeachQuery};
-use crate::system::query::CheckedQuery;
+#[cfg(feature = "resource")]
+pub use condition::{resource_added, resource_changed, resource_exists, RunCondition};
+use holder::{ErasedSystemHolder, ForeachSystemHolder, Run, SystemHolder};
+use slotmap::SlotMap;
+pub use stats::{ScheduleStats, SystemStats};
+
+#[cfg(feature = "resource")]
+use crate::resource::{Resource, ResourceTypeId};
+use crate::system::foreach::{ForeachSystem, Query as ForeachQuery};
+#[cfg(feature = "resource")]
+use crate::world::FromWorld;
 use crate::world::World;
 
 use super::{Query, System};
 
+#[cfg(feature = "resource")]
+mod condition;
 mod holder;
+mod stats;
+
+slotmap::new_key_type! {
+    /// Unique handle of the system in the [schedule](Schedule).
+    ///
+    /// It stays valid until the system is removed from the schedule,
+    /// even if other systems were inserted, removed or reordered.
+    pub struct SystemId;
+}
 
 /// A schedule of systems for execution.
 ///
 /// This struct is used to run systems one by one in expected order
 /// which is defined by sequential calls of [`ScheduleBuilder::system`] function.
-#[repr(transparent)]
-pub struct Schedule<'data> {
-    systems: Vec<ErasedSystemHolder<'data>>,
+///
+/// Schedule does not borrow the [world](World), so it can be stored
+/// next to the world or sent to another thread.
+///
+/// Systems can also be inserted, removed, disabled or reordered
+/// after the schedule was built.
+///
+/// # Examples
+///
+/// ```
+/// use toucan_ecs::prelude::*;
+///
+/// let mut world = World::new();
+/// let mut schedule = Schedule::builder()
+///     .system(|| println!("Hello, World"))
+///     .build();
+///
+/// let id = schedule.insert_system(|| println!("Inserted at runtime"));
+/// schedule.move_system(id, 0);
+/// schedule.run(&mut world);
+///
+/// schedule.disable(id);
+/// schedule.run(&mut world);
+///
+/// assert!(schedule.remove_system(id));
+/// assert_eq!(schedule.len(), 1);
+/// ```
+pub struct Schedule {
+    systems: SlotMap<SystemId, SystemEntry>,
+    order: Vec<SystemId>,
+    stats: Option<ScheduleStats>,
+    #[cfg(feature = "resource")]
+    initializers: Vec<Initializer>,
+}
+
+#[cfg(feature = "resource")]
+type Initializer = (ResourceTypeId, fn(&mut World));
+
+struct SystemEntry {
+    holder: ErasedSystemHolder,
+    enabled: bool,
+    #[cfg(feature = "resource")]
+    condition: Option<RunCondition>,
+}
+
+impl SystemEntry {
+    fn new(holder: ErasedSystemHolder) -> Self {
+        Self {
+            holder,
+            enabled: true,
+            #[cfg(feature = "resource")]
+            condition: None,
+        }
+    }
+
+    #[cfg(feature = "resource")]
+    fn should_run(&mut self, world: &World) -> bool {
+        if !self.enabled {
+            return false;
+        }
+        match self.condition.as_mut() {
+            None => true,
+            Some(condition) => condition.check(world, self.holder.last_run()),
+        }
+    }
+
+    #[cfg(not(feature = "resource"))]
+    fn should_run(&mut self, _: &World) -> bool {
+        self.enabled
+    }
 }
 
-impl<'data> Schedule<'data> {
+impl Schedule {
     /// Creates a new [schedule](Schedule) builder.
-    pub fn builder() -> ScheduleBuilder<'data> {
+    pub fn builder() -> ScheduleBuilder {
         ScheduleBuilder::new()
     }
 
-    /// Executes all the systems inside of schedule
+    /// Executes all the enabled systems inside of schedule
     /// in the order of their addition.
+    ///
+    /// After all the systems are executed, [removals](crate::component::RemovedComponents) of components
+    /// which were seen by every system reading them (in this and any other schedule) are dropped.
+    ///
+    /// If enabled by the feature `tracing`, each system is executed
+    /// inside of its own [span](https://docs.rs/tracing/latest/tracing/struct.Span.html).
+    ///
+    /// # Panics
+    ///
+    /// This function will panic before executing any system
+    /// if some of enabled systems require [non-send](crate::marker::NonSend) resources
+    /// and it is called on the thread other than the thread
+    /// non-send resources of the world are bound to.
     pub fn run(&mut self, world: &mut World) {
-        for system in self.systems.iter_mut() {
-            system.run(world);
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!("schedule").entered();
+
+        #[cfg(feature = "resource")]
+        if self.has_non_send() {
+            world.check_non_send_thread();
+        }
+        #[cfg(feature = "resource")]
+        for (_, initializer) in self.initializers.iter() {
+            initializer(world);
+        }
+
+        let systems = &mut self.systems;
+        match self.stats.as_mut() {
+            None => {
+                for &id in self.order.iter() {
+                    let entry = &mut systems[id];
+                    if entry.should_run(world) {
+                        run_system(&mut entry.holder, world);
+                    }
+                }
+            }
+            Some(stats) => {
+                let start = Instant::now();
+                for (index, &id) in self.order.iter().enumerate() {
+                    let entry = &mut systems[id];
+                    if !entry.should_run(world) {
+                        continue;
+                    }
+                    let system_start = Instant::now();
+                    if let Some(run) = run_system(&mut entry.holder, world) {
+                        let duration = system_start.elapsed();
+                        stats.system_mut(index).record(duration, run.entities);
+                    }
+                }
+                stats.record_run(start.elapsed());
+            }
+        }
+        world.prune_trackers();
+    }
+
+    /// Adds the system to the end of the [schedule](Schedule)
+    /// and returns its unique [handle](SystemId).
+    ///
+    /// See [`ScheduleBuilder::system`] for more details.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if provided query does not satisfies
+    /// the first rule of references described in
+    /// **References and Borrowing** section of [**Rust Book**][rust_book]:
+    ///
+    /// > - *At any given time, you can have either **one** mutable reference
+    /// >   or **any** number of immutable references.*
+    ///
+    /// [rust_book]: https://doc.rust-lang.org/book/ch04-02-references-and-borrowing.html#the-rules-of-references
+    pub fn insert_system<S, Q>(&mut self, system: S) -> SystemId
+    where
+        S: System<'static, Q> + for<'data> System<'data, Q::Rebind<'data>> + Send,
+        Q: Query<'static>,
+    {
+        let erased = SystemHolder::new(system).into();
+        self.insert_erased(SystemEntry::new(erased))
+    }
+
+    /// Adds the foreach system to the end of the [schedule](Schedule)
+    /// and returns its unique [handle](SystemId).
+    ///
+    /// See [`ScheduleBuilder::foreach_system`] for more details.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if provided query does not satisfies
+    /// the first rule of references described in
+    /// **References and Borrowing** section of [**Rust Book**][rust_book]:
+    ///
+    /// > - *At any given time, you can have either **one** mutable reference
+    /// >   or **any** number of immutable references.*
+    ///
+    /// [rust_book]: https://doc.rust-lang.org/book/ch04-02-references-and-borrowing.html#the-rules-of-references
+    pub fn insert_foreach_system<S, Q>(&mut self, system: S) -> SystemId
+    where
+        S: ForeachSystem<'static, Q> + for<'data> ForeachSystem<'data, Q::Rebind<'data>> + Send,
+        Q: ForeachQuery<'static>,
+    {
+        let erased = ForeachSystemHolder::new(system).into();
+        self.insert_erased(SystemEntry::new(erased))
+    }
+
+    /// Removes the system from the [schedule](Schedule).
+    ///
+    /// Returns `true` if the system was present in the schedule.
+    pub fn remove_system(&mut self, id: SystemId) -> bool {
+        let index = match self.position(id) {
+            None => return false,
+            Some(index) => index,
+        };
+        self.systems.remove(id);
+        self.order.remove(index);
+        if let Some(stats) = self.stats.as_mut() {
+            stats.remove(index);
+        }
+        true
+    }
+
+    /// Returns `true` if the system is present in the [schedule](Schedule).
+    pub fn contains_system(&self, id: SystemId) -> bool {
+        self.systems.contains_key(id)
+    }
+
+    /// Enables the system, so it will be executed on the next [run](Schedule::run).
+    ///
+    /// Returns `true` if the system was present in the schedule.
+    pub fn enable(&mut self, id: SystemId) -> bool {
+        self.set_enabled(id, true)
+    }
+
+    /// Disables the system, so it will be skipped by the [run](Schedule::run)
+    /// until it is [enabled](Schedule::enable) again.
+    ///
+    /// Returns `true` if the system was present in the schedule.
+    pub fn disable(&mut self, id: SystemId) -> bool {
+        self.set_enabled(id, false)
+    }
+
+    /// Returns `true` if the system is present in the [schedule](Schedule) and is enabled.
+    pub fn is_enabled(&self, id: SystemId) -> bool {
+        self.systems.get(id).is_some_and(|entry| entry.enabled)
+    }
+
+    /// Sets the [condition](RunCondition) of the system execution,
+    /// replacing the previous one.
+    /// If `condition` is [`None`](Option::None), the system will be executed on each run.
+    ///
+    /// Returns `true` if the system was present in the [schedule](Schedule).
+    #[cfg(feature = "resource")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
+    pub fn set_condition(&mut self, id: SystemId, condition: Option<RunCondition>) -> bool {
+        match self.systems.get_mut(id) {
+            None => false,
+            Some(entry) => {
+                entry.condition = condition;
+                true
+            }
+        }
+    }
+
+    /// Returns position of the system in the execution order
+    /// or [`None`](Option::None) if the system is not present in the [schedule](Schedule).
+    pub fn position(&self, id: SystemId) -> Option<usize> {
+        self.order.iter().position(|&other| other == id)
+    }
+
+    /// Moves the system to the provided position in the execution order,
+    /// shifting all systems after it.
+    ///
+    /// Returns `true` if the system was present in the [schedule](Schedule).
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `index` is out of bounds.
+    pub fn move_system(&mut self, id: SystemId, index: usize) -> bool {
+        assert!(index < self.order.len(), "index out of bounds");
+        let from = match self.position(id) {
+            None => return false,
+            Some(from) => from,
+        };
+        let id = self.order.remove(from);
+        self.order.insert(index, id);
+        if let Some(stats) = self.stats.as_mut() {
+            stats.move_system(from, index);
+        }
+        true
+    }
+
+    /// Returns iterator over handles of all the systems
+    /// in the order of their execution.
+    pub fn system_ids(&self) -> impl ExactSizeIterator<Item = SystemId> + '_ {
+        self.order.iter().copied()
+    }
+
+    /// Returns count of systems in the [schedule](Schedule).
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    /// Returns `true` if the [schedule](Schedule) contains no systems.
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    /// Enables collecting of the [statistics](ScheduleStats) of this schedule.
+    ///
+    /// Does nothing if statistics are already enabled.
+    pub fn enable_stats(&mut self) {
+        if self.stats.is_none() {
+            self.stats = Some(self.new_stats());
+        }
+    }
+
+    /// Disables collecting of the [statistics](ScheduleStats) of this schedule.
+    ///
+    /// All the statistics collected before will be lost.
+    pub fn disable_stats(&mut self) {
+        self.stats = None;
+    }
+
+    /// Returns [statistics](ScheduleStats) of this schedule
+    /// or [`None`](Option::None) if they are not enabled.
+    pub fn stats(&self) -> Option<&ScheduleStats> {
+        self.stats.as_ref()
+    }
+
+    /// Resets [statistics](ScheduleStats) of this schedule if they are enabled.
+    pub fn reset_stats(&mut self) {
+        if let Some(stats) = self.stats.as_mut() {
+            stats.reset();
         }
     }
+
+    fn insert_erased(&mut self, entry: SystemEntry) -> SystemId {
+        let name = entry.holder.name();
+        let id = self.systems.insert(entry);
+        self.order.push(id);
+        if let Some(stats) = self.stats.as_mut() {
+            stats.push(id, name);
+        }
+        id
+    }
+
+    #[cfg(feature = "resource")]
+    fn has_non_send(&self) -> bool {
+        self.systems
+            .values()
+            .any(|entry| entry.enabled && entry.holder.is_non_send())
+    }
+
+    fn set_enabled(&mut self, id: SystemId, enabled: bool) -> bool {
+        match self.systems.get_mut(id) {
+            None => false,
+            Some(entry) => {
+                entry.enabled = enabled;
+                true
+            }
+        }
+    }
+
+    fn new_stats(&self) -> ScheduleStats {
+        let systems = &self.systems;
+        let names = self.order.iter().map(|&id| (id, systems[id].holder.name()));
+        ScheduleStats::new(names)
+    }
+}
+
+fn run_system(system: &mut ErasedSystemHolder, world: &mut World) -> Option<Run> {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::info_span!("system", name = system.name()).entered();
+
+    system.run(world)
 }
 
 /// A builder for [`Schedule`] struct.
-pub struct ScheduleBuilder<'data> {
-    systems: Vec<ErasedSystemHolder<'data>>,
+pub struct ScheduleBuilder {
+    systems: Vec<SystemEntry>,
+    stats: bool,
+    #[cfg(feature = "resource")]
+    initializers: Vec<Initializer>,
 }
 
-impl<'data> ScheduleBuilder<'data> {
+impl ScheduleBuilder {
     fn new() -> Self {
         Self {
             systems: Vec::new(),
+            stats: false,
+            #[cfg(feature = "resource")]
+            initializers: Vec::new(),
+        }
+    }
+
+    /// Enables collecting of the [statistics](ScheduleStats) of the [schedule](Schedule).
+    pub fn with_stats(mut self) -> Self {
+        self.stats = true;
+        self
+    }
+
+    /// Adds the initializer of the resource to the [schedule](Schedule).
+    ///
+    /// Before executing any system, the schedule will create this resource
+    /// by [`World::init_resource`] associated function
+    /// if the world does not contain resource of this type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// #[derive(Default, Resource)]
+    /// struct Frame(u64);
+    ///
+    /// let mut world = World::new();
+    /// let mut schedule = Schedule::builder()
+    ///     .init_resource::<Frame>()
+    ///     .system(|mut frame: ResMut<Frame>| frame.0 += 1)
+    ///     .build();
+    ///
+    /// schedule.run(&mut world);
+    /// schedule.run(&mut world);
+    /// assert_eq!(world.get_resource::<Frame>().unwrap().0, 2);
+    /// ```
+    #[cfg(feature = "resource")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
+    pub fn init_resource<R>(mut self) -> Self
+    where
+        R: Resource + FromWorld,
+    {
+        let type_id = ResourceTypeId::of::<R>();
+        if self.initializers.iter().all(|&(other, _)| other != type_id) {
+            self.initializers.push((type_id, World::init_resource::<R>));
         }
+        self
     }
 
     /// Adds the system to the [schedule](Schedule).
     ///
+    /// System must accept its arguments for any lifetime of the [world](World) borrow,
+    /// so it cannot capture any borrowed data.
+    ///
+    /// For the same reason, system which is generic over the query of its view
+    /// cannot be added with the query bound to one lifetime of the world borrow,
+    /// like `view_system::<(Entity, &Position)>` with `view: View<'data, Q>` argument.
+    /// Such system should accept the view of [rebound](crate::world::query::Query::RebindRef) query instead.
+    ///
     /// # Panics
     ///
     /// This function will panic if provided query does not satisfies
@@ -53,21 +464,54 @@ impl<'data> ScheduleBuilder<'data> {
     /// **References and Borrowing** section of [**Rust Book**][rust_book]:
     ///
     /// > - *At any given time, you can have either **one** mutable reference
-    /// or **any** number of immutable references.*
+    /// >   or **any** number of immutable references.*
     ///
     /// [rust_book]: https://doc.rust-lang.org/book/ch04-02-references-and-borrowing.html#the-rules-of-references
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// use std::fmt::Debug;
+    ///
+    /// use toucan_ecs::world::query::Query;
+    ///
+    /// #[derive(Copy, Clone, Component, Debug)]
+    /// struct Position(f32, f32);
+    ///
+    /// fn view_system<'data, Q>(view: View<'data, Q::RebindRef<'data>>)
+    /// where
+    ///     Q: Query<'static>,
+    ///     for<'w> Q::RebindRef<'w>: Debug,
+    /// {
+    ///     for item in view {
+    ///         println!("item: {:?}", item)
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.create_with(Position(0.0, 0.0));
+    ///
+    /// let mut schedule = Schedule::builder()
+    ///     .system(view_system::<(Entity, &Position)>)
+    ///     .build();
+    /// schedule.run(&mut world);
+    /// ```
     pub fn system<S, Q>(mut self, system: S) -> Self
     where
-        S: System<'data, Q>,
-        Q: Query<'data>,
+        S: System<'static, Q> + for<'data> System<'data, Q::Rebind<'data>> + Send,
+        Q: Query<'static>,
     {
-        let erased = (system, CheckedQuery::new()).into();
-        self.systems.push(erased);
+        let erased = SystemHolder::new(system).into();
+        self.systems.push(SystemEntry::new(erased));
         self
     }
 
     /// Adds the foreach system to the [schedule](Schedule).
     ///
+    /// Foreach system must accept its arguments for any lifetime of the [world](World) borrow,
+    /// so it cannot capture any borrowed data.
+    ///
     /// # Panics
     ///
     /// This function will panic if provided query does not satisfies
@@ -75,21 +519,68 @@ impl<'data> ScheduleBuilder<'data> {
     /// **References and Borrowing** section of [**Rust Book**][rust_book]:
     ///
     /// > - *At any given time, you can have either **one** mutable reference
-    /// or **any** number of immutable references.*
+    /// >   or **any** number of immutable references.*
     ///
     /// [rust_book]: https://doc.rust-lang.org/book/ch04-02-references-and-borrowing.html#the-rules-of-references
-    pub fn foreach_system<S, Q>(self, system: S) -> Self
+    pub fn foreach_system<S, Q>(mut self, system: S) -> Self
     where
-        S: ForeachSystem<'data, Q>,
-        Q: ForeachQuery<'data>,
+        S: ForeachSystem<'static, Q> + for<'data> ForeachSystem<'data, Q::Rebind<'data>> + Send,
+        Q: ForeachQuery<'static>,
     {
-        let system = FromForeachSystem::from(system);
-        self.system(system)
+        let erased = ForeachSystemHolder::new(system).into();
+        self.systems.push(SystemEntry::new(erased));
+        self
+    }
+
+    /// Sets the [condition](RunCondition) of execution of the last added system,
+    /// replacing the previous one.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if no systems were added to the builder yet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// use toucan_ecs::system::resource_changed;
+    ///
+    /// #[derive(Resource)]
+    /// struct Settings {
+    ///     scale: f32,
+    /// }
+    ///
+    /// let schedule = Schedule::builder()
+    ///     .system(|settings: Res<Settings>| println!("rebuild UI with scale {}", settings.scale))
+    ///     .run_if(resource_changed::<Settings>())
+    ///     .build();
+    /// ```
+    #[cfg(feature = "resource")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
+    pub fn run_if(mut self, condition: RunCondition) -> Self {
+        let entry = self
+            .systems
+            .last_mut()
+            .expect("no systems were added to the schedule");
+        entry.condition = Some(condition);
+        self
     }
 
     /// Finalizes the builder into a [schedule](Schedule).
-    pub fn build(self) -> Schedule<'data> {
-        let systems = self.systems;
-        Schedule { systems }
+    pub fn build(self) -> Schedule {
+        let mut schedule = Schedule {
+            systems: SlotMap::with_key(),
+            order: Vec::with_capacity(self.systems.len()),
+            stats: None,
+            #[cfg(feature = "resource")]
+            initializers: self.initializers,
+        };
+        for system in self.systems {
+            schedule.insert_erased(system);
+        }
+        if self.stats {
+            schedule.enable_stats();
+        }
+        schedule
     }
 }
@@ -0,0 +1,218 @@
+use std::time::Duration;
+
+use super::SystemId;
+
+/// Statistics of the [schedule](super::Schedule) execution.
+///
+/// Statistics are collected only if they were enabled by
+/// [`ScheduleBuilder::with_stats`](super::ScheduleBuilder::with_stats())
+/// or [`Schedule::enable_stats`](super::Schedule::enable_stats()) function call.
+///
+/// # Examples
+///
+/// ```
+/// use toucan_ecs::prelude::*;
+///
+/// #[derive(Copy, Clone, Component)]
+/// struct ID(u32);
+///
+/// let mut world = World::new();
+/// world.extend_with([ID(0), ID(1), ID(2)]);
+///
+/// let mut schedule = Schedule::builder()
+///     .with_stats()
+///     .system(|| println!("Hello, World"))
+///     .foreach_system(|id: &mut ID| id.0 += 1)
+///     .build();
+/// schedule.run(&mut world);
+///
+/// let stats = schedule.stats().unwrap();
+/// assert_eq!(stats.runs(), 1);
+/// for system in stats.iter() {
+///     println!("{} took {:?}", system.name(), system.total_duration());
+/// }
+/// let foreach = stats.iter().last().unwrap();
+/// assert_eq!(foreach.entities(), Some(3));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ScheduleStats {
+    runs: u64,
+    total_duration: Duration,
+    systems: Vec<SystemStats>,
+}
+
+impl ScheduleStats {
+    pub(super) fn new<I>(names: I) -> Self
+    where
+        I: IntoIterator<Item = (SystemId, &'static str)>,
+    {
+        let systems = names
+            .into_iter()
+            .map(|(id, name)| SystemStats::new(id, name))
+            .collect();
+        Self {
+            systems,
+            ..Self::default()
+        }
+    }
+
+    pub(super) fn record_run(&mut self, duration: Duration) {
+        self.runs += 1;
+        self.total_duration += duration;
+    }
+
+    pub(super) fn push(&mut self, id: SystemId, name: &'static str) {
+        self.systems.push(SystemStats::new(id, name));
+    }
+
+    pub(super) fn remove(&mut self, index: usize) {
+        self.systems.remove(index);
+    }
+
+    pub(super) fn move_system(&mut self, from: usize, to: usize) {
+        let system = self.systems.remove(from);
+        self.systems.insert(to, system);
+    }
+
+    pub(super) fn system_mut(&mut self, index: usize) -> &mut SystemStats {
+        &mut self.systems[index]
+    }
+
+    /// Returns how many times the schedule was executed.
+    pub fn runs(&self) -> u64 {
+        self.runs
+    }
+
+    /// Returns total wall-clock duration of all the schedule executions.
+    pub fn total_duration(&self) -> Duration {
+        self.total_duration
+    }
+
+    /// Returns count of systems in the schedule.
+    pub fn len(&self) -> usize {
+        self.systems.len()
+    }
+
+    /// Returns `true` if the schedule contains no systems.
+    pub fn is_empty(&self) -> bool {
+        self.systems.is_empty()
+    }
+
+    /// Returns statistics of the system by its position in the execution order.
+    pub fn get(&self, index: usize) -> Option<&SystemStats> {
+        self.systems.get(index)
+    }
+
+    /// Returns statistics of the system by its [handle](SystemId).
+    pub fn system(&self, id: SystemId) -> Option<&SystemStats> {
+        self.systems.iter().find(|system| system.id == id)
+    }
+
+    /// Returns iterator over statistics of all the systems
+    /// in the order of their execution.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &SystemStats> + '_ {
+        self.systems.iter()
+    }
+
+    /// Resets all the collected statistics.
+    pub fn reset(&mut self) {
+        self.runs = 0;
+        self.total_duration = Duration::ZERO;
+        self.systems.iter_mut().for_each(SystemStats::reset);
+    }
+}
+
+/// Statistics of one [system](crate::system::System) in the schedule.
+#[derive(Debug, Clone)]
+pub struct SystemStats {
+    id: SystemId,
+    name: &'static str,
+    invocations: u64,
+    total_duration: Duration,
+    last_duration: Duration,
+    entities: Option<u64>,
+    last_entities: Option<usize>,
+}
+
+impl SystemStats {
+    fn new(id: SystemId, name: &'static str) -> Self {
+        Self {
+            id,
+            name,
+            invocations: 0,
+            total_duration: Duration::ZERO,
+            last_duration: Duration::ZERO,
+            entities: None,
+            last_entities: None,
+        }
+    }
+
+    pub(super) fn record(&mut self, duration: Duration, entities: Option<usize>) {
+        self.invocations += 1;
+        self.total_duration += duration;
+        self.last_duration = duration;
+        if let Some(entities) = entities {
+            let total = self.entities.get_or_insert(0);
+            *total += entities as u64;
+        }
+        self.last_entities = entities;
+    }
+
+    fn reset(&mut self) {
+        *self = Self::new(self.id, self.name);
+    }
+
+    /// Returns [handle](SystemId) of the system in the schedule.
+    pub fn id(&self) -> SystemId {
+        self.id
+    }
+
+    /// Returns type name of the system.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns how many times the system was invoked.
+    ///
+    /// Note that system is not invoked if its arguments cannot be fetched,
+    /// for example, if required resource does not exist in the world.
+    pub fn invocations(&self) -> u64 {
+        self.invocations
+    }
+
+    /// Returns total wall-clock duration of all the system invocations.
+    pub fn total_duration(&self) -> Duration {
+        self.total_duration
+    }
+
+    /// Returns wall-clock duration of the last system invocation.
+    pub fn last_duration(&self) -> Duration {
+        self.last_duration
+    }
+
+    /// Returns mean wall-clock duration of the system invocation.
+    pub fn mean_duration(&self) -> Duration {
+        match self.invocations {
+            0 => Duration::ZERO,
+            invocations => self.total_duration.div_f64(invocations as f64),
+        }
+    }
+
+    /// Returns total count of entities visited by the system.
+    ///
+    /// Returns [`None`](Option::None) if the system is not a
+    /// [foreach system](crate::system::foreach::ForeachSystem)
+    /// or it was not invoked yet.
+    pub fn entities(&self) -> Option<u64> {
+        self.entities
+    }
+
+    /// Returns count of entities visited by the last system invocation.
+    ///
+    /// Returns [`None`](Option::None) if the system is not a
+    /// [foreach system](crate::system::foreach::ForeachSystem)
+    /// or it was not invoked yet.
+    pub fn last_entities(&self) -> Option<usize> {
+        self.last_entities
+    }
+}
@@ -2,7 +2,7 @@ use std::any::TypeId;
 
 use crate::component::ComponentTypeId;
 #[cfg(feature = "resource")]
-use crate::resource::ResourceTypeId;
+use crate::resource::{NonSendTypeId, ResourceTypeId};
 
 #[repr(transparent)]
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
@@ -23,6 +23,14 @@ impl From<ResourceTypeId> for DataTypeId {
     }
 }
 
+#[cfg(feature = "resource")]
+impl From<NonSendTypeId> for DataTypeId {
+    fn from(type_id: NonSendTypeId) -> Self {
+        let type_id = type_id.into();
+        Self(type_id)
+    }
+}
+
 impl From<DataTypeId> for TypeId {
     fn from(type_id: DataTypeId) -> Self {
         type_id.0
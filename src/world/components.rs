@@ -145,12 +145,12 @@ impl<'world> Components<'world> {
     ///     println!("component: {:?}", *component)
     /// }
     /// ```
-    pub fn view_one<C>(&self) -> ViewOne<C>
+    pub fn view_one<C>(&self) -> ViewOne<'_, C>
     where
         C: Component,
     {
         let storage = self.components.get_storage::<C>();
-        ViewOne::new(storage)
+        ViewOne::new(storage, self.entities)
     }
 
     /// Creates a [view](View) of the multiple component types.
@@ -186,12 +186,14 @@ impl<'world> Components<'world> {
         View::new(entities, &mut data)
     }
 
-    fn split_refs(&self) -> (&EntityRegistry, WorldRefs) {
+    fn split_refs(&self) -> (&EntityRegistry, WorldRefs<'_>) {
         let entities = self.entities;
         let refs = WorldRefs {
             storages: StorageRefs::from(self.components),
             #[cfg(feature = "resource")]
             resources: ResourceRefs::default(),
+            #[cfg(feature = "resource")]
+            non_send: None,
         };
         (entities, refs)
     }
@@ -361,12 +363,12 @@ impl<'world> ComponentsMut<'world> {
     ///     println!("component: {:?}", *component)
     /// }
     /// ```
-    pub fn view_one<C>(&self) -> ViewOne<C>
+    pub fn view_one<C>(&self) -> ViewOne<'_, C>
     where
         C: Component,
     {
         let storage = self.components.get_storage::<C>();
-        ViewOne::new(storage)
+        ViewOne::new(storage, self.entities)
     }
 
     /// Creates a [view](ViewOneMut) of the component type.
@@ -392,12 +394,12 @@ impl<'world> ComponentsMut<'world> {
     ///     println!("component: {:?}", *component)
     /// }
     /// ```
-    pub fn view_one_mut<C>(&mut self) -> ViewOneMut<C>
+    pub fn view_one_mut<C>(&mut self) -> ViewOneMut<'_, C>
     where
         C: Component,
     {
-        let storage = self.components.get_storage_mut::<C>();
-        ViewOneMut::new(storage)
+        let (storage, touch) = self.components.get_storage_mut::<C>();
+        ViewOneMut::new(storage, self.entities, touch)
     }
 
     /// Creates a [view](View) of the multiple component types.
@@ -448,7 +450,7 @@ impl<'world> ComponentsMut<'world> {
     /// **References and Borrowing** section of [**Rust Book**][rust_book]:
     ///
     /// > - *At any given time, you can have either **one** mutable reference
-    /// or **any** number of immutable references.*
+    /// >   or **any** number of immutable references.*
     ///
     /// # Examples
     ///
@@ -497,22 +499,26 @@ impl<'world> ComponentsMut<'world> {
         ViewMut::new(entities, &mut data)
     }
 
-    fn split_refs(&self) -> (&EntityRegistry, WorldRefs) {
+    fn split_refs(&self) -> (&EntityRegistry, WorldRefs<'_>) {
         let entities = self.entities;
         let refs = WorldRefs {
             storages: StorageRefs::from(&*self.components),
             #[cfg(feature = "resource")]
             resources: ResourceRefs::default(),
+            #[cfg(feature = "resource")]
+            non_send: None,
         };
         (entities, refs)
     }
 
-    fn split_refs_mut(&mut self) -> (&EntityRegistry, WorldRefs) {
+    fn split_refs_mut(&mut self) -> (&EntityRegistry, WorldRefs<'_>) {
         let entities = self.entities;
         let refs = WorldRefs {
             storages: StorageRefs::from(&mut *self.components),
             #[cfg(feature = "resource")]
             resources: ResourceRefs::default(),
+            #[cfg(feature = "resource")]
+            non_send: None,
         };
         (entities, refs)
     }
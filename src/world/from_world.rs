@@ -0,0 +1,62 @@
+use crate::world::World;
+
+/// Trait for types which can be created from the data of the [world](World).
+///
+/// This trait is implemented for all the types which implement [`Default`] trait.
+///
+/// It is mostly used to initialize [resources](crate::resource::Resource)
+/// lazily with [`World::init_resource`] associated function
+/// (if enabled by the feature `resource`).
+///
+/// ## How can I implement `FromWorld`?
+///
+/// You can implement this trait manually:
+///
+/// ```
+/// use toucan_ecs::prelude::*;
+///
+/// #[derive(Copy, Clone, Component)]
+/// struct Position(f32);
+///
+/// struct Count(usize);
+///
+/// impl FromWorld for Count {
+///     fn from_world(world: &mut World) -> Self {
+///         Self(world.view_one::<Position>().len())
+///     }
+/// }
+/// ```
+///
+/// or with `#[resource(init = "...")]` helper attribute of the
+/// [`Resource`](crate::resource::Resource) derive macro
+/// if the type is a resource (requires feature `resource`):
+///
+/// ```
+/// use toucan_ecs::prelude::*;
+///
+/// #[derive(Copy, Clone, Component)]
+/// struct Position(f32);
+///
+/// #[derive(Resource)]
+/// #[resource(init = "Count::new")]
+/// struct Count(usize);
+///
+/// impl Count {
+///     fn new(world: &mut World) -> Self {
+///         Self(world.view_one::<Position>().len())
+///     }
+/// }
+/// ```
+pub trait FromWorld {
+    /// Creates new value from the data of the [world](World).
+    fn from_world(world: &mut World) -> Self;
+}
+
+impl<T> FromWorld for T
+where
+    T: Default,
+{
+    fn from_world(_: &mut World) -> Self {
+        T::default()
+    }
+}
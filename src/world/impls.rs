@@ -1,18 +1,35 @@
+use std::collections::HashSet;
+#[cfg(feature = "resource")]
+use std::panic::{self, AssertUnwindSafe};
+
 use crate::component::{
-    Component, ComponentSet, Registry as ComponentRegistry, RegistryRefs as StorageRefs,
+    Component, ComponentDescriptor, ComponentId, ComponentSet, IndexKey,
+    Registry as ComponentRegistry, RegistryRefs as StorageRefs, RemovalReader, RemovedComponents,
 };
-use crate::entity::{Entity, EntityBuilder, Registry as EntityRegistry};
+use crate::entity::{
+    Entity, EntityBuilder, EntityDebug, EntityMap, MapEntities, Names, Prefab,
+    Registry as EntityRegistry,
+};
+use crate::reflect::{Reflect, ReflectError, Registry as ReflectRegistry};
 use crate::world::components::{Components, ComponentsMut};
-use crate::world::query::{Query, QueryMut};
-use crate::world::view::{View, ViewMut, ViewOne, ViewOneMut};
+use crate::world::patch::Registry as PatchRegistry;
+use crate::world::query::{DynamicQuery, Query, QueryMut};
+use crate::world::view::{DynamicView, View, ViewMut, ViewOne, ViewOneMut};
 use crate::world::world_refs::WorldRefs;
-use crate::world::Entry;
+use crate::world::{
+    Entry, MergeError, MergePolicy, PatchComponent, PatchError, WorldInfo, WorldPatch,
+    WorldSnapshot,
+};
 #[cfg(feature = "resource")]
 use crate::{
-    resource::{Registry as ResourceRegistry, RegistryRefs as ResourceRefs, Resource, ResourceSet},
+    resource::{
+        NonSendRefs, NonSendRegistry, Registry as ResourceRegistry, RegistryRefs as ResourceRefs,
+        Resource, ResourceSet, Ticks,
+    },
     system::foreach::ForeachHolder,
     world::query::{ResourceQuery, ResourceQueryMut},
     world::resources::{Resources, ResourcesMut},
+    world::FromWorld,
 };
 
 /// **Immutable** borrowed type of the [world](crate::world::World) that contains
@@ -37,8 +54,13 @@ pub type SplitMut<'world> = (ComponentsMut<'world>, ResourcesMut<'world>);
 pub struct World {
     entities: EntityRegistry,
     components: ComponentRegistry,
+    names: Names,
+    reflect: ReflectRegistry,
+    patches: PatchRegistry,
     #[cfg(feature = "resource")]
     resources: ResourceRegistry,
+    #[cfg(feature = "resource")]
+    non_send: NonSendRegistry,
 }
 
 impl Default for World {
@@ -60,8 +82,13 @@ impl World {
         Self {
             entities: EntityRegistry::default(),
             components: ComponentRegistry::default(),
+            names: Names::default(),
+            reflect: ReflectRegistry::default(),
+            patches: PatchRegistry::default(),
             #[cfg(feature = "resource")]
             resources: ResourceRegistry::default(),
+            #[cfg(feature = "resource")]
+            non_send: NonSendRegistry::default(),
         }
     }
 
@@ -109,6 +136,57 @@ impl World {
         self.resources.create(set)
     }
 
+    /// Creates new resource initialized by [`FromWorld`] trait implementation
+    /// if the world does not contain resource of this type yet.
+    ///
+    /// Resource can be initialized either with [`Default`] trait implementation
+    /// or from other data of the world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// #[derive(Copy, Clone, Component)]
+    /// struct Position(f32);
+    ///
+    /// #[derive(Default, Resource)]
+    /// struct Frame(u64);
+    ///
+    /// #[derive(Resource)]
+    /// #[resource(init = "Count::new")]
+    /// struct Count(usize);
+    ///
+    /// impl Count {
+    ///     fn new(world: &mut World) -> Self {
+    ///         Self(world.view_one::<Position>().len())
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.extend_with([Position(0.0), Position(1.0)]);
+    ///
+    /// world.init_resource::<Frame>();
+    /// world.init_resource::<Count>();
+    /// assert_eq!(world.get_resource::<Count>().unwrap().0, 2);
+    ///
+    /// // Resource already exists, so it is not initialized again
+    /// world.create_with(Position(2.0));
+    /// world.init_resource::<Count>();
+    /// assert_eq!(world.get_resource::<Count>().unwrap().0, 2);
+    /// ```
+    #[cfg(feature = "resource")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
+    pub fn init_resource<R>(&mut self)
+    where
+        R: Resource + FromWorld,
+    {
+        if self.resources.contains::<R>() {
+            return;
+        }
+        let resource = R::from_world(self);
+        self.resources.create(resource)
+    }
+
     /// Creates new entity with one component or set of components attached to it.
     ///
     /// This can be done by hand with [`attach`][World::attach()] associated function.
@@ -150,7 +228,7 @@ impl World {
     /// assert!(world.contains(entity));
     /// assert!(world.is_entity_empty(entity));
     /// ```
-    pub fn create_entry(&mut self) -> Entry {
+    pub fn create_entry(&mut self) -> Entry<'_> {
         let entity = self.create();
         Entry::new(entity, self)
     }
@@ -177,7 +255,7 @@ impl World {
     /// assert!(world.contains(entity));
     /// assert!(!world.is_entity_empty(entity));
     /// ```
-    pub fn create_entry_with<S>(&mut self, set: S) -> Entry
+    pub fn create_entry_with<S>(&mut self, set: S) -> Entry<'_>
     where
         S: ComponentSet,
     {
@@ -201,7 +279,7 @@ impl World {
     /// world.destroy(entity);
     /// assert!(world.entry(entity).is_none());
     /// ```
-    pub fn entry(&mut self, entity: Entity) -> Option<Entry> {
+    pub fn entry(&mut self, entity: Entity) -> Option<Entry<'_>> {
         self.contains(entity).then(|| Entry::new(entity, self))
     }
 
@@ -231,7 +309,7 @@ impl World {
     /// assert!(world.contains(entity));
     /// assert!(world.attached::<(Position, Mass)>(entity));
     /// ```
-    pub fn entity(&mut self) -> EntityBuilder {
+    pub fn entity(&mut self) -> EntityBuilder<'_> {
         EntityBuilder::new(self)
     }
 
@@ -251,6 +329,69 @@ impl World {
         (0..count).map(|_| self.create()).collect()
     }
 
+    /// Reserves handle of the new entity without mutable access to the world.
+    ///
+    /// Reservation is atomic, so it can be done from many threads at once.
+    /// Reserved entity will be created by the next call of [`flush`][World::flush()],
+    /// which is also done implicitly when entities are created or destroyed.
+    /// Until then, the world does not [contain](World::contains()) the reserved entity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.reserve_entity();
+    /// assert!(!world.contains(entity));
+    ///
+    /// world.flush();
+    /// assert!(world.contains(entity));
+    /// ```
+    pub fn reserve_entity(&self) -> Entity {
+        self.entities.reserve()
+    }
+
+    /// Reserves handles of provided count of new entities without mutable access to the world.
+    ///
+    /// See [`reserve_entity`][World::reserve_entity()] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// let mut world = World::new();
+    ///
+    /// let entities = world.reserve_entities(10);
+    /// assert_eq!(entities.len(), 10);
+    ///
+    /// world.flush();
+    /// assert!(entities.into_iter().all(|entity| world.contains(entity)));
+    /// ```
+    pub fn reserve_entities(&self, count: u32) -> Vec<Entity> {
+        self.entities.reserve_many(count)
+    }
+
+    /// Creates all entities [reserved](World::reserve_entity()) since the last flush.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// #[derive(Copy, Clone, Component)]
+    /// struct Name(&'static str);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.reserve_entity();
+    /// world.flush();
+    /// world.attach(entity, Name("Hello, World"));
+    /// assert!(world.attached::<Name>(entity));
+    /// ```
+    pub fn flush(&mut self) {
+        self.entities.flush()
+    }
+
     // noinspection SpellCheckingInspection
     /// Extends world with collection of set of components to the newly created entities.
     /// Returns handles of newly created entities.
@@ -305,6 +446,25 @@ impl World {
         self.entities.contains(entity)
     }
 
+    /// Returns the live entity at the provided [index](Entity::index), if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.create();
+    /// let index = entity.index();
+    /// assert_eq!(world.resolve(index), Some(entity));
+    ///
+    /// world.destroy(entity);
+    /// assert_eq!(world.resolve(index), None);
+    /// ```
+    pub fn resolve(&self, index: u32) -> Option<Entity> {
+        self.entities.resolve(index)
+    }
+
     /// Returns `true` if the world has resource or set of resources.
     ///
     /// # Examples
@@ -342,320 +502,1643 @@ impl World {
     /// ```
     pub fn destroy(&mut self, entity: Entity) {
         self.remove_all(entity);
+        self.names.remove(entity);
         self.entities.destroy(entity);
     }
 
-    /// Destroys resource or set of resources and removes them from the world.
+    pub(crate) fn retain_components<S>(&mut self, entity: Entity)
+    where
+        S: ComponentSet,
+    {
+        self.components.retain::<S>(entity)
+    }
+
+    pub(crate) fn renew(&mut self, entity: Entity) -> Option<Entity> {
+        let renewed = self.entities.renew(entity)?;
+        self.components.rekey(entity, renewed);
+        if let Some(name) = self.names.remove(entity) {
+            self.names.set(renewed, &name);
+        }
+        Some(renewed)
+    }
+
+    /// Disables the entity without removing its attached components.
+    ///
+    /// Disabled entities are skipped by views and systems
+    /// unless their query contains [`IncludeDisabled`](crate::marker::IncludeDisabled) marker,
+    /// but they are still [contained](World::contains()) in the world
+    /// and their components can be accessed directly.
+    /// Does nothing if the entity does not exist in the world.
     ///
     /// # Examples
     ///
     /// ```
     /// # use toucan_ecs::prelude::*;
-    /// #[derive(Resource)]
-    /// struct ExampleResource(u32);
+    /// #[derive(Copy, Clone, Component)]
+    /// struct Name(&'static str);
     ///
     /// let mut world = World::new();
+    /// let entity = world.create_with(Name("Hello, World"));
     ///
-    /// world.create_resources(ExampleResource(42));
-    /// world.destroy_resources::<ExampleResource>();
-    /// assert!(!world.contains_resources::<ExampleResource>());
+    /// world.disable(entity);
+    /// assert!(world.contains(entity));
+    /// assert!(world.attached::<Name>(entity));
+    /// assert_eq!(world.view_one::<Name>().count(), 0);
+    /// assert_eq!(world.view::<(&Name, IncludeDisabled)>().count(), 1);
     /// ```
-    #[cfg(feature = "resource")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
-    pub fn destroy_resources<S>(&mut self)
+    pub fn disable(&mut self, entity: Entity) {
+        self.entities.disable(entity)
+    }
+
+    /// Enables the entity [disabled](World::disable()) earlier.
+    /// Does nothing if the entity does not exist in the world or it is not disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// #[derive(Copy, Clone, Component)]
+    /// struct Name(&'static str);
+    ///
+    /// let mut world = World::new();
+    /// let entity = world.create_with(Name("Hello, World"));
+    ///
+    /// world.disable(entity);
+    /// world.enable(entity);
+    /// assert_eq!(world.view_one::<Name>().count(), 1);
+    /// ```
+    pub fn enable(&mut self, entity: Entity) {
+        self.entities.enable(entity)
+    }
+
+    /// Returns `true` if the entity exists in the world and it is [disabled](World::disable()).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// let mut world = World::new();
+    /// let entity = world.create();
+    ///
+    /// world.disable(entity);
+    /// assert!(world.is_disabled(entity));
+    ///
+    /// world.destroy(entity);
+    /// assert!(!world.is_disabled(entity));
+    /// ```
+    pub fn is_disabled(&self, entity: Entity) -> bool {
+        self.entities.is_disabled(entity)
+    }
+
+    /// Sets human-readable name of the entity, replacing its previous name.
+    ///
+    /// Names are not required to be unique.
+    /// Name is removed when the entity is destroyed.
+    /// Setting the same name again does nothing.
+    ///
+    /// Storage of names is allocated by the world only when the first name is set,
+    /// so worlds which do not use names have no overhead.
+    ///
+    /// # Panics
+    ///
+    /// Function will panic if the entity does not exist in the world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// let mut world = World::new();
+    /// let entity = world.create();
+    ///
+    /// world.set_name(entity, "Player");
+    /// assert_eq!(world.name(entity), Some("Player"));
+    /// ```
+    pub fn set_name(&mut self, entity: Entity, name: &str) {
+        assert!(self.contains(entity), "entity does not exist in the world");
+        self.names.set(entity, name)
+    }
+
+    /// Returns name of the entity, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// let mut world = World::new();
+    /// let entity = world.create();
+    /// assert_eq!(world.name(entity), None);
+    ///
+    /// world.set_name(entity, "Player");
+    /// assert_eq!(world.name(entity), Some("Player"));
+    /// ```
+    pub fn name(&self, entity: Entity) -> Option<&str> {
+        self.names.get(entity)
+    }
+
+    /// Removes name of the entity and returns it, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// let mut world = World::new();
+    /// let entity = world.create();
+    ///
+    /// world.set_name(entity, "Player");
+    /// assert_eq!(world.remove_name(entity).as_deref(), Some("Player"));
+    /// assert_eq!(world.name(entity), None);
+    /// ```
+    pub fn remove_name(&mut self, entity: Entity) -> Option<String> {
+        self.names.remove(entity)
+    }
+
+    /// Finds the entity by its [name](World::set_name).
+    ///
+    /// If many entities have the same name,
+    /// returns the entity which got this name first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// let mut world = World::new();
+    /// let entity = world.create();
+    ///
+    /// world.set_name(entity, "Player");
+    /// assert_eq!(world.find_by_name("Player"), Some(entity));
+    ///
+    /// world.destroy(entity);
+    /// assert_eq!(world.find_by_name("Player"), None);
+    /// ```
+    pub fn find_by_name(&self, name: &str) -> Option<Entity> {
+        self.names.find(name)
+    }
+
+    /// Returns wrapper of the entity handle
+    /// which includes [name](World::set_name) of the entity into its [`Debug`] output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// let mut world = World::new();
+    /// let entity = world.create();
+    /// world.set_name(entity, "Player");
+    ///
+    /// let debug = format!("{:?}", world.debug_entity(entity));
+    /// assert_eq!(debug, format!("{:?}", entity).replace(')', ", \"Player\")"));
+    /// ```
+    pub fn debug_entity(&self, entity: Entity) -> EntityDebug<'_> {
+        EntityDebug::new(entity, self.name(entity))
+    }
+
+    /// Spawns new entity from the [prefab](Prefab) and returns its handle.
+    ///
+    /// Child prefabs are spawned as separate entities
+    /// with [`Parent`](crate::entity::Parent) component attached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// #[derive(Copy, Clone, Component, Debug, PartialEq)]
+    /// struct Name(&'static str);
+    ///
+    /// let prefab = Prefab::new().with(Name("Hello, World"));
+    ///
+    /// let mut world = World::new();
+    /// for _ in 0..10 {
+    ///     world.spawn_prefab(&prefab);
+    /// }
+    /// assert_eq!(world.view_one::<Name>().count(), 10);
+    /// ```
+    pub fn spawn_prefab(&mut self, prefab: &Prefab) -> Entity {
+        prefab.spawn(self, None)
+    }
+
+    /// Spawns new entity from the [prefab](Prefab) and attaches provided overrides to it.
+    ///
+    /// Overrides replace components of the prefab with the same type
+    /// and are attached only to the root entity of the prefab.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// #[derive(Copy, Clone, Component, Debug, PartialEq)]
+    /// struct Name(&'static str);
+    ///
+    /// let prefab = Prefab::new().with(Name("Hello, World"));
+    ///
+    /// let mut world = World::new();
+    /// let entity = world.spawn_prefab_with(&prefab, Name("Goodbye, World"));
+    /// assert_eq!(world.get::<Name>(entity), Some(&Name("Goodbye, World")));
+    /// ```
+    pub fn spawn_prefab_with<S>(&mut self, prefab: &Prefab, overrides: S) -> Entity
     where
-        S: ResourceSet,
+        S: ComponentSet,
     {
-        self.resources.destroy::<S>();
+        let entity = self.spawn_prefab(prefab);
+        self.attach(entity, overrides);
+        entity
     }
 
-    /// Returns `true` if the world does not contain any entity and any resource.
+    /// Creates new entity with copies of all the components attached to provided entity,
+    /// including [dynamic](World::register_dynamic) ones.
+    ///
+    /// # Panics
+    ///
+    /// Function will panic if provided entity does not exist in the world.
     ///
     /// # Examples
     ///
     /// ```
     /// # use toucan_ecs::prelude::*;
+    /// #[derive(Copy, Clone, Component, Debug, PartialEq)]
+    /// struct Name(&'static str);
+    ///
     /// let mut world = World::new();
-    /// assert!(world.is_empty());
     ///
-    /// let _ = world.create();
-    /// assert!(!world.is_empty());
+    /// let entity = world.create_with(Name("Hello, World"));
+    /// let clone = world.clone_entity(entity);
+    /// assert_ne!(entity, clone);
+    /// assert_eq!(world.get::<Name>(clone), Some(&Name("Hello, World")));
     /// ```
-    pub fn is_empty(&self) -> bool {
-        self.cfg_is_empty()
+    pub fn clone_entity(&mut self, entity: Entity) -> Entity {
+        assert!(self.contains(entity), "entity does not exist in the world");
+        let clone = self.create();
+        self.components.copy_all(entity, clone);
+        clone
     }
 
-    #[cfg(feature = "resource")]
-    #[inline(always)]
-    fn cfg_is_empty(&self) -> bool {
-        self.entities.is_empty() && self.resources.is_empty()
+    /// Copies one component or set of components from one entity to another.
+    ///
+    /// Components which are not attached to the source entity are skipped,
+    /// while components already attached to the target entity are replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// #[derive(Copy, Clone, Component, Debug, PartialEq)]
+    /// struct Name(&'static str);
+    ///
+    /// #[derive(Copy, Clone, Component, Debug, PartialEq)]
+    /// struct ID(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let from = world.create_with((Name("Hello, World"), ID(42)));
+    /// let to = world.create_with(ID(0));
+    /// world.copy_components::<(Name, ID)>(from, to);
+    /// assert_eq!(world.get::<Name>(to), Some(&Name("Hello, World")));
+    /// assert_eq!(world.get::<ID>(to), Some(&ID(42)));
+    /// ```
+    pub fn copy_components<S>(&mut self, from: Entity, to: Entity)
+    where
+        S: ComponentSet,
+    {
+        self.components.copy::<S>(from, to)
     }
 
-    #[cfg(not(feature = "resource"))]
-    #[inline(always)]
+    /// Moves the entity with all its components into another world
+    /// and returns new handle of the entity in that world.
+    ///
+    /// Entity is destroyed in this world after the move.
+    /// [Dynamic](World::register_dynamic) components are moved into the dynamic component type
+    /// of another world with the same [descriptor](ComponentDescriptor),
+    /// which is registered there if it does not exist yet.
+    ///
+    /// Entity handles stored in the moved components which [implement](MapEntities)
+    /// [registered](World::register_map_entities) in any of these worlds are fixed up,
+    /// so handle of the moved entity is replaced by its new handle.
+    /// Handles of other entities are left as is: to move related entities together,
+    /// use [`move_entities_to`](World::move_entities_to).
+    ///
+    /// # Panics
+    ///
+    /// Function will panic if provided entity does not exist in this world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// #[derive(Copy, Clone, Component, Debug, PartialEq)]
+    /// struct Name(&'static str);
+    ///
+    /// let mut staging = World::new();
+    /// let mut world = World::new();
+    ///
+    /// let entity = staging.create_with(Name("Hello, World"));
+    /// let moved = staging.move_entity_to(entity, &mut world);
+    ///
+    /// assert!(!staging.contains(entity));
+    /// assert_eq!(world.get::<Name>(moved), Some(&Name("Hello, World")));
+    /// ```
+    pub fn move_entity_to(&mut self, entity: Entity, other: &mut World) -> Entity {
+        assert!(self.contains(entity), "entity does not exist in the world");
+        let moved = self.move_entity_unmapped(entity, other);
+        let map = EntityMap::from_iter([(entity, moved)]);
+        self.map_moved_entities(other, &map);
+        moved
+    }
+
+    fn move_entity_unmapped(&mut self, entity: Entity, other: &mut World) -> Entity {
+        let moved = other.create();
+        self.components
+            .transfer(entity, &mut other.components, moved);
+        if self.entities.is_disabled(entity) {
+            other.entities.disable(moved);
+        }
+        if let Some(name) = self.names.remove(entity) {
+            other.names.set(moved, &name);
+        }
+        self.entities.destroy(entity);
+        moved
+    }
+
+    /// Moves provided entities with all their components into another world
+    /// and returns [table](EntityMap) which maps old entity handles to the new ones.
+    ///
+    /// Entity handles stored in the moved components which [implement](MapEntities)
+    /// [registered](World::register_map_entities) in any of these worlds are fixed up.
+    /// See [`move_entity_to`](World::move_entity_to) for details.
+    ///
+    /// # Panics
+    ///
+    /// Function will panic if any of provided entities does not exist in this world
+    /// or if the same entity is provided more than once.
+    /// In this case, no entity is moved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// #[derive(Copy, Clone, Component, Debug, PartialEq)]
+    /// struct ID(u32);
+    ///
+    /// let mut staging = World::new();
+    /// let mut world = World::new();
+    ///
+    /// let entities: Vec<_> = (0..10).map(|i| staging.create_with(ID(i))).collect();
+    /// let map = staging.move_entities_to(entities.iter().copied(), &mut world);
+    ///
+    /// assert!(staging.is_empty());
+    /// for (i, entity) in entities.into_iter().enumerate() {
+    ///     let moved = map.get(entity).unwrap();
+    ///     assert_eq!(world.get::<ID>(moved), Some(&ID(i as u32)));
+    /// }
+    /// ```
+    pub fn move_entities_to<I>(&mut self, entities: I, other: &mut World) -> EntityMap
+    where
+        I: IntoIterator<Item = Entity>,
+    {
+        let entities = entities.into_iter().collect::<Vec<_>>();
+        let mut unique = HashSet::with_capacity(entities.len());
+        for &entity in &entities {
+            assert!(self.contains(entity), "entity does not exist in the world");
+            assert!(unique.insert(entity), "entity is provided more than once");
+        }
+        let map: EntityMap = entities
+            .into_iter()
+            .map(|entity| (entity, self.move_entity_unmapped(entity, other)))
+            .collect();
+        self.map_moved_entities(other, &map);
+        map
+    }
+
+    fn map_moved_entities(&self, other: &mut World, map: &EntityMap) {
+        let moved = map.iter().map(|(_, entity)| entity).collect::<Vec<_>>();
+        other
+            .components
+            .map_entities_with(&self.components, &moved, map);
+    }
+
+    /// Merges another world into this one and returns [table](EntityMap)
+    /// which maps entity handles of merged world to the new ones.
+    ///
+    /// All the entities of merged world, including its [reserved](World::reserve_entity) entities,
+    /// are moved into this world as if by [`move_entities_to`](World::move_entities_to),
+    /// while its resources are moved according to provided [policy](MergePolicy).
+    /// Non-send resources of merged world are dropped.
+    ///
+    /// Types of components [registered](World::register_map_entities) in merged world
+    /// are fixed up during the merge, but they are not registered in this world.
+    ///
+    /// # Errors
+    ///
+    /// Returns [error](MergeError) if policy is [`MergePolicy::Error`]
+    /// and both worlds contain resource of the same type.
+    /// In this case, this world is left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// #[derive(Copy, Clone, Component, Debug, PartialEq)]
+    /// struct Name(&'static str);
+    ///
+    /// let mut level = World::new();
+    /// let entity = level.create_with(Name("Hello, World"));
+    ///
+    /// let mut world = World::new();
+    /// let map = world.merge(level, MergePolicy::Keep).unwrap();
+    ///
+    /// let entity = map.get(entity).unwrap();
+    /// assert_eq!(world.get::<Name>(entity), Some(&Name("Hello, World")));
+    /// ```
+    pub fn merge(
+        &mut self,
+        mut other: World,
+        policy: MergePolicy,
+    ) -> Result<EntityMap, MergeError> {
+        #[cfg(feature = "resource")]
+        if policy == MergePolicy::Error {
+            if let Some(info) = self.resources.conflict(&other.resources) {
+                return Err(MergeError::new(info.name()));
+            }
+        }
+        other.flush();
+        let entities = other.entities.iter().collect::<Vec<_>>();
+        let map = other.move_entities_to(entities, self);
+        #[cfg(feature = "resource")]
+        if policy != MergePolicy::Skip {
+            let resources = std::mem::take(&mut other.resources);
+            let replace = policy == MergePolicy::Replace;
+            self.resources.merge(resources, replace);
+        }
+        #[cfg(not(feature = "resource"))]
+        let _ = policy;
+        Ok(map)
+    }
+
+    /// Registers type of component which contains entity handles
+    /// to be fixed up when entities are moved into this world from another one.
+    ///
+    /// See [`MapEntities`] trait for details.
+    pub fn register_map_entities<C>(&mut self)
+    where
+        C: Component + MapEntities,
+    {
+        self.components.register_map_entities::<C>()
+    }
+
+    /// Destroys resource or set of resources and removes them from the world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// #[derive(Resource)]
+    /// struct ExampleResource(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.create_resources(ExampleResource(42));
+    /// world.destroy_resources::<ExampleResource>();
+    /// assert!(!world.contains_resources::<ExampleResource>());
+    /// ```
+    #[cfg(feature = "resource")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
+    pub fn destroy_resources<S>(&mut self)
+    where
+        S: ResourceSet,
+    {
+        self.resources.destroy::<S>();
+    }
+
+    /// Creates [snapshot](WorldSnapshot) of all the entities and their components.
+    ///
+    /// Resources are not included into the snapshot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// #[derive(Copy, Clone, Component, Debug, PartialEq)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    /// let entity = world.create_with(Health(100));
+    ///
+    /// let snapshot = world.snapshot();
+    /// world.get_mut::<Health>(entity).unwrap().0 = 0;
+    /// world.destroy(entity);
+    ///
+    /// world.restore(&snapshot);
+    /// assert_eq!(world.get::<Health>(entity), Some(&Health(100)));
+    /// ```
+    pub fn snapshot(&self) -> WorldSnapshot {
+        let entities = self.entities.clone();
+        let components = self.components.snapshot();
+        WorldSnapshot::new(entities, components, self.names.clone())
+    }
+
+    /// Restores all the entities and their components from the [snapshot](WorldSnapshot).
+    ///
+    /// Entities created after the snapshot are destroyed,
+    /// while entities destroyed after the snapshot are restored with the same handles.
+    /// [Names](World::set_name) of the entities are restored too,
+    /// while resources of the world are left untouched.
+    ///
+    /// Components which are attached now but are missing in the snapshot
+    /// are tracked as [removed](World::removed).
+    pub fn restore(&mut self, snapshot: &WorldSnapshot) {
+        self.entities.clone_from(snapshot.entities());
+        self.components.restore(snapshot.components());
+        self.names.clone_from(snapshot.names());
+    }
+
+    /// Registers type of component to be included into [world patches](WorldPatch)
+    /// with its [type name](std::any::type_name) as the name of the component in patches.
+    ///
+    /// Type of component must be registered both in the world which creates patches
+    /// and in the world which applies them.
+    /// Type names are not stable between compiler versions and builds,
+    /// so use [`register_patch_as`](World::register_patch_as) if patches are sent
+    /// between different builds of the application.
+    pub fn register_patch<C>(&mut self)
+    where
+        C: PatchComponent,
+    {
+        self.register_patch_as::<C>(std::any::type_name::<C>())
+    }
+
+    /// Registers type of component to be included into [world patches](WorldPatch)
+    /// with provided name of the component in patches.
+    ///
+    /// Patches are applied only by the worlds which registered the component with the same name.
+    /// Registering the type again replaces its previous name.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the name is already registered for another component type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// #[derive(Copy, Clone, Component, Debug, PartialEq)]
+    /// # #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    /// world.register_patch_as::<Health>("health");
+    ///
+    /// let snapshot = world.snapshot();
+    /// world.create_with(Health(100));
+    /// let patch = world.diff(&snapshot);
+    /// assert_eq!(patch.components()[0].name(), "health");
+    /// ```
+    pub fn register_patch_as<C>(&mut self, name: &'static str)
+    where
+        C: PatchComponent,
+    {
+        self.patches.register::<C>(name)
+    }
+
+    /// Creates [patch](WorldPatch) which contains all the changes of the world
+    /// since provided [snapshot](WorldSnapshot) was made.
+    ///
+    /// Only components of [registered](World::register_patch) types are compared,
+    /// including components of [disabled](World::disable) entities.
+    /// Changes of the disabled state of the entities are included too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// use toucan_ecs::world::ChangeKind;
+    ///
+    /// #[derive(Copy, Clone, Component, Debug, PartialEq)]
+    /// # #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    /// world.register_patch::<Health>();
+    /// let entity = world.create_with(Health(100));
+    ///
+    /// let snapshot = world.snapshot();
+    /// world.get_mut::<Health>(entity).unwrap().0 = 50;
+    /// let created = world.create();
+    ///
+    /// let patch = world.diff(&snapshot);
+    /// assert_eq!(patch.created(), [created]);
+    /// assert_eq!(patch.components()[0].kind(), ChangeKind::Changed);
+    /// ```
+    pub fn diff(&self, old: &WorldSnapshot) -> WorldPatch {
+        let created = self
+            .entities
+            .iter()
+            .filter(|&entity| !old.contains(entity))
+            .collect();
+        let destroyed = old
+            .entities()
+            .iter()
+            .filter(|&entity| !self.contains(entity))
+            .collect();
+        let disabled = self
+            .entities
+            .disabled()
+            .iter()
+            .copied()
+            .filter(|&entity| !old.entities().is_disabled(entity))
+            .collect();
+        let enabled = old
+            .entities()
+            .disabled()
+            .iter()
+            .copied()
+            .filter(|&entity| self.contains(entity) && !self.is_disabled(entity))
+            .collect();
+        let components = self.patches.diff(self, old);
+        WorldPatch::new(created, destroyed, disabled, enabled, components)
+    }
+
+    /// Applies [patch](WorldPatch) created by another world to this world.
+    ///
+    /// Provided [entity map](EntityMap) translates entity handles of another world
+    /// into entity handles of this world: it is updated with entities created by the patch,
+    /// while entities which are not mapped are considered to have the same handles in both worlds.
+    /// Keep the same map between patches received from the same world.
+    ///
+    /// # Errors
+    ///
+    /// Returns [error](PatchError) if the patch contains component of unregistered type,
+    /// in which case the world is left untouched,
+    /// or if the value of the component cannot be converted into its type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// #[derive(Copy, Clone, Component, Debug, PartialEq)]
+    /// # #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    /// struct Health(u32);
+    ///
+    /// let mut server = World::new();
+    /// server.register_patch::<Health>();
+    /// let mut client = World::new();
+    /// client.register_patch::<Health>();
+    /// let mut entities = EntityMap::new();
+    ///
+    /// let snapshot = server.snapshot();
+    /// let entity = server.create_with(Health(100));
+    /// let patch = server.diff(&snapshot);
+    ///
+    /// client.apply_patch(&patch, &mut entities).unwrap();
+    /// let replica = entities.get(entity).unwrap();
+    /// assert_eq!(client.get::<Health>(replica), Some(&Health(100)));
+    /// ```
+    pub fn apply_patch(
+        &mut self,
+        patch: &WorldPatch,
+        entities: &mut EntityMap,
+    ) -> Result<(), PatchError> {
+        if let Some(component) = patch
+            .components()
+            .iter()
+            .find(|component| !self.patches.contains(component.name()))
+        {
+            return Err(PatchError::UnknownComponent {
+                name: component.name().to_string(),
+            });
+        }
+        for &entity in patch.destroyed() {
+            let entity = entities.remove(entity).unwrap_or(entity);
+            self.destroy(entity);
+        }
+        for &entity in patch.created() {
+            let created = self.create();
+            entities.insert(entity, created);
+        }
+
+        let registry = std::mem::take(&mut self.patches);
+        let mut patched = Vec::with_capacity(patch.components().len());
+        let result = patch.components().iter().try_for_each(|component| {
+            let entity = entities.map(component.entity());
+            patched.push(entity);
+            registry.apply(self, entity, component)
+        });
+        self.patches = registry;
+
+        patched.sort_unstable();
+        patched.dedup();
+        self.components.map_entities(&patched, entities);
+
+        for &entity in patch.disabled() {
+            self.disable(entities.map(entity));
+        }
+        for &entity in patch.enabled() {
+            self.enable(entities.map(entity));
+        }
+        result
+    }
+
+    /// Collects [information](WorldInfo) about entities, registered component types
+    /// and resources of the world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// #[derive(Copy, Clone, Component)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    /// world.create_with(Health(100));
+    /// world.create();
+    ///
+    /// let info = world.info();
+    /// assert_eq!(info.entities(), 2);
+    ///
+    /// let health = &info.components()[0];
+    /// assert!(health.name().ends_with("Health"));
+    /// assert_eq!(health.size(), 4);
+    /// assert_eq!(health.entities(), 1);
+    /// ```
+    pub fn info(&self) -> WorldInfo {
+        let entities = self.entities.len();
+        let components = self.components.infos().collect();
+        #[cfg(feature = "resource")]
+        let resources = self.resources.infos().collect();
+        WorldInfo::new(
+            entities,
+            components,
+            #[cfg(feature = "resource")]
+            resources,
+        )
+    }
+
+    /// Returns `true` if the world does not contain any entity and any resource.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// let mut world = World::new();
+    /// assert!(world.is_empty());
+    ///
+    /// let _ = world.create();
+    /// assert!(!world.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.cfg_is_empty()
+    }
+
+    #[cfg(feature = "resource")]
+    #[inline(always)]
+    fn cfg_is_empty(&self) -> bool {
+        self.entities.is_empty() && self.resources.is_empty() && self.non_send.is_empty()
+    }
+
+    #[cfg(not(feature = "resource"))]
+    #[inline(always)]
     fn cfg_is_empty(&self) -> bool {
         self.entities.is_empty()
     }
 
-    /// Clears this world, destroying all resources, all entities and their data.
+    /// Clears this world, destroying all resources, all entities and their data.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the world contains non-send resources
+    /// and it is called on the thread other than the thread these resources were created on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// let mut world = World::new();
+    ///
+    /// world.extend(100);
+    /// assert!(!world.is_empty());
+    ///
+    /// world.clear();
+    /// assert!(world.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.entities.clear();
+        self.components.clear();
+        self.names.clear();
+        #[cfg(feature = "resource")]
+        self.resources.clear();
+        #[cfg(feature = "resource")]
+        self.non_send.clear();
+    }
+
+    /// Registers new type of component to be stored in the world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// #[derive(Copy, Clone, Component)]
+    /// struct Name(&'static str);
+    ///
+    /// #[derive(Copy, Clone, Component)]
+    /// struct ID(u32);
+    ///
+    /// let mut world = World::new();
+    /// world.register::<Name>();
+    /// world.register::<ID>();
+    /// ```
+    pub fn register<C>(&mut self)
+    where
+        C: Component,
+    {
+        self.components.register::<C>();
+    }
+
+    /// Registers new dynamic component type defined at runtime
+    /// and returns its unique [identifier](ComponentId).
+    ///
+    /// Data of dynamic components is stored as raw bytes, so it can be
+    /// [attached](World::attach_dynamic), [retrieved](World::get_dynamic)
+    /// and [removed](World::remove_dynamic) by byte slices only.
+    /// Byte slices have no alignment guarantees,
+    /// so the data must be read and written as unaligned.
+    ///
+    /// Identifier is valid only for this world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// use std::alloc::Layout;
+    /// use toucan_ecs::component::ComponentDescriptor;
+    ///
+    /// let mut world = World::new();
+    /// let descriptor = ComponentDescriptor::new("Health", Layout::new::<u32>());
+    /// let health = world.register_dynamic(descriptor);
+    ///
+    /// let entity = world.create();
+    /// world.attach_dynamic(entity, health, &100u32.to_ne_bytes());
+    ///
+    /// let data = world.get_dynamic(entity, health).unwrap();
+    /// assert_eq!(u32::from_ne_bytes(data.try_into().unwrap()), 100);
+    /// ```
+    pub fn register_dynamic(&mut self, descriptor: ComponentDescriptor) -> ComponentId {
+        self.components.register_dynamic(descriptor)
+    }
+
+    /// Returns [descriptor](ComponentDescriptor) of the dynamic component type
+    /// or [`None`](Option::None) if it was not registered in this world.
+    pub fn dynamic_descriptor(&self, id: ComponentId) -> Option<&ComponentDescriptor> {
+        let storage = self.components.get_dynamic_storage(id)?;
+        Some(storage.descriptor())
+    }
+
+    /// Attaches data of the dynamic component to the entity
+    /// replacing the previous data, if any.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if dynamic component type was not registered in this world
+    /// or length of `data` does not match the size of the component type layout.
+    pub fn attach_dynamic(&mut self, entity: Entity, id: ComponentId, data: &[u8]) {
+        let storage = self
+            .components
+            .get_dynamic_storage_mut(id)
+            .expect("dynamic component is not registered in this world");
+        storage.attach(entity, data)
+    }
+
+    /// Returns `true` if the entity has the dynamic component attached.
+    pub fn attached_dynamic(&self, entity: Entity, id: ComponentId) -> bool {
+        let storage = self.components.get_dynamic_storage(id);
+        storage.is_some_and(|storage| storage.attached(entity))
+    }
+
+    /// Retrieves the shared borrow of the dynamic component data attached to the entity.
+    pub fn get_dynamic(&self, entity: Entity, id: ComponentId) -> Option<&[u8]> {
+        let storage = self.components.get_dynamic_storage(id)?;
+        storage.get(entity)
+    }
+
+    /// Retrieves the unique borrow of the dynamic component data attached to the entity.
+    pub fn get_dynamic_mut(&mut self, entity: Entity, id: ComponentId) -> Option<&mut [u8]> {
+        let storage = self.components.get_dynamic_storage_mut(id)?;
+        storage.get_mut(entity)
+    }
+
+    /// Removes the dynamic component from the entity.
+    pub fn remove_dynamic(&mut self, entity: Entity, id: ComponentId) {
+        if let Some(storage) = self.components.get_dynamic_storage_mut(id) {
+            storage.remove(entity)
+        }
+    }
+
+    /// Registers type of component to be accessible through [reflection](Reflect)
+    /// by its type name.
+    ///
+    /// Component can be found either by its full type name
+    /// (as returned by [`type_name`](std::any::type_name)) or by its name without the module path
+    /// if there are no other registered components with such name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// #[derive(Copy, Clone, Component, Default, Reflect)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    /// world.register_reflect::<Health>();
+    /// assert_eq!(world.reflect_type_names().count(), 1);
+    /// ```
+    pub fn register_reflect<C>(&mut self)
+    where
+        C: Component + Reflect + Default,
+    {
+        self.components.register::<C>();
+        self.reflect.register::<C>();
+    }
+
+    /// Returns full type names of all the components registered for [reflection](Reflect).
+    pub fn reflect_type_names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.reflect.type_names()
+    }
+
+    /// Retrieves the shared borrow of the component attached to the entity
+    /// through [reflection](Reflect) by its type name.
+    ///
+    /// # Errors
+    ///
+    /// Returns [error](ReflectError::UnknownType) if component type was not
+    /// [registered for reflection](World::register_reflect).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// #[derive(Copy, Clone, Component, Default, Reflect)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    /// world.register_reflect::<Health>();
+    /// let entity = world.create_with(Health(100));
+    ///
+    /// let health = world.get_reflect(entity, "Health").unwrap().unwrap();
+    /// assert_eq!(health.field("0").unwrap().display().as_deref(), Some("100"));
+    /// ```
+    pub fn get_reflect(
+        &self,
+        entity: Entity,
+        type_name: &str,
+    ) -> Result<Option<&dyn Reflect>, ReflectError> {
+        let component = self.reflect.get(type_name)?;
+        Ok(component.get(&self.components, entity))
+    }
+
+    /// Retrieves the unique borrow of the component attached to the entity
+    /// through [reflection](Reflect) by its type name.
+    ///
+    /// # Errors
+    ///
+    /// Returns [error](ReflectError::UnknownType) if component type was not
+    /// [registered for reflection](World::register_reflect).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// #[derive(Copy, Clone, Component, Default, Reflect)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    /// world.register_reflect::<Health>();
+    /// let entity = world.create_with(Health(100));
+    ///
+    /// let health = world.get_reflect_mut(entity, "Health").unwrap().unwrap();
+    /// health.field_mut("0").unwrap().set_from_str("50").unwrap();
+    /// assert_eq!(world.get::<Health>(entity).unwrap().0, 50);
+    /// ```
+    pub fn get_reflect_mut(
+        &mut self,
+        entity: Entity,
+        type_name: &str,
+    ) -> Result<Option<&mut dyn Reflect>, ReflectError> {
+        let component = self.reflect.get(type_name)?;
+        Ok(component.get_mut(&mut self.components, entity))
+    }
+
+    /// Attaches [default](Default) value of the component to the entity by its type name
+    /// and returns unique borrow of the attached component through [reflection](Reflect).
+    ///
+    /// # Errors
+    ///
+    /// Returns [error](ReflectError::UnknownType) if component type was not
+    /// [registered for reflection](World::register_reflect).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// #[derive(Copy, Clone, Component, Default, Reflect)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    /// world.register_reflect::<Health>();
+    /// let entity = world.create();
+    ///
+    /// let health = world.attach_reflect(entity, "Health").unwrap();
+    /// health.field_mut("0").unwrap().set_from_str("100").unwrap();
+    /// assert_eq!(world.get::<Health>(entity).unwrap().0, 100);
+    /// ```
+    pub fn attach_reflect(
+        &mut self,
+        entity: Entity,
+        type_name: &str,
+    ) -> Result<&mut dyn Reflect, ReflectError> {
+        let component = self.reflect.get(type_name)?;
+        component.attach_default(&mut self.components, entity);
+        let attached = component.get_mut(&mut self.components, entity);
+        Ok(attached.expect("component should be attached"))
+    }
+
+    /// Attaches one component or set of components to the entity.
+    ///
+    /// This function does not panic because it registers components' types automatically.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// #[derive(Copy, Clone, Component)]
+    /// struct Name(&'static str);
+    ///
+    /// #[derive(Copy, Clone, Component)]
+    /// struct ID(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.create();
+    /// world.attach(entity, (Name("Hello, World"), ID(42)));
+    /// assert!(world.attached::<(ID, Name)>(entity));
+    /// ```
+    pub fn attach<S>(&mut self, entity: Entity, set: S)
+    where
+        S: ComponentSet,
+    {
+        self.components.attach(entity, set)
+    }
+
+    /// Returns `true` if one component or set of components are attached to the entity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// #[derive(Copy, Clone, Component)]
+    /// struct Name(&'static str);
+    ///
+    /// #[derive(Copy, Clone, Component)]
+    /// struct ID(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.create();
+    /// assert!(!world.attached::<(ID, Name)>(entity));
+    ///
+    /// world.attach(entity, (Name("Hello, World"), ID(42)));
+    /// assert!(world.attached::<(Name, ID)>(entity));
+    /// ```
+    pub fn attached<S>(&self, entity: Entity) -> bool
+    where
+        S: ComponentSet,
+    {
+        self.components.attached::<S>(entity)
+    }
+
+    /// Returns `true` if the entity does not exist or does not contain any data attached to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.create();
+    /// assert!(world.is_entity_empty(entity));
+    /// ```
+    pub fn is_entity_empty(&self, entity: Entity) -> bool {
+        self.components.is_entity_empty(entity)
+    }
+
+    /// Removes one component or set of components from the entity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// #[derive(Copy, Clone, Component)]
+    /// struct Name(&'static str);
+    ///
+    /// #[derive(Copy, Clone, Component)]
+    /// struct ID(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.create_with((Name("Hello, World"), ID(42)));
+    /// world.remove::<(ID, Name)>(entity);
+    /// assert!(!world.attached::<(Name, ID)>(entity));
+    /// ```
+    pub fn remove<S>(&mut self, entity: Entity)
+    where
+        S: ComponentSet,
+    {
+        self.components.remove::<S>(entity);
+    }
+
+    /// Removes all attached components from the entity.
+    /// It makes the entity effectively empty.
+    ///
+    /// To remove just a set of components from the entity,
+    /// use [`remove`][World::remove()] associated functions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// #[derive(Copy, Clone, Component)]
+    /// struct Name(&'static str);
+    ///
+    /// #[derive(Copy, Clone, Component)]
+    /// struct ID(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.create_with((Name("Hello, World"), ID(42)));
+    /// world.remove_all(entity);
+    /// assert!(world.is_entity_empty(entity));
+    /// ```
+    pub fn remove_all(&mut self, entity: Entity) {
+        self.components.remove_all(entity);
+    }
+
+    /// Returns an iterator of entities the component of generic type was removed from.
+    ///
+    /// Removals are recorded when component is [removed](World::remove()) from the entity
+    /// or when the entity is [destroyed](World::destroy()).
+    /// They are kept until every [system](crate::system::System) which reads them has seen them
+    /// or until the second call of [`clear_trackers`](World::clear_trackers()).
+    /// If there are no such systems, they are dropped after each [run of the schedule](crate::system::Schedule::run).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// #[derive(Copy, Clone, Component)]
+    /// struct Name(&'static str);
+    ///
+    /// let mut world = World::new();
+    /// let entity = world.create_with(Name("Hello, World"));
+    ///
+    /// world.remove::<Name>(entity);
+    /// assert_eq!(world.removed::<Name>().collect::<Vec<_>>(), [entity]);
+    /// ```
+    pub fn removed<C>(&self) -> RemovedComponents<'_, C>
+    where
+        C: Component,
+    {
+        self.components.removed().since(0)
+    }
+
+    /// Drops tracked removals of components which are older than the previous call
+    /// of this function, so every removal is kept for two calls of it.
+    ///
+    /// Removals are dropped even if some [systems](crate::system::System) have not seen them yet.
+    /// Usually there is no need to call it because [`Schedule::run`](crate::system::Schedule::run)
+    /// drops removals which were seen by every system reading them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// #[derive(Copy, Clone, Component)]
+    /// struct Name(&'static str);
+    ///
+    /// let mut world = World::new();
+    /// let entity = world.create_with(Name("Hello, World"));
+    /// world.destroy(entity);
+    ///
+    /// world.clear_trackers();
+    /// assert_eq!(world.removed::<Name>().len(), 1);
+    /// world.clear_trackers();
+    /// assert_eq!(world.removed::<Name>().len(), 0);
+    /// ```
+    pub fn clear_trackers(&mut self) {
+        self.components.clear_removed();
+    }
+
+    /// Retrieves the shared borrow for the component of one type attached to the entity.
+    /// Returns [`None`](Option::None) if component is not attached to the entity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// #[derive(Copy, Clone, Component, Eq, PartialEq, Debug)]
+    /// struct Name(&'static str);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.create_with((Name("Hello, World"),));
+    /// let name = world.get::<Name>(entity).unwrap();
+    /// assert_eq!(*name, Name("Hello, World"));
+    /// ```
+    pub fn get<C>(&self, entity: Entity) -> Option<&C>
+    where
+        C: Component,
+    {
+        self.components.get::<C>(entity)
+    }
+
+    /// Retrieves the unique borrow for the component of one type attached to the entity.
+    /// Returns [`None`](Option::None) if component is not attached to the entity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// #[derive(Copy, Clone, Component, Eq, PartialEq, Debug)]
+    /// struct Name(&'static str);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.create_with((Name("Hello, World"),));
+    /// let mut name = world.get_mut::<Name>(entity).unwrap();
+    /// name.0 = "This name was changed";
+    /// assert_ne!(*name, Name("Hello, World"));
+    /// assert_eq!(*name, Name("This name was changed"));
+    /// ```
+    pub fn get_mut<C>(&mut self, entity: Entity) -> Option<&mut C>
+    where
+        C: Component,
+    {
+        self.components.get_mut::<C>(entity)
+    }
+
+    /// Creates index of the entities with the component of generic type by the key
+    /// which is computed from the component by provided function.
+    ///
+    /// Index is kept up to date on attach, remove and any unique borrow of the component,
+    /// so [lookup](World::lookup) always returns entities with the current value of the key.
+    /// Entities whose components were borrowed uniquely, including by mutable views and systems,
+    /// are re-indexed on the next lookup, so its cost depends on the count of such entities.
+    /// If it exceeds the count of indexed entities, the whole index is rebuilt instead.
+    /// Repeated function call with the same type of component replaces previous index.
     ///
     /// # Examples
     ///
     /// ```
     /// # use toucan_ecs::prelude::*;
+    /// #[derive(Copy, Clone, Component)]
+    /// struct GridCell {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
     /// let mut world = World::new();
+    /// world.index_by::<GridCell, _>(|cell| (cell.x, cell.y));
     ///
-    /// world.extend(100);
-    /// assert!(!world.is_empty());
+    /// let entity = world.create_with(GridCell { x: 1, y: 2 });
+    /// assert!(world.lookup::<GridCell>(&(1, 2)).eq([entity]));
     ///
-    /// world.clear();
-    /// assert!(world.is_empty());
+    /// world.get_mut::<GridCell>(entity).unwrap().x = 3;
+    /// assert_eq!(world.lookup::<GridCell>(&(1, 2)).count(), 0);
+    /// assert!(world.lookup::<GridCell>(&(3, 2)).eq([entity]));
     /// ```
-    pub fn clear(&mut self) {
-        self.entities.clear();
-        self.components.clear();
-        #[cfg(feature = "resource")]
-        self.resources.clear();
+    pub fn index_by<C, K>(&mut self, key_fn: fn(&C) -> K)
+    where
+        C: Component,
+        K: IndexKey,
+    {
+        self.components.index_by(key_fn)
     }
 
-    /// Registers new type of component to be stored in the world.
+    /// Returns entities with the component of generic type
+    /// which [index](World::index_by) key is equal to the provided key.
+    ///
+    /// Entities are returned in order of indexing.
+    ///
+    /// # Panics
+    ///
+    /// Function will panic if the component is not indexed
+    /// or it is indexed by the key of another type,
+    /// so type of provided key must be exactly the same as the key type of the index.
     ///
     /// # Examples
     ///
     /// ```
     /// # use toucan_ecs::prelude::*;
     /// #[derive(Copy, Clone, Component)]
-    /// struct Name(&'static str);
-    ///
-    /// #[derive(Copy, Clone, Component)]
-    /// struct ID(u32);
+    /// struct PlayerId(u32);
     ///
     /// let mut world = World::new();
-    /// world.register::<Name>();
-    /// world.register::<ID>();
+    /// world.index_by::<PlayerId, _>(|id| id.0);
+    ///
+    /// let entity = world.create_with(PlayerId(42));
+    /// assert!(world.lookup::<PlayerId>(&42_u32).eq([entity]));
+    ///
+    /// world.remove::<PlayerId>(entity);
+    /// assert_eq!(world.lookup::<PlayerId>(&42_u32).next(), None);
     /// ```
-    pub fn register<C>(&mut self)
+    pub fn lookup<C>(&self, key: &impl IndexKey) -> impl Iterator<Item = Entity>
     where
         C: Component,
     {
-        self.components.register::<C>();
+        self.components.lookup::<C, _>(key).into_iter()
     }
 
-    /// Attaches one component or set of components to the entity.
-    ///
-    /// This function does not panic because it registers components' types automatically.
+    /// Retrieves the shared borrow of the generic resource type.
     ///
     /// # Examples
     ///
     /// ```
     /// # use toucan_ecs::prelude::*;
-    /// #[derive(Copy, Clone, Component)]
-    /// struct Name(&'static str);
-    ///
-    /// #[derive(Copy, Clone, Component)]
-    /// struct ID(u32);
+    /// #[derive(Debug, Eq, PartialEq, Resource)]
+    /// struct ExampleResource(u32);
     ///
     /// let mut world = World::new();
     ///
-    /// let entity = world.create();
-    /// world.attach(entity, (Name("Hello, World"), ID(42)));
-    /// assert!(world.attached::<(ID, Name)>(entity));
+    /// world.create_resources(ExampleResource(42));
+    /// let resource = world.get_resource::<ExampleResource>().unwrap();
+    /// assert_eq!(*resource, ExampleResource(42));
     /// ```
-    pub fn attach<S>(&mut self, entity: Entity, set: S)
+    #[cfg(feature = "resource")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
+    pub fn get_resource<R>(&self) -> Option<&R>
     where
-        S: ComponentSet,
+        R: Resource,
     {
-        self.components.attach(entity, set)
+        self.resources.get::<R>()
     }
 
-    /// Returns `true` if one component or set of components are attached to the entity.
+    /// Retrieves the unique borrow of the generic resource type.
     ///
     /// # Examples
     ///
     /// ```
     /// # use toucan_ecs::prelude::*;
-    /// #[derive(Copy, Clone, Component)]
-    /// struct Name(&'static str);
-    ///
-    /// #[derive(Copy, Clone, Component)]
-    /// struct ID(u32);
+    /// #[derive(Debug, Eq, PartialEq, Resource)]
+    /// struct ExampleResource(u32);
     ///
     /// let mut world = World::new();
+    /// world.create_resources(ExampleResource(42));
     ///
-    /// let entity = world.create();
-    /// assert!(!world.attached::<(ID, Name)>(entity));
-    ///
-    /// world.attach(entity, (Name("Hello, World"), ID(42)));
-    /// assert!(world.attached::<(Name, ID)>(entity));
+    /// let mut resource = world.get_resource_mut::<ExampleResource>().unwrap();
+    /// *resource = ExampleResource(35);
+    /// assert_eq!(*resource, ExampleResource(35));
     /// ```
-    pub fn attached<S>(&self, entity: Entity) -> bool
+    #[cfg(feature = "resource")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
+    pub fn get_resource_mut<R>(&mut self) -> Option<&mut R>
     where
-        S: ComponentSet,
+        R: Resource,
     {
-        self.components.attached::<S>(entity)
+        self.resources.get_mut::<R>()
     }
 
-    /// Returns `true` if the entity does not exist or does not contain any data attached to it.
+    /// Temporarily removes the resource of generic type from the world
+    /// and calls provided function with both the world and the removed resource.
+    ///
+    /// This is useful when the resource needs to modify the world
+    /// while being borrowed mutably.
+    /// After the function returns, the resource is put back to the world.
+    /// The resource is put back even if the function panics.
+    ///
+    /// If the function creates the resource of the same type in the world,
+    /// the created resource is dropped and replaced by the resource of the scope.
+    ///
+    /// The scope does not mark the resource as [changed](crate::marker::Res::is_changed),
+    /// because it cannot know if the function modified the resource.
+    /// To mark it as changed, call [`get_resource_mut`](World::get_resource_mut) after the scope.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the world does not contain resource of generic type.
+    /// See [`try_resource_scope`](World::try_resource_scope) for the fallible version.
     ///
     /// # Examples
     ///
     /// ```
     /// # use toucan_ecs::prelude::*;
+    /// #[derive(Copy, Clone, Component)]
+    /// struct Enemy;
+    ///
+    /// #[derive(Resource)]
+    /// struct Level {
+    ///     enemies: usize,
+    /// }
+    ///
     /// let mut world = World::new();
+    /// world.create_resources(Level { enemies: 3 });
     ///
-    /// let entity = world.create();
-    /// assert!(world.is_entity_empty(entity));
+    /// world.resource_scope(|world, level: &mut Level| {
+    ///     for _ in 0..level.enemies {
+    ///         world.create_with((Enemy,));
+    ///     }
+    ///     level.enemies = 0;
+    /// });
+    ///
+    /// assert_eq!(world.view_one::<Enemy>().count(), 3);
+    /// assert_eq!(world.get_resource::<Level>().unwrap().enemies, 0);
     /// ```
-    pub fn is_entity_empty(&self, entity: Entity) -> bool {
-        self.components.is_entity_empty(entity)
+    #[cfg(feature = "resource")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
+    pub fn resource_scope<R, T>(&mut self, f: impl FnOnce(&mut World, &mut R) -> T) -> T
+    where
+        R: Resource,
+    {
+        self.try_resource_scope(f)
+            .expect("resource does not exist in the world")
     }
 
-    /// Removes one component or set of components from the entity.
+    /// Temporarily removes the resource of generic type from the world
+    /// and calls provided function with both the world and the removed resource.
+    ///
+    /// Returns [`None`](Option::None) if the world does not contain resource of generic type.
+    /// See [`resource_scope`](World::resource_scope) for more details.
     ///
     /// # Examples
     ///
     /// ```
     /// # use toucan_ecs::prelude::*;
-    /// #[derive(Copy, Clone, Component)]
-    /// struct Name(&'static str);
-    ///
-    /// #[derive(Copy, Clone, Component)]
-    /// struct ID(u32);
+    /// #[derive(Resource)]
+    /// struct Level(u32);
     ///
     /// let mut world = World::new();
+    /// assert_eq!(world.try_resource_scope(|_, level: &mut Level| level.0), None);
     ///
-    /// let entity = world.create_with((Name("Hello, World"), ID(42)));
-    /// world.remove::<(ID, Name)>(entity);
-    /// assert!(!world.attached::<(Name, ID)>(entity));
+    /// world.create_resources(Level(1));
+    /// assert_eq!(world.try_resource_scope(|_, level: &mut Level| level.0), Some(1));
     /// ```
-    pub fn remove<S>(&mut self, entity: Entity)
+    #[cfg(feature = "resource")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
+    pub fn try_resource_scope<R, T>(&mut self, f: impl FnOnce(&mut World, &mut R) -> T) -> Option<T>
     where
-        S: ComponentSet,
+        R: Resource,
     {
-        self.components.remove::<S>(entity);
+        let mut erased = self.resources.take::<R>()?;
+        let (resource, _) = erased.as_resource_mut::<R>().expect("downcast error");
+        let result = panic::catch_unwind(AssertUnwindSafe(|| f(self, resource)));
+        self.resources.restore::<R>(erased);
+        match result {
+            Ok(result) => Some(result),
+            Err(payload) => panic::resume_unwind(payload),
+        }
     }
 
-    /// Removes all attached components from the entity.
-    /// It makes the entity effectively empty.
+    /// Creates new non-send resource in the world.
     ///
-    /// To remove just a set of components from the entity,
-    /// use [`remove`][World::remove()] associated functions.
+    /// Unlike [resources](Resource), non-send resources are not required
+    /// to implement [`Send`] and [`Sync`] traits. All non-send resources of the world
+    /// are bound to the thread the first of them was created on,
+    /// so they can be accessed, modified or destroyed only on this thread.
+    ///
+    /// In systems, they can be retrieved by [`NonSend`](crate::marker::NonSend)
+    /// and [`NonSendMut`](crate::marker::NonSendMut) markers.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if called on the thread other than
+    /// the thread non-send resources of the world are bound to.
     ///
     /// # Examples
     ///
     /// ```
     /// # use toucan_ecs::prelude::*;
-    /// #[derive(Copy, Clone, Component)]
-    /// struct Name(&'static str);
-    ///
-    /// #[derive(Copy, Clone, Component)]
-    /// struct ID(u32);
+    /// use std::rc::Rc;
     ///
     /// let mut world = World::new();
     ///
-    /// let entity = world.create_with((Name("Hello, World"), ID(42)));
-    /// world.remove_all(entity);
-    /// assert!(world.is_entity_empty(entity));
+    /// world.create_non_send(Rc::new(42));
+    /// assert!(world.contains_non_send::<Rc<i32>>());
     /// ```
-    pub fn remove_all(&mut self, entity: Entity) {
-        self.components.remove_all(entity);
+    #[cfg(feature = "resource")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
+    pub fn create_non_send<R>(&mut self, resource: R)
+    where
+        R: 'static,
+    {
+        self.non_send.create(resource)
     }
 
-    /// Retrieves the shared borrow for the component of one type attached to the entity.
-    /// Returns [`None`](Option::None) if component is not attached to the entity.
+    /// Destroys non-send resource of the generic type in the world.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the resource exists and this function is called
+    /// on the thread other than the thread non-send resources of the world are bound to.
     ///
     /// # Examples
     ///
     /// ```
     /// # use toucan_ecs::prelude::*;
-    /// #[derive(Copy, Clone, Component, Eq, PartialEq, Debug)]
-    /// struct Name(&'static str);
+    /// use std::rc::Rc;
     ///
     /// let mut world = World::new();
+    /// world.create_non_send(Rc::new(42));
     ///
-    /// let entity = world.create_with((Name("Hello, World"),));
-    /// let name = world.get::<Name>(entity).unwrap();
-    /// assert_eq!(*name, Name("Hello, World"));
+    /// world.destroy_non_send::<Rc<i32>>();
+    /// assert!(!world.contains_non_send::<Rc<i32>>());
     /// ```
-    pub fn get<C>(&self, entity: Entity) -> Option<&C>
+    #[cfg(feature = "resource")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
+    pub fn destroy_non_send<R>(&mut self)
     where
-        C: Component,
+        R: 'static,
     {
-        self.components.get::<C>(entity)
+        self.non_send.destroy::<R>()
     }
 
-    /// Retrieves the unique borrow for the component of one type attached to the entity.
-    /// Returns [`None`](Option::None) if component is not attached to the entity.
+    /// Returns `true` if the world contains non-send resource of the generic type.
+    ///
+    /// This function can be called on any thread.
     ///
     /// # Examples
     ///
     /// ```
     /// # use toucan_ecs::prelude::*;
-    /// #[derive(Copy, Clone, Component, Eq, PartialEq, Debug)]
-    /// struct Name(&'static str);
+    /// use std::rc::Rc;
     ///
     /// let mut world = World::new();
+    /// assert!(!world.contains_non_send::<Rc<i32>>());
     ///
-    /// let entity = world.create_with((Name("Hello, World"),));
-    /// let mut name = world.get_mut::<Name>(entity).unwrap();
-    /// name.0 = "This name was changed";
-    /// assert_ne!(*name, Name("Hello, World"));
-    /// assert_eq!(*name, Name("This name was changed"));
+    /// world.create_non_send(Rc::new(42));
+    /// assert!(world.contains_non_send::<Rc<i32>>());
     /// ```
-    pub fn get_mut<C>(&mut self, entity: Entity) -> Option<&mut C>
+    #[cfg(feature = "resource")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
+    pub fn contains_non_send<R>(&self) -> bool
     where
-        C: Component,
+        R: 'static,
     {
-        self.components.get_mut::<C>(entity)
+        self.non_send.contains::<R>()
     }
 
-    /// Retrieves the shared borrow of the generic resource type.
+    /// Retrieves the shared borrow of the generic non-send resource type.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if called on the thread other than
+    /// the thread non-send resources of the world are bound to.
     ///
     /// # Examples
     ///
     /// ```
     /// # use toucan_ecs::prelude::*;
-    /// #[derive(Debug, Eq, PartialEq, Resource)]
-    /// struct ExampleResource(u32);
+    /// use std::rc::Rc;
     ///
     /// let mut world = World::new();
+    /// world.create_non_send(Rc::new(42));
     ///
-    /// world.create_resources(ExampleResource(42));
-    /// let resource = world.get_resource::<ExampleResource>().unwrap();
-    /// assert_eq!(*resource, ExampleResource(42));
+    /// let resource = world.get_non_send::<Rc<i32>>().unwrap();
+    /// assert_eq!(**resource, 42);
     /// ```
     #[cfg(feature = "resource")]
     #[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
-    pub fn get_resource<R>(&self) -> Option<&R>
+    pub fn get_non_send<R>(&self) -> Option<&R>
     where
-        R: Resource,
+        R: 'static,
     {
-        self.resources.get::<R>()
+        self.non_send.get::<R>()
     }
 
-    /// Retrieves the unique borrow of the generic resource type.
+    /// Retrieves the unique borrow of the generic non-send resource type.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if called on the thread other than
+    /// the thread non-send resources of the world are bound to.
     ///
     /// # Examples
     ///
     /// ```
     /// # use toucan_ecs::prelude::*;
-    /// #[derive(Debug, Eq, PartialEq, Resource)]
-    /// struct ExampleResource(u32);
+    /// use std::rc::Rc;
     ///
     /// let mut world = World::new();
-    /// world.create_resources(ExampleResource(42));
+    /// world.create_non_send(Rc::new(42));
     ///
-    /// let mut resource = world.get_resource_mut::<ExampleResource>().unwrap();
-    /// *resource = ExampleResource(35);
-    /// assert_eq!(*resource, ExampleResource(35));
+    /// let resource = world.get_non_send_mut::<Rc<i32>>().unwrap();
+    /// *resource = Rc::new(35);
+    /// assert_eq!(**resource, 35);
     /// ```
     #[cfg(feature = "resource")]
     #[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
-    pub fn get_resource_mut<R>(&mut self) -> Option<&mut R>
+    pub fn get_non_send_mut<R>(&mut self) -> Option<&mut R>
     where
-        R: Resource,
+        R: 'static,
     {
-        self.resources.get_mut::<R>()
+        self.non_send.get_mut::<R>()
+    }
+
+    #[cfg(feature = "resource")]
+    pub(crate) fn check_non_send_thread(&self) {
+        self.non_send.check_thread()
     }
 
     /// Creates a [view](ViewOne) of the component type.
@@ -678,12 +2161,12 @@ impl World {
     ///     println!("component: {:?}", *component)
     /// }
     /// ```
-    pub fn view_one<C>(&self) -> ViewOne<C>
+    pub fn view_one<C>(&self) -> ViewOne<'_, C>
     where
         C: Component,
     {
         let storage = self.components.get_storage::<C>();
-        ViewOne::new(storage)
+        ViewOne::new(storage, &self.entities)
     }
 
     /// Creates a [view](ViewOneMut) of the component type.
@@ -708,12 +2191,12 @@ impl World {
     ///     println!("component: {:?}", *component)
     /// }
     /// ```
-    pub fn view_one_mut<C>(&mut self) -> ViewOneMut<C>
+    pub fn view_one_mut<C>(&mut self) -> ViewOneMut<'_, C>
     where
         C: Component,
     {
-        let storage = self.components.get_storage_mut::<C>();
-        ViewOneMut::new(storage)
+        let (storage, touch) = self.components.get_storage_mut::<C>();
+        ViewOneMut::new(storage, &self.entities, touch)
     }
 
     /// Creates a [view](View) of the multiple component types.
@@ -761,7 +2244,7 @@ impl World {
     /// **References and Borrowing** section of [**Rust Book**][rust_book]:
     ///
     /// > - *At any given time, you can have either **one** mutable reference
-    /// or **any** number of immutable references.*
+    /// >   or **any** number of immutable references.*
     ///
     /// # Examples
     ///
@@ -796,6 +2279,7 @@ impl World {
         let mut data = WorldRefs {
             storages: StorageRefs::default(),
             resources: ResourceRefs::from(&self.resources),
+            non_send: None,
         };
         ForeachHolder::new(None, &mut data)
             .next()
@@ -817,7 +2301,7 @@ impl World {
     /// **References and Borrowing** section of [**Rust Book**][rust_book]:
     ///
     /// > - *At any given time, you can have either **one** mutable reference
-    /// or **any** number of immutable references.*
+    /// >   or **any** number of immutable references.*
     ///
     /// # Examples
     ///
@@ -865,6 +2349,78 @@ impl World {
         ViewMut::new(entities, &mut data)
     }
 
+    /// Creates a [dynamic view](DynamicView) of the world
+    /// which yields entities matching the [dynamic query](DynamicQuery).
+    ///
+    /// # Panics
+    ///
+    /// Function will panic if provided query retrieves any **unique** borrow of the component.
+    /// Use [`dynamic_view_mut`](World::dynamic_view_mut) for such queries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// use toucan_ecs::component::ComponentId;
+    /// use toucan_ecs::world::query::DynamicQuery;
+    ///
+    /// #[derive(Copy, Clone, Component, Debug, PartialEq)]
+    /// struct Name(&'static str);
+    ///
+    /// let mut world = World::new();
+    /// world.create_with(Name("Hello, World"));
+    ///
+    /// let query = DynamicQuery::new().with(ComponentId::of::<Name>());
+    /// for (_, components) in world.dynamic_view(&query) {
+    ///     let name = components[0].as_ref().unwrap().downcast_ref::<Name>();
+    ///     assert_eq!(name, Some(&Name("Hello, World")));
+    /// }
+    /// ```
+    pub fn dynamic_view(&self, query: &DynamicQuery) -> DynamicView<'_> {
+        assert!(
+            !query.is_mut(),
+            "dynamic query with unique borrows requires unique borrow of the world",
+        );
+        let entities = self.entities.iter();
+        let storages = StorageRefs::from(&self.components);
+        DynamicView::new(entities, storages, query)
+    }
+
+    /// Creates a [dynamic view](DynamicView) of the world
+    /// which yields entities matching the [dynamic query](DynamicQuery)
+    /// and can retrieve **unique** borrows of the components.
+    ///
+    /// # Panics
+    ///
+    /// Function will panic if provided query borrows the same component
+    /// both as **shared** and as **unique**, or as **unique** more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use toucan_ecs::prelude::*;
+    /// use std::alloc::Layout;
+    /// use toucan_ecs::component::ComponentDescriptor;
+    /// use toucan_ecs::world::query::DynamicQuery;
+    ///
+    /// let mut world = World::new();
+    /// let health = world.register_dynamic(ComponentDescriptor::new("Health", Layout::new::<u32>()));
+    /// let entity = world.create();
+    /// world.attach_dynamic(entity, health, &100u32.to_ne_bytes());
+    ///
+    /// let query = DynamicQuery::new().with_mut(health);
+    /// for (_, mut components) in world.dynamic_view_mut(&query) {
+    ///     let bytes = components[0].as_mut().unwrap().as_bytes_mut().unwrap();
+    ///     bytes.copy_from_slice(&50u32.to_ne_bytes());
+    /// }
+    /// assert_eq!(world.get_dynamic(entity, health), Some(&50u32.to_ne_bytes()[..]));
+    /// ```
+    pub fn dynamic_view_mut(&mut self, query: &DynamicQuery) -> DynamicView<'_> {
+        let entities = self.entities.iter();
+        let storages = StorageRefs::from(&mut self.components);
+        DynamicView::new(entities, storages, query)
+    }
+
     /// Retrieves multiple **unique** resource borrows by provided query.
     ///
     /// # Panics
@@ -878,7 +2434,7 @@ impl World {
     /// **References and Borrowing** section of [**Rust Book**][rust_book]:
     ///
     /// > - *At any given time, you can have either **one** mutable reference
-    /// or **any** number of immutable references.*
+    /// >   or **any** number of immutable references.*
     ///
     /// # Examples
     ///
@@ -899,7 +2455,7 @@ impl World {
     /// );
     /// let (mut example, another) = world.resource_view_mut::<Query>();
     ///
-    /// (*example).0 = 10;
+    /// example.0 = 10;
     /// assert_eq!(*example, ExampleResource(10));
     /// assert!(another.is_none());
     /// ```
@@ -914,15 +2470,24 @@ impl World {
         let mut data = WorldRefs {
             storages: StorageRefs::default(),
             resources: ResourceRefs::from(&mut self.resources),
+            non_send: None,
         };
         ForeachHolder::new(None, &mut data)
             .next()
             .expect("unable to view resources by provided query")
     }
 
+    #[cfg(feature = "resource")]
+    pub(crate) fn resource_ticks<R>(&self) -> Option<&Ticks>
+    where
+        R: Resource,
+    {
+        self.resources.ticks::<R>()
+    }
+
     /// Retrieves **immutable** borrowed type of the [world](World)
     /// that contains data of its entities and components.
-    pub fn components(&self) -> Components {
+    pub fn components(&self) -> Components<'_> {
         let entities = &self.entities;
         let components = &self.components;
         Components::new(entities, components)
@@ -930,7 +2495,7 @@ impl World {
 
     /// Retrieves **mutable** borrowed type of the [world](World)
     /// that contains data of its entities and components.
-    pub fn components_mut(&mut self) -> ComponentsMut {
+    pub fn components_mut(&mut self) -> ComponentsMut<'_> {
         let entities = &self.entities;
         let components = &mut self.components;
         ComponentsMut::new(entities, components)
@@ -940,7 +2505,7 @@ impl World {
     /// that contains data of its resources.
     #[cfg(feature = "resource")]
     #[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
-    pub fn resources(&self) -> Resources {
+    pub fn resources(&self) -> Resources<'_> {
         let resources = &self.resources;
         Resources::new(resources)
     }
@@ -949,7 +2514,7 @@ impl World {
     /// that contains data of its resources.
     #[cfg(feature = "resource")]
     #[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
-    pub fn resources_mut(&mut self) -> ResourcesMut {
+    pub fn resources_mut(&mut self) -> ResourcesMut<'_> {
         let resources = &mut self.resources;
         ResourcesMut::new(resources)
     }
@@ -958,7 +2523,7 @@ impl World {
     /// that contains all the data of this world.
     #[cfg(feature = "resource")]
     #[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
-    pub fn split(&self) -> Split {
+    pub fn split(&self) -> Split<'_> {
         let components = Components::new(&self.entities, &self.components);
         let resources = Resources::new(&self.resources);
         (components, resources)
@@ -968,38 +2533,56 @@ impl World {
     /// that contains all the data of this world.
     #[cfg(feature = "resource")]
     #[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
-    pub fn split_mut(&mut self) -> SplitMut {
+    pub fn split_mut(&mut self) -> SplitMut<'_> {
         let components = ComponentsMut::new(&self.entities, &mut self.components);
         let resources = ResourcesMut::new(&mut self.resources);
         (components, resources)
     }
 
-    fn split_refs(&self) -> (&EntityRegistry, WorldRefs) {
+    fn split_refs(&self) -> (&EntityRegistry, WorldRefs<'_>) {
         let entities = &self.entities;
         let refs = WorldRefs {
             storages: StorageRefs::from(&self.components),
             #[cfg(feature = "resource")]
             resources: ResourceRefs::default(),
+            #[cfg(feature = "resource")]
+            non_send: None,
         };
         (entities, refs)
     }
 
-    fn split_refs_mut(&mut self) -> (&EntityRegistry, WorldRefs) {
+    pub(crate) fn removal_counter(&self) -> u64 {
+        self.components.removed().counter()
+    }
+
+    pub(crate) fn removal_reader(&mut self) -> RemovalReader {
+        self.components.removal_reader()
+    }
+
+    pub(crate) fn prune_trackers(&mut self) {
+        self.components.prune_removed();
+    }
+
+    fn split_refs_mut(&mut self) -> (&EntityRegistry, WorldRefs<'_>) {
         let entities = &self.entities;
         let refs = WorldRefs {
             storages: StorageRefs::from(&mut self.components),
             #[cfg(feature = "resource")]
             resources: ResourceRefs::default(),
+            #[cfg(feature = "resource")]
+            non_send: None,
         };
         (entities, refs)
     }
 
-    pub(crate) fn split_refs_system_mut(&mut self) -> (&EntityRegistry, WorldRefs) {
+    pub(crate) fn split_refs_system_mut(&mut self) -> (&EntityRegistry, WorldRefs<'_>) {
         let entities = &self.entities;
         let refs = WorldRefs {
             storages: StorageRefs::from(&mut self.components),
             #[cfg(feature = "resource")]
             resources: ResourceRefs::from(&mut self.resources),
+            #[cfg(feature = "resource")]
+            non_send: Some(NonSendRefs::from(&mut self.non_send)),
         };
         (entities, refs)
     }
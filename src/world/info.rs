@@ -0,0 +1,179 @@
+use std::any::TypeId;
+use std::borrow::Cow;
+
+use crate::component::ComponentId;
+
+/// Information about the contents of the [world](crate::world::World).
+///
+/// It is a snapshot of the world which can be inspected
+/// without knowing concrete types of its components and resources,
+/// for example, by debug consoles or editors.
+///
+/// Created by [`World::info`](crate::world::World::info) associated function.
+///
+/// # Examples
+///
+/// ```
+/// # use toucan_ecs::prelude::*;
+/// #[derive(Copy, Clone, Component)]
+/// struct Name(&'static str);
+///
+/// let mut world = World::new();
+/// world.create_with(Name("Hello, World"));
+///
+/// let info = world.info();
+/// assert_eq!(info.entities(), 1);
+/// for component in info.components() {
+///     println!("{}: {} entities", component.name(), component.entities());
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct WorldInfo {
+    entities: usize,
+    components: Vec<ComponentInfo>,
+    #[cfg(feature = "resource")]
+    resources: Vec<ResourceInfo>,
+}
+
+impl WorldInfo {
+    pub(super) fn new(
+        entities: usize,
+        mut components: Vec<ComponentInfo>,
+        #[cfg(feature = "resource")] mut resources: Vec<ResourceInfo>,
+    ) -> Self {
+        components.sort_unstable_by(|a, b| a.name().cmp(b.name()));
+        #[cfg(feature = "resource")]
+        resources.sort_unstable_by_key(ResourceInfo::name);
+        Self {
+            entities,
+            components,
+            #[cfg(feature = "resource")]
+            resources,
+        }
+    }
+
+    /// Returns count of entities in the world.
+    pub fn entities(&self) -> usize {
+        self.entities
+    }
+
+    /// Returns information about all the registered component types
+    /// sorted by their names.
+    ///
+    /// Component type is registered if it was attached to any entity at least once
+    /// or was [registered](crate::world::World::register) explicitly.
+    /// All the [dynamic](crate::world::World::register_dynamic) component types
+    /// are registered too.
+    pub fn components(&self) -> &[ComponentInfo] {
+        &self.components
+    }
+
+    /// Returns information about the registered component type
+    /// or [`None`](Option::None) if this type was not registered.
+    pub fn component(&self, id: ComponentId) -> Option<&ComponentInfo> {
+        self.components.iter().find(|component| component.id == id)
+    }
+
+    /// Returns information about all the resources of the world
+    /// sorted by their names.
+    #[cfg(feature = "resource")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
+    pub fn resources(&self) -> &[ResourceInfo] {
+        &self.resources
+    }
+
+    /// Returns information about the resource
+    /// or [`None`](Option::None) if the world does not contain it.
+    #[cfg(feature = "resource")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
+    pub fn resource(&self, type_id: TypeId) -> Option<&ResourceInfo> {
+        self.resources
+            .iter()
+            .find(|resource| resource.type_id == type_id)
+    }
+}
+
+/// Information about the component type registered in the [world](crate::world::World).
+#[derive(Debug, Clone)]
+pub struct ComponentInfo {
+    id: ComponentId,
+    name: Cow<'static, str>,
+    size: usize,
+    storage_name: &'static str,
+    entities: usize,
+}
+
+impl ComponentInfo {
+    pub(crate) fn new(
+        id: ComponentId,
+        name: Cow<'static, str>,
+        size: usize,
+        storage_name: &'static str,
+        entities: usize,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            size,
+            storage_name,
+            entities,
+        }
+    }
+
+    /// Returns [identifier](ComponentId) of the component type.
+    pub fn id(&self) -> ComponentId {
+        self.id
+    }
+
+    /// Returns [type identifier](TypeId) of the component type
+    /// or [`None`](Option::None) if the component type is dynamic.
+    pub fn type_id(&self) -> Option<TypeId> {
+        self.id.type_id()
+    }
+
+    /// Returns name of the component type.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns size of the component type in bytes.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns name of the [storage](crate::component::storage::Storage) type of the component.
+    pub fn storage_name(&self) -> &'static str {
+        self.storage_name
+    }
+
+    /// Returns count of entities which have component of this type attached.
+    pub fn entities(&self) -> usize {
+        self.entities
+    }
+}
+
+/// Information about the resource of the [world](crate::world::World).
+#[derive(Debug, Clone)]
+#[cfg(feature = "resource")]
+#[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
+pub struct ResourceInfo {
+    type_id: TypeId,
+    name: &'static str,
+}
+
+#[cfg(feature = "resource")]
+impl ResourceInfo {
+    pub(crate) fn new(type_id: TypeId, name: &'static str) -> Self {
+        Self { type_id, name }
+    }
+
+    /// Returns [type identifier](TypeId) of the resource.
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// Returns name of the resource type.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
@@ -0,0 +1,47 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Policy of [merging](super::World::merge) resources of two worlds
+/// when both of them contain resource of the same type.
+///
+/// Policy is ignored if feature `resource` is disabled.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum MergePolicy {
+    /// Resources of merged world are not moved at all,
+    /// so only its entities are merged.
+    Skip,
+    /// Resources of this world are kept, conflicting resources of merged world are dropped.
+    #[default]
+    Keep,
+    /// Resources of this world are replaced with conflicting resources of merged world.
+    Replace,
+    /// Merge fails if any resource conflicts, leaving both worlds untouched.
+    Error,
+}
+
+/// Error which is returned from [merging](super::World::merge) of two worlds
+/// with [error policy](MergePolicy::Error) when both of them contain resource of the same type.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MergeError {
+    type_name: &'static str,
+}
+
+impl MergeError {
+    #[cfg(feature = "resource")]
+    pub(super) fn new(type_name: &'static str) -> Self {
+        Self { type_name }
+    }
+
+    /// Returns name of the type of conflicting resource.
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+}
+
+impl Display for MergeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Resource `{}` exists in both worlds", self.type_name)
+    }
+}
+
+impl Error for MergeError {}
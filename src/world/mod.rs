@@ -3,19 +3,30 @@
 
 pub use components::{Components, ComponentsMut};
 pub use entry::Entry;
+pub use from_world::FromWorld;
 pub use impls::World;
+pub use info::{ComponentInfo, WorldInfo};
+pub use merge::{MergeError, MergePolicy};
+pub use patch::{ChangeKind, ComponentPatch, PatchComponent, PatchError, WorldPatch};
+pub use snapshot::WorldSnapshot;
 pub(crate) use world_refs::WorldRefs;
 #[cfg(feature = "resource")]
 pub use {
     impls::{Split, SplitMut},
+    info::ResourceInfo,
     resources::{Resources, ResourcesMut},
 };
 
 mod components;
 mod entry;
+mod from_world;
 mod impls;
+mod info;
+mod merge;
+mod patch;
 #[cfg(feature = "resource")]
 mod resources;
+mod snapshot;
 mod world_refs;
 
 pub mod query;
@@ -0,0 +1,211 @@
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::component::Component;
+use crate::entity::Entity;
+
+pub(crate) use registry::Registry;
+
+mod registry;
+
+/// Trait for components which can be included into [world patch](WorldPatch).
+///
+/// It is implemented automatically for all the components which implement [`PartialEq`]
+/// and, if feature `serde` is enabled, [`Serialize`](serde::Serialize)
+/// and [`Deserialize`](serde::Deserialize) traits.
+#[cfg(not(feature = "serde"))]
+pub trait PatchComponent: Component + PartialEq {}
+
+#[cfg(not(feature = "serde"))]
+impl<C> PatchComponent for C where C: Component + PartialEq {}
+
+/// Trait for components which can be included into [world patch](WorldPatch).
+///
+/// It is implemented automatically for all the components which implement [`PartialEq`]
+/// and, if feature `serde` is enabled, [`Serialize`](serde::Serialize)
+/// and [`Deserialize`](serde::Deserialize) traits.
+#[cfg(feature = "serde")]
+pub trait PatchComponent: Component + PartialEq + Serialize + for<'de> Deserialize<'de> {}
+
+#[cfg(feature = "serde")]
+impl<C> PatchComponent for C where C: Component + PartialEq + Serialize + for<'de> Deserialize<'de> {}
+
+#[cfg(not(feature = "serde"))]
+pub(crate) type PatchValue = crate::entity::ErasedComponentHolder;
+
+#[cfg(feature = "serde")]
+pub(crate) type PatchValue = serde_value::Value;
+
+/// Difference between [snapshot](super::WorldSnapshot) of the world and its current state.
+///
+/// Patch is created by [`World::diff`](super::World::diff)
+/// and can be applied to another world with [`World::apply_patch`](super::World::apply_patch).
+/// Only components [registered](super::World::register_patch) in the world are included.
+///
+/// Content of the patch is deterministically ordered:
+/// entities are sorted by their handles, components are sorted by their
+/// [registered names](super::World::register_patch_as) and then by handles of the entities. With enabled feature `serde`,
+/// patch can be serialized to be sent over the network.
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Debug, PartialEq, Serialize, Deserialize))]
+pub struct WorldPatch {
+    created: Vec<Entity>,
+    destroyed: Vec<Entity>,
+    disabled: Vec<Entity>,
+    enabled: Vec<Entity>,
+    components: Vec<ComponentPatch>,
+}
+
+impl WorldPatch {
+    pub(super) fn new(
+        mut created: Vec<Entity>,
+        mut destroyed: Vec<Entity>,
+        mut disabled: Vec<Entity>,
+        mut enabled: Vec<Entity>,
+        components: Vec<ComponentPatch>,
+    ) -> Self {
+        created.sort_unstable();
+        destroyed.sort_unstable();
+        disabled.sort_unstable();
+        enabled.sort_unstable();
+        Self {
+            created,
+            destroyed,
+            disabled,
+            enabled,
+            components,
+        }
+    }
+
+    /// Returns entities created since the snapshot.
+    pub fn created(&self) -> &[Entity] {
+        &self.created
+    }
+
+    /// Returns entities destroyed since the snapshot.
+    pub fn destroyed(&self) -> &[Entity] {
+        &self.destroyed
+    }
+
+    /// Returns entities [disabled](super::World::disable) since the snapshot,
+    /// including created entities which are disabled.
+    pub fn disabled(&self) -> &[Entity] {
+        &self.disabled
+    }
+
+    /// Returns entities [enabled](super::World::enable) since the snapshot.
+    pub fn enabled(&self) -> &[Entity] {
+        &self.enabled
+    }
+
+    /// Returns changes of the components since the snapshot.
+    pub fn components(&self) -> &[ComponentPatch] {
+        &self.components
+    }
+
+    /// Returns `true` if nothing has changed since the snapshot.
+    pub fn is_empty(&self) -> bool {
+        self.created.is_empty()
+            && self.destroyed.is_empty()
+            && self.disabled.is_empty()
+            && self.enabled.is_empty()
+            && self.components.is_empty()
+    }
+}
+
+/// Change of the component of one entity in the [world patch](WorldPatch).
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Debug, PartialEq, Serialize, Deserialize))]
+pub struct ComponentPatch {
+    name: Cow<'static, str>,
+    entity: Entity,
+    change: Change,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Debug, PartialEq, Serialize, Deserialize))]
+pub(crate) enum Change {
+    Attached(PatchValue),
+    Changed(PatchValue),
+    Removed,
+}
+
+impl ComponentPatch {
+    pub(crate) fn new(name: &'static str, entity: Entity, change: Change) -> Self {
+        Self {
+            name: name.into(),
+            entity,
+            change,
+        }
+    }
+
+    /// Returns name which the component type was [registered](super::World::register_patch_as) with.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the entity which component was changed.
+    pub fn entity(&self) -> Entity {
+        self.entity
+    }
+
+    /// Returns kind of the change of the component.
+    pub fn kind(&self) -> ChangeKind {
+        match self.change {
+            Change::Attached(_) => ChangeKind::Attached,
+            Change::Changed(_) => ChangeKind::Changed,
+            Change::Removed => ChangeKind::Removed,
+        }
+    }
+
+    pub(crate) fn change(&self) -> &Change {
+        &self.change
+    }
+}
+
+/// Kind of the change of the component in the [world patch](WorldPatch).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ChangeKind {
+    /// Component was attached to the entity.
+    Attached,
+    /// Value of the component was changed.
+    Changed,
+    /// Component was removed from the entity.
+    Removed,
+}
+
+/// Error which can occur while [applying](super::World::apply_patch) the world patch.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum PatchError {
+    /// Type of the component was not [registered](super::World::register_patch) in the world.
+    UnknownComponent {
+        /// Registered name of the component.
+        name: String,
+    },
+    /// Value of the component cannot be converted into its type.
+    InvalidValue {
+        /// Registered name of the component.
+        name: String,
+        /// Description of the conversion error.
+        message: String,
+    },
+}
+
+impl Display for PatchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownComponent { name } => {
+                write!(f, "Component `{}` is not registered", name)
+            }
+            Self::InvalidValue { name, message } => {
+                write!(f, "Invalid value of component `{}`: {}", name, message)
+            }
+        }
+    }
+}
+
+impl Error for PatchError {}
@@ -0,0 +1,161 @@
+use std::collections::BTreeMap;
+
+use crate::component::ComponentTypeId;
+use crate::entity::Entity;
+use crate::marker::IncludeDisabled;
+use crate::world::patch::{Change, ComponentPatch, PatchComponent, PatchError, PatchValue};
+use crate::world::{World, WorldSnapshot};
+
+type DiffFn = fn(&World, &WorldSnapshot, &'static str, &mut Vec<ComponentPatch>);
+type ApplyFn = fn(&mut World, Entity, &'static str, &Change) -> Result<(), PatchError>;
+
+struct Entry {
+    type_id: ComponentTypeId,
+    diff: DiffFn,
+    apply: ApplyFn,
+}
+
+#[derive(Default)]
+pub struct Registry {
+    types: BTreeMap<&'static str, Entry>,
+}
+
+impl Registry {
+    pub fn register<C>(&mut self, name: &'static str)
+    where
+        C: PatchComponent,
+    {
+        let type_id = ComponentTypeId::of::<C>();
+        if let Some(entry) = self.types.get(name) {
+            assert!(
+                entry.type_id == type_id,
+                "patch name `{}` is already registered for another component",
+                name,
+            );
+        }
+        self.types.retain(|_, entry| entry.type_id != type_id);
+        let entry = Entry {
+            type_id,
+            diff: diff::<C>,
+            apply: apply::<C>,
+        };
+        self.types.insert(name, entry);
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.types.contains_key(name)
+    }
+
+    pub fn diff(&self, world: &World, snapshot: &WorldSnapshot) -> Vec<ComponentPatch> {
+        let mut patches = Vec::new();
+        for (&name, entry) in &self.types {
+            (entry.diff)(world, snapshot, name, &mut patches);
+        }
+        patches
+    }
+
+    pub fn apply(
+        &self,
+        world: &mut World,
+        entity: Entity,
+        patch: &ComponentPatch,
+    ) -> Result<(), PatchError> {
+        let (&name, entry) =
+            self.types
+                .get_key_value(patch.name())
+                .ok_or_else(|| PatchError::UnknownComponent {
+                    name: patch.name().to_string(),
+                })?;
+        (entry.apply)(world, entity, name, patch.change())
+    }
+}
+
+fn diff<C>(
+    world: &World,
+    snapshot: &WorldSnapshot,
+    name: &'static str,
+    patches: &mut Vec<ComponentPatch>,
+) where
+    C: PatchComponent,
+{
+    let mut changes = Vec::new();
+    for (entity, component, _) in world.view::<(Entity, &C, IncludeDisabled)>() {
+        let change = match snapshot.get::<C>(entity) {
+            None => Change::Attached(into_value(component)),
+            Some(old) if old != component => Change::Changed(into_value(component)),
+            Some(_) => continue,
+        };
+        changes.push((entity, change));
+    }
+    for (entity, _) in snapshot.iter::<C>() {
+        if world.contains(entity) && !world.attached::<C>(entity) {
+            changes.push((entity, Change::Removed));
+        }
+    }
+    changes.sort_unstable_by_key(|&(entity, _)| entity);
+    let changes = changes
+        .into_iter()
+        .map(|(entity, change)| ComponentPatch::new(name, entity, change));
+    patches.extend(changes);
+}
+
+fn apply<C>(
+    world: &mut World,
+    entity: Entity,
+    name: &'static str,
+    change: &Change,
+) -> Result<(), PatchError>
+where
+    C: PatchComponent,
+{
+    match change {
+        Change::Attached(value) | Change::Changed(value) => {
+            let component = from_value::<C>(value, name)?;
+            world.attach(entity, component);
+        }
+        Change::Removed => world.remove::<C>(entity),
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "serde"))]
+fn into_value<C>(component: &C) -> PatchValue
+where
+    C: PatchComponent,
+{
+    PatchValue::from(*component)
+}
+
+#[cfg(feature = "serde")]
+fn into_value<C>(component: &C) -> PatchValue
+where
+    C: PatchComponent,
+{
+    serde_value::to_value(component).expect("component should be serializable")
+}
+
+#[cfg(not(feature = "serde"))]
+fn from_value<C>(value: &PatchValue, name: &str) -> Result<C, PatchError>
+where
+    C: PatchComponent,
+{
+    let component = value.as_component_ref::<C>().copied();
+    component.ok_or_else(|| PatchError::InvalidValue {
+        name: name.to_string(),
+        message: "component has another type".to_string(),
+    })
+}
+
+#[cfg(feature = "serde")]
+fn from_value<C>(value: &PatchValue, name: &str) -> Result<C, PatchError>
+where
+    C: PatchComponent,
+{
+    value
+        .clone()
+        .deserialize_into()
+        .map_err(|error| PatchError::InvalidValue {
+            name: name.to_string(),
+            message: error.to_string(),
+        })
+}
@@ -0,0 +1,92 @@
+use crate::component::ComponentId;
+
+/// Query of the [world](crate::world::World) which is built at runtime
+/// from [component identifiers](ComponentId).
+///
+/// Unlike [static queries](super::Query), it does not require component types
+/// to be known at compile time, so it can be constructed from data,
+/// for example, by editors or scripting layers.
+/// Both [static](ComponentId::of) and [dynamic](crate::world::World::register_dynamic)
+/// component types can be used in the same query.
+///
+/// # Examples
+///
+/// ```
+/// # use toucan_ecs::prelude::*;
+/// use toucan_ecs::component::ComponentId;
+/// use toucan_ecs::world::query::DynamicQuery;
+///
+/// #[derive(Copy, Clone, Component)]
+/// struct Position(f32, f32);
+///
+/// #[derive(Copy, Clone, Component)]
+/// struct Frozen;
+///
+/// let query = DynamicQuery::new()
+///     .with_mut(ComponentId::of::<Position>())
+///     .without(ComponentId::of::<Frozen>());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DynamicQuery {
+    terms: Vec<(ComponentId, Access)>,
+    include_disabled: bool,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum Access {
+    Ref,
+    Mut,
+    Optional,
+    Without,
+}
+
+impl DynamicQuery {
+    /// Creates an empty query which matches all the entities of the world.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requires the entity to have the component and retrieves its **shared** borrow.
+    pub fn with(self, id: ComponentId) -> Self {
+        self.term(id, Access::Ref)
+    }
+
+    /// Requires the entity to have the component and retrieves its **unique** borrow.
+    pub fn with_mut(self, id: ComponentId) -> Self {
+        self.term(id, Access::Mut)
+    }
+
+    /// Retrieves **shared** borrow of the component if the entity has it.
+    pub fn optional(self, id: ComponentId) -> Self {
+        self.term(id, Access::Optional)
+    }
+
+    /// Requires the entity not to have the component.
+    pub fn without(self, id: ComponentId) -> Self {
+        self.term(id, Access::Without)
+    }
+
+    /// Retrieves [disabled](crate::world::World::disable()) entities along with enabled ones.
+    pub fn include_disabled(mut self) -> Self {
+        self.include_disabled = true;
+        self
+    }
+
+    /// Returns `true` if the query retrieves any **unique** borrow of the component.
+    pub fn is_mut(&self) -> bool {
+        self.terms.iter().any(|&(_, access)| access == Access::Mut)
+    }
+
+    pub(crate) fn terms(&self) -> &[(ComponentId, Access)] {
+        &self.terms
+    }
+
+    pub(crate) fn includes_disabled(&self) -> bool {
+        self.include_disabled
+    }
+
+    fn term(mut self, id: ComponentId, access: Access) -> Self {
+        self.terms.push((id, access));
+        self
+    }
+}
@@ -8,25 +8,77 @@ use crate::{
     world::query::{ResourceQuery, ResourceQueryMut},
 };
 
-impl<'data> Query<'data> for Entity {}
-
-impl<'data> QueryMut<'data> for Entity {}
-
-impl<'data, C> Query<'data> for &'data C where C: Component {}
-
-impl<'data, C> QueryMut<'data> for &'data C where C: Component {}
-
-impl<'data, C> Query<'data> for Option<&'data C> where C: Component {}
-
-impl<'data, C> QueryMut<'data> for Option<&'data C> where C: Component {}
-
-impl<'data, C> Query<'data> for Not<C> where C: Component {}
-
-impl<'data, C> QueryMut<'data> for Not<C> where C: Component {}
-
-impl<'data, C> QueryMut<'data> for &'data mut C where C: Component {}
-
-impl<'data, C> QueryMut<'data> for Option<&'data mut C> where C: Component {}
+impl<'data> Query<'data> for Entity {
+    type RebindRef<'w> = Entity;
+}
+
+impl<'data> QueryMut<'data> for Entity {
+    type RebindMut<'w> = Entity;
+}
+
+impl<'data> Query<'data> for IncludeDisabled {
+    type RebindRef<'w> = IncludeDisabled;
+}
+
+impl<'data> QueryMut<'data> for IncludeDisabled {
+    type RebindMut<'w> = IncludeDisabled;
+}
+
+impl<'data, C> Query<'data> for &'data C
+where
+    C: Component,
+{
+    type RebindRef<'w> = &'w C;
+}
+
+impl<'data, C> QueryMut<'data> for &'data C
+where
+    C: Component,
+{
+    type RebindMut<'w> = &'w C;
+}
+
+impl<'data, C> Query<'data> for Option<&'data C>
+where
+    C: Component,
+{
+    type RebindRef<'w> = Option<&'w C>;
+}
+
+impl<'data, C> QueryMut<'data> for Option<&'data C>
+where
+    C: Component,
+{
+    type RebindMut<'w> = Option<&'w C>;
+}
+
+impl<'data, C> Query<'data> for Not<C>
+where
+    C: Component,
+{
+    type RebindRef<'w> = Not<C>;
+}
+
+impl<'data, C> QueryMut<'data> for Not<C>
+where
+    C: Component,
+{
+    type RebindMut<'w> = Not<C>;
+}
+
+impl<'data, C> QueryMut<'data> for &'data mut C
+where
+    C: Component,
+{
+    type RebindMut<'w> = &'w mut C;
+}
+
+impl<'data, C> QueryMut<'data> for Option<&'data mut C>
+where
+    C: Component,
+{
+    type RebindMut<'w> = Option<&'w mut C>;
+}
 
 #[cfg(feature = "resource")]
 #[cfg_attr(docsrs, doc(cfg(feature = "resource")))]
@@ -5,8 +5,10 @@
 //! - immutable and mutable [references] of [components];
 //! - immutable and mutable [references] of [components] wrapped in [`Option`];
 //! - immutable and mutable [references] of [resources] via [markers](crate::marker)
-//! (if enabled by `resource` feature);
+//!   (if enabled by `resource` feature);
 //! - [`Not`](crate::marker::Not) marker type of components;
+//! - [`IncludeDisabled`](crate::marker::IncludeDisabled) marker type
+//!   to retrieve [disabled](crate::world::World::disable()) entities;
 //! - and [tuples] of arity 12 or less of types listed above.
 //!
 //! [references]: prim@reference
@@ -14,8 +16,11 @@
 //! [components]: crate::component::Component
 //! [resources]: crate::resource::Resource
 
+pub use dynamic::DynamicQuery;
+
 use crate::system::foreach;
 
+pub(crate) mod dynamic;
 mod impls;
 mod tuple;
 
@@ -23,13 +28,25 @@ mod tuple;
 /// of the [world](crate::world::World).
 ///
 /// This trait is **sealed** and cannot be implemented for types outside of `toucan_ecs`.
-pub trait Query<'data>: QueryMut<'data> {}
+pub trait Query<'data>: QueryMut<'data> {
+    /// The same query with the lifetime of the world borrow replaced by `'w`.
+    ///
+    /// Used by [systems](crate::system::System) which are generic over their query,
+    /// see [`ScheduleBuilder::system`](crate::system::ScheduleBuilder::system).
+    type RebindRef<'w>: Query<'w>;
+}
 
 /// Type which can be queried by **mutable** [view](crate::world::view::ViewMut)
 /// of the [world](crate::world::World).
 ///
 /// This trait is **sealed** and cannot be implemented for types outside of `toucan_ecs`.
-pub trait QueryMut<'data>: foreach::Query<'data> {}
+pub trait QueryMut<'data>: foreach::Query<'data> {
+    /// The same query with the lifetime of the world borrow replaced by `'w`.
+    ///
+    /// Used by [systems](crate::system::System) which are generic over their query,
+    /// see [`ScheduleBuilder::system`](crate::system::ScheduleBuilder::system).
+    type RebindMut<'w>: QueryMut<'w>;
+}
 
 /// Type which can be queried
 /// by **shared** resource [view][crate::world::World::resource_view()]
@@ -1,14 +1,13 @@
-use crate::system::foreach::QueryItem;
-
 use super::*;
 
 macro_rules! query {
     ($($types:ident),*) => {
         impl<'data, $($types),*> Query<'data> for ($($types,)*)
         where
-            Self: From<($(QueryItem<'data, $types>,)*)>,
             $($types: Query<'data>,)*
-        {}
+        {
+            type RebindRef<'w> = ($($types::RebindRef<'w>,)*);
+        }
     };
 }
 
@@ -30,9 +29,10 @@ macro_rules! query_mut {
     ($($types:ident),*) => {
         impl<'data, $($types),*> QueryMut<'data> for ($($types,)*)
         where
-            Self: From<($(QueryItem<'data, $types>,)*)>,
             $($types: QueryMut<'data>,)*
-        {}
+        {
+            type RebindMut<'w> = ($($types::RebindMut<'w>,)*);
+        }
     };
 }
 
@@ -55,7 +55,6 @@ macro_rules! resource_query {
     ($($types:ident),*) => {
         impl<'data, $($types),*> ResourceQuery<'data> for ($($types,)*)
         where
-            Self: From<($(QueryItem<'data, $types>,)*)>,
             $($types: ResourceQuery<'data>,)*
         {}
     };
@@ -85,7 +84,6 @@ macro_rules! resource_query_mut {
     ($($types:ident),*) => {
         impl<'data, $($types),*> ResourceQueryMut<'data> for ($($types,)*)
         where
-            Self: From<($(QueryItem<'data, $types>,)*)>,
             $($types: ResourceQueryMut<'data>,)*
         {}
     };
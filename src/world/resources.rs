@@ -115,6 +115,7 @@ impl<'world> Resources<'world> {
         let mut data = WorldRefs {
             storages: StorageRefs::default(),
             resources: ResourceRefs::from(self.resources),
+            non_send: None,
         };
         ForeachHolder::new(None, &mut data)
             .next()
@@ -257,6 +258,7 @@ impl<'world> ResourcesMut<'world> {
         let mut data = WorldRefs {
             storages: StorageRefs::default(),
             resources: ResourceRefs::from(&*self.resources),
+            non_send: None,
         };
         ForeachHolder::new(None, &mut data)
             .next()
@@ -276,7 +278,7 @@ impl<'world> ResourcesMut<'world> {
     /// **References and Borrowing** section of [**Rust Book**][rust_book]:
     ///
     /// > - *At any given time, you can have either **one** mutable reference
-    /// or **any** number of immutable references.*
+    /// >   or **any** number of immutable references.*
     ///
     /// # Examples
     ///
@@ -298,7 +300,7 @@ impl<'world> ResourcesMut<'world> {
     /// let mut resources = world.resources_mut();
     /// let (mut example, another) = resources.view_mut::<Query>();
     ///
-    /// (*example).0 = 10;
+    /// example.0 = 10;
     /// assert_eq!(*example, ExampleResource(10));
     /// assert!(another.is_none());
     /// ```
@@ -311,6 +313,7 @@ impl<'world> ResourcesMut<'world> {
         let mut data = WorldRefs {
             storages: StorageRefs::default(),
             resources: ResourceRefs::from(&mut *self.resources),
+            non_send: None,
         };
         ForeachHolder::new(None, &mut data)
             .next()
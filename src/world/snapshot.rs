@@ -0,0 +1,76 @@
+use crate::component::storage::{DynIter, Storage};
+use crate::component::{Component, Registry as ComponentRegistry};
+use crate::entity::{Entity, Names, Registry as EntityRegistry};
+
+/// Copy of the entities and components of the [world](super::World)
+/// at some point in time.
+///
+/// Snapshot is created by [`World::snapshot`](super::World::snapshot)
+/// and can be restored any number of times with [`World::restore`](super::World::restore).
+/// It contains state of the entity allocator too, so handles of the entities
+/// which were alive at the moment of snapshot stay valid after restoring.
+pub struct WorldSnapshot {
+    entities: EntityRegistry,
+    components: ComponentRegistry,
+    names: Names,
+}
+
+impl WorldSnapshot {
+    pub(super) fn new(
+        entities: EntityRegistry,
+        components: ComponentRegistry,
+        names: Names,
+    ) -> Self {
+        Self {
+            entities,
+            components,
+            names,
+        }
+    }
+
+    /// Returns `true` if the entity was alive at the moment of snapshot.
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.entities.contains(entity)
+    }
+
+    /// Returns count of the entities which were alive at the moment of snapshot.
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    /// Returns `true` if there were no entities at the moment of snapshot.
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    /// Retrieves the shared borrow for the component of one type
+    /// which was attached to the entity at the moment of snapshot.
+    pub fn get<C>(&self, entity: Entity) -> Option<&C>
+    where
+        C: Component,
+    {
+        self.components.get::<C>(entity)
+    }
+
+    pub(crate) fn iter<C>(&self) -> Box<DynIter<'_, C>>
+    where
+        C: Component,
+    {
+        match self.components.get_storage::<C>() {
+            Some(storage) => storage.iter(),
+            None => Box::new(std::iter::empty()),
+        }
+    }
+
+    pub(super) fn entities(&self) -> &EntityRegistry {
+        &self.entities
+    }
+
+    pub(super) fn components(&self) -> &ComponentRegistry {
+        &self.components
+    }
+
+    pub(super) fn names(&self) -> &Names {
+        &self.names
+    }
+}
@@ -0,0 +1,252 @@
+use std::any::Any;
+use std::mem::transmute;
+
+use crate::component::storage::{DynamicStorage, ErasedStorageHolder};
+use crate::component::{Component, ComponentId, RegistryRefs as StorageRefs, Touch};
+use crate::entity::{Entity, Iter, Registry};
+use crate::system::foreach::fetch::find_smallest;
+use crate::world::query::dynamic::Access;
+use crate::world::query::DynamicQuery;
+
+/// Type-erased borrow of the component retrieved by [dynamic view](DynamicView).
+///
+/// Components of types known at compile time are borrowed as [`Any`] trait objects,
+/// while data of dynamic components is borrowed as raw bytes.
+#[derive(Debug)]
+pub enum DynamicComponent<'data> {
+    /// **Shared** borrow of the component of type known at compile time.
+    Ref(&'data dyn Any),
+    /// **Unique** borrow of the component of type known at compile time.
+    Mut(&'data mut dyn Any),
+    /// **Shared** borrow of the dynamic component data.
+    Bytes(&'data [u8]),
+    /// **Unique** borrow of the dynamic component data.
+    BytesMut(&'data mut [u8]),
+}
+
+impl<'data> DynamicComponent<'data> {
+    /// Returns **shared** borrow of the component if it is of generic type.
+    pub fn downcast_ref<C>(&self) -> Option<&C>
+    where
+        C: Component,
+    {
+        match self {
+            Self::Ref(component) => component.downcast_ref(),
+            Self::Mut(component) => component.downcast_ref(),
+            Self::Bytes(_) | Self::BytesMut(_) => None,
+        }
+    }
+
+    /// Returns **unique** borrow of the component if it is of generic type
+    /// and was borrowed uniquely.
+    pub fn downcast_mut<C>(&mut self) -> Option<&mut C>
+    where
+        C: Component,
+    {
+        match self {
+            Self::Mut(component) => component.downcast_mut(),
+            Self::Ref(_) | Self::Bytes(_) | Self::BytesMut(_) => None,
+        }
+    }
+
+    /// Returns **shared** borrow of the data if the component is dynamic.
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Self::Bytes(bytes) => Some(bytes),
+            Self::BytesMut(bytes) => Some(bytes),
+            Self::Ref(_) | Self::Mut(_) => None,
+        }
+    }
+
+    /// Returns **unique** borrow of the data if the component is dynamic
+    /// and was borrowed uniquely.
+    pub fn as_bytes_mut(&mut self) -> Option<&mut [u8]> {
+        match self {
+            Self::BytesMut(bytes) => Some(bytes),
+            Self::Ref(_) | Self::Mut(_) | Self::Bytes(_) => None,
+        }
+    }
+}
+
+/// Iterator which returns *entities* of the world
+/// with type-erased borrows of their components
+/// requested by the [dynamic query](DynamicQuery).
+///
+/// Borrows are returned in the order of `with`, `with_mut` and `optional` terms of the query.
+/// Borrows of the components required by `with` and `with_mut` terms are always present.
+pub struct DynamicView<'data> {
+    entities: std::vec::IntoIter<Entity>,
+    disabled: Option<&'data Registry>,
+    columns: Vec<Column<'data>>,
+    filters: Vec<Source<'data>>,
+}
+
+struct Column<'data> {
+    source: Source<'data>,
+    required: bool,
+}
+
+enum Source<'data> {
+    Missing,
+    Ref(&'data ErasedStorageHolder),
+    Bytes(&'data DynamicStorage),
+    Mut(&'data mut ErasedStorageHolder, Touch<'data>),
+    BytesMut(&'data mut DynamicStorage),
+}
+
+impl<'data> DynamicView<'data> {
+    pub(crate) fn new(
+        entities: Iter<'data>,
+        mut refs: StorageRefs<'data>,
+        query: &DynamicQuery,
+    ) -> Self {
+        let mut columns = Vec::new();
+        let mut filters = Vec::new();
+        for &(id, access) in query.terms() {
+            match access {
+                Access::Ref => columns.push(Column {
+                    source: Source::new_ref(&mut refs, id),
+                    required: true,
+                }),
+                Access::Mut => columns.push(Column {
+                    source: Source::new_mut(&mut refs, id),
+                    required: true,
+                }),
+                Access::Optional => columns.push(Column {
+                    source: Source::new_ref(&mut refs, id),
+                    required: false,
+                }),
+                Access::Without => filters.push(Source::new_ref(&mut refs, id)),
+            }
+        }
+
+        let disabled = Some(entities.registry())
+            .filter(|registry| !query.includes_disabled() && !registry.disabled().is_empty());
+        let required = columns.iter().filter(|column| column.required);
+        let optimal = find_smallest(required, |column| column.source.len());
+        let entities = match optimal {
+            None => entities.collect(),
+            Some(column) => column.source.entities(),
+        };
+        let entities = entities.into_iter();
+        Self {
+            entities,
+            disabled,
+            columns,
+            filters,
+        }
+    }
+}
+
+impl<'data> Iterator for DynamicView<'data> {
+    type Item = (Entity, Vec<Option<DynamicComponent<'data>>>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let entity = self.entities.next()?;
+            if let Some(registry) = self.disabled {
+                if registry.is_disabled(entity) {
+                    continue;
+                }
+            }
+            if self.filters.iter().any(|filter| filter.contains(entity)) {
+                continue;
+            }
+            let matches = self
+                .columns
+                .iter()
+                .all(|column| !column.required || column.source.contains(entity));
+            if !matches {
+                continue;
+            }
+            let components = self
+                .columns
+                .iter_mut()
+                .map(|column| column.source.take(entity))
+                .collect();
+            return Some((entity, components));
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.entities.len()))
+    }
+}
+
+impl<'data> Source<'data> {
+    fn new_ref(refs: &mut StorageRefs<'data>, id: ComponentId) -> Self {
+        let source = match id.as_static() {
+            Some(type_id) => refs.move_erased_ref(type_id).map(Self::Ref),
+            None => refs.move_dynamic_ref(id).map(Self::Bytes),
+        };
+        source.unwrap_or(Self::Missing)
+    }
+
+    fn new_mut(refs: &mut StorageRefs<'data>, id: ComponentId) -> Self {
+        let source = match id.as_static() {
+            Some(type_id) => refs
+                .move_erased_mut(type_id)
+                .map(|(storage, touch)| Self::Mut(storage, touch)),
+            None => refs.move_dynamic_mut(id).map(Self::BytesMut),
+        };
+        source.unwrap_or(Self::Missing)
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Self::Missing => 0,
+            Self::Ref(storage) => storage.len(),
+            Self::Bytes(storage) => storage.len(),
+            Self::Mut(storage, _) => storage.len(),
+            Self::BytesMut(storage) => storage.len(),
+        }
+    }
+
+    fn entities(&self) -> Vec<Entity> {
+        match self {
+            Self::Missing => Vec::new(),
+            Self::Ref(storage) => storage.entities().collect(),
+            Self::Bytes(storage) => storage.iter().map(|(entity, _)| entity).collect(),
+            Self::Mut(storage, _) => storage.entities().collect(),
+            Self::BytesMut(storage) => storage.iter().map(|(entity, _)| entity).collect(),
+        }
+    }
+
+    fn contains(&self, entity: Entity) -> bool {
+        match self {
+            Self::Missing => false,
+            Self::Ref(storage) => storage.attached(entity),
+            Self::Bytes(storage) => storage.attached(entity),
+            Self::Mut(storage, _) => storage.attached(entity),
+            Self::BytesMut(storage) => storage.attached(entity),
+        }
+    }
+
+    fn take(&mut self, entity: Entity) -> Option<DynamicComponent<'data>> {
+        match self {
+            Self::Missing => None,
+            Self::Ref(storage) => {
+                let storage: &'data ErasedStorageHolder = storage;
+                storage.get_any(entity).map(DynamicComponent::Ref)
+            }
+            Self::Bytes(storage) => {
+                let storage: &'data DynamicStorage = storage;
+                storage.get(entity).map(DynamicComponent::Bytes)
+            }
+            Self::Mut(storage, touch) => {
+                // SAFETY: view returns each entity at most once,
+                // so returned borrows of the components do not overlap
+                let storage: &'data mut ErasedStorageHolder = unsafe { transmute(&mut **storage) };
+                let component = storage.get_any_mut(entity)?;
+                touch.touch(entity);
+                Some(DynamicComponent::Mut(component))
+            }
+            Self::BytesMut(storage) => {
+                // SAFETY: view returns each entity at most once,
+                // so returned borrows of the components do not overlap
+                let storage: &'data mut DynamicStorage = unsafe { transmute(&mut **storage) };
+                storage.get_mut(entity).map(DynamicComponent::BytesMut)
+            }
+        }
+    }
+}
@@ -1,26 +1,38 @@
 use crate::component::storage::{DynIter, Storage};
 use crate::component::Component;
-use crate::entity::Entity;
+use crate::entity::{Entity, Registry};
 
 /// Iterator which returns *entity* of the world
 /// with **shared** *borrow* of component attached to it.
 ///
 /// Only entities that has generic component type will be returned.
-#[repr(transparent)]
 pub struct ViewOne<'data, C>
 where
     C: Component,
 {
     iter: Option<Box<DynIter<'data, C>>>,
+    entities: Option<&'data Registry>,
+    disabled: usize,
 }
 
 impl<'data, C> ViewOne<'data, C>
 where
     C: Component,
 {
-    pub(crate) fn new(storage: Option<&'data C::Storage>) -> Self {
-        let iter = storage.map(Storage::iter);
-        Self { iter }
+    pub(crate) fn new(storage: Option<&'data C::Storage>, entities: &'data Registry) -> Self {
+        let (iter, disabled) = match storage {
+            None => (None, 0),
+            Some(storage) => {
+                let disabled = entities.count_disabled(storage);
+                (Some(storage.iter()), disabled)
+            }
+        };
+        let entities = (disabled > 0).then_some(entities);
+        Self {
+            iter,
+            entities,
+            disabled,
+        }
     }
 }
 
@@ -31,7 +43,13 @@ where
     type Item = (Entity, &'data C);
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter.as_mut()?.next()
+        loop {
+            let (entity, component) = self.iter.as_mut()?.next()?;
+            match self.entities {
+                Some(entities) if entities.is_disabled(entity) => self.disabled -= 1,
+                _ => return Some((entity, component)),
+            }
+        }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -45,6 +63,7 @@ where
     C: Component,
 {
     fn len(&self) -> usize {
-        self.iter.as_ref().map(ExactSizeIterator::len).unwrap_or(0)
+        let len = self.iter.as_ref().map(ExactSizeIterator::len).unwrap_or(0);
+        len - self.disabled
     }
 }
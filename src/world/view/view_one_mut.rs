@@ -1,26 +1,44 @@
 use crate::component::storage::{DynIterMut, Storage};
-use crate::component::Component;
-use crate::entity::Entity;
+use crate::component::{Component, Touch};
+use crate::entity::{Entity, Registry};
 
 /// Iterator which returns *entity* of the world
 /// with **unique** *borrow* of component attached to it.
 ///
 /// Only entities that has generic component type will be returned.
-#[repr(transparent)]
 pub struct ViewOneMut<'data, C>
 where
     C: Component,
 {
     iter: Option<Box<DynIterMut<'data, C>>>,
+    entities: Option<&'data Registry>,
+    disabled: usize,
+    touch: Touch<'data>,
 }
 
 impl<'data, C> ViewOneMut<'data, C>
 where
     C: Component,
 {
-    pub(crate) fn new(storage: Option<&'data mut C::Storage>) -> Self {
-        let iter = storage.map(Storage::iter_mut);
-        Self { iter }
+    pub(crate) fn new(
+        storage: Option<&'data mut C::Storage>,
+        entities: &'data Registry,
+        touch: Touch<'data>,
+    ) -> Self {
+        let (iter, disabled) = match storage {
+            None => (None, 0),
+            Some(storage) => {
+                let disabled = entities.count_disabled(storage);
+                (Some(storage.iter_mut()), disabled)
+            }
+        };
+        let entities = (disabled > 0).then_some(entities);
+        Self {
+            iter,
+            entities,
+            disabled,
+            touch,
+        }
     }
 }
 
@@ -31,7 +49,16 @@ where
     type Item = (Entity, &'data mut C);
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter.as_mut()?.next()
+        loop {
+            let (entity, component) = self.iter.as_mut()?.next()?;
+            match self.entities {
+                Some(entities) if entities.is_disabled(entity) => self.disabled -= 1,
+                _ => {
+                    self.touch.touch(entity);
+                    return Some((entity, component));
+                }
+            }
+        }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -45,6 +72,7 @@ where
     C: Component,
 {
     fn len(&self) -> usize {
-        self.iter.as_ref().map(ExactSizeIterator::len).unwrap_or(0)
+        let len = self.iter.as_ref().map(ExactSizeIterator::len).unwrap_or(0);
+        len - self.disabled
     }
 }
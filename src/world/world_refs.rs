@@ -1,11 +1,15 @@
-use crate::component::{Component, RegistryRefs as StorageRefs};
+use crate::component::{Component, RegistryRefs as StorageRefs, RemovedComponents, Touch};
 #[cfg(feature = "resource")]
-use crate::resource::{RegistryRefs as ResourceRefs, Resource};
+use crate::marker::{Res, ResMut};
+#[cfg(feature = "resource")]
+use crate::resource::{NonSendRefs, RegistryRefs as ResourceRefs, Resource, Tick};
 
 pub struct WorldRefs<'world> {
     pub(super) storages: StorageRefs<'world>,
     #[cfg(feature = "resource")]
     pub(super) resources: ResourceRefs<'world>,
+    #[cfg(feature = "resource")]
+    pub(super) non_send: Option<NonSendRefs<'world>>,
 }
 
 impl<'world> WorldRefs<'world> {
@@ -23,13 +27,29 @@ impl<'world> WorldRefs<'world> {
         self.storages.move_ref::<C>()
     }
 
-    pub fn move_storage_mut<C>(&mut self) -> Option<&'world mut C::Storage>
+    pub fn move_storage_mut<C>(&mut self) -> Option<(&'world mut C::Storage, Touch<'world>)>
     where
         C: Component,
     {
         self.storages.move_mut::<C>()
     }
 
+    pub fn set_last_removal(&mut self, last_removal: u64) -> u64 {
+        self.storages.set_last_removal(last_removal)
+    }
+
+    pub fn removed<C>(&self) -> Option<RemovedComponents<'world, C>>
+    where
+        C: Component,
+    {
+        self.storages.removed::<C>()
+    }
+
+    #[cfg(feature = "resource")]
+    pub fn set_last_run(&mut self, last_run: Tick) -> Tick {
+        self.resources.set_last_run(last_run)
+    }
+
     #[cfg(feature = "resource")]
     pub fn get_resource_ref<R>(&self) -> Option<&R>
     where
@@ -39,7 +59,7 @@ impl<'world> WorldRefs<'world> {
     }
 
     #[cfg(feature = "resource")]
-    pub fn move_resource_ref<R>(&mut self) -> Option<&'world R>
+    pub fn move_resource_ref<R>(&mut self) -> Option<Res<'world, R>>
     where
         R: Resource,
     {
@@ -47,10 +67,26 @@ impl<'world> WorldRefs<'world> {
     }
 
     #[cfg(feature = "resource")]
-    pub fn move_resource_mut<R>(&mut self) -> Option<&'world mut R>
+    pub fn move_resource_mut<R>(&mut self) -> Option<ResMut<'world, R>>
     where
         R: Resource,
     {
         self.resources.move_mut::<R>()
     }
+
+    #[cfg(feature = "resource")]
+    pub fn move_non_send_ref<R>(&mut self) -> Option<&'world R>
+    where
+        R: 'static,
+    {
+        self.non_send.as_mut()?.move_ref::<R>()
+    }
+
+    #[cfg(feature = "resource")]
+    pub fn move_non_send_mut<R>(&mut self) -> Option<&'world mut R>
+    where
+        R: 'static,
+    {
+        self.non_send.as_mut()?.move_mut::<R>()
+    }
 }
@@ -0,0 +1,95 @@
+use components::{Position, Velocity};
+use toucan_ecs::app::Dependencies;
+use toucan_ecs::prelude::*;
+
+mod components;
+
+struct PositionPlugin;
+
+impl Plugin for PositionPlugin {
+    fn build(&self, app: &mut AppBuilder) {
+        app.register::<Position>()
+            .foreach_system(|position: &mut Position| position.x += 1.0);
+    }
+}
+
+struct VelocityPlugin;
+
+impl Plugin for VelocityPlugin {
+    fn build(&self, app: &mut AppBuilder) {
+        app.register::<Velocity>().foreach_system(
+            |position: &mut Position, velocity: &Velocity| {
+                position.x += velocity.dx;
+                position.y += velocity.dy;
+            },
+        );
+    }
+
+    fn dependencies(&self) -> Dependencies {
+        Dependencies::new().with::<PositionPlugin>()
+    }
+}
+
+#[test]
+fn plugins() {
+    let mut builder = App::builder();
+    builder
+        .add_plugin(PositionPlugin)
+        .add_plugin(VelocityPlugin);
+    assert!(builder.has_plugin::<PositionPlugin>());
+    assert!(builder.has_plugin::<VelocityPlugin>());
+    assert_eq!(builder.schedule_mut().len(), 2);
+
+    let mut app = builder.build();
+    let position = Position { x: 0.0, y: 0.0 };
+    let velocity = Velocity { dx: 1.0, dy: 2.0 };
+    let entity = app.world_mut().create_with((position, velocity));
+    app.run();
+
+    let position = app.world().get::<Position>(entity).unwrap();
+    assert_eq!(position.x, 2.0);
+    assert_eq!(position.y, 2.0);
+
+    let (world, schedule) = app.into_parts();
+    assert!(world.contains(entity));
+    assert_eq!(schedule.len(), 2);
+}
+
+#[test]
+#[cfg(feature = "resource")]
+fn plugin_resources() {
+    #[derive(Resource)]
+    struct Counter(u32);
+
+    struct CounterPlugin;
+
+    impl Plugin for CounterPlugin {
+        fn build(&self, app: &mut AppBuilder) {
+            app.create_resources(Counter(0))
+                .system(|mut counter: ResMut<Counter>| counter.0 += 1);
+        }
+    }
+
+    let mut builder = App::builder();
+    builder.add_plugin(CounterPlugin);
+    let mut app = builder.build();
+    app.run();
+    app.run();
+    assert_eq!(app.world().get_resource::<Counter>().unwrap().0, 2);
+}
+
+#[test]
+#[should_panic(expected = "requires plugin")]
+fn missing_dependency() {
+    let mut builder = App::builder();
+    builder.add_plugin(VelocityPlugin);
+}
+
+#[test]
+#[should_panic(expected = "was already added")]
+fn duplicate_plugin() {
+    let mut builder = App::builder();
+    builder
+        .add_plugin(PositionPlugin)
+        .add_plugin(PositionPlugin);
+}
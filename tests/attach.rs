@@ -40,3 +40,16 @@ fn attach() {
     assert!(world.attached::<Velocity>(entity));
     assert!(world.attached::<Mass>(entity));
 }
+
+#[test]
+fn attach_replaces() {
+    let mut world = World::new();
+    let entity = world.create_with(Mass(1.0));
+
+    world.attach(entity, Mass(2.0));
+    assert_eq!(world.get::<Mass>(entity), Some(&Mass(2.0)));
+    assert_eq!(world.view_one::<Mass>().count(), 1);
+
+    world.remove::<Mass>(entity);
+    assert_eq!(world.view_one::<Mass>().count(), 0);
+}
@@ -0,0 +1,217 @@
+#![cfg(feature = "resource")]
+
+use std::sync::{Arc, RwLock};
+
+use components::Position;
+use resources::SimpleResource;
+use toucan_ecs::prelude::*;
+use toucan_ecs::system::{resource_added, resource_changed, resource_exists, RunCondition};
+
+mod components;
+mod resources;
+mod utils;
+
+type Log = Arc<RwLock<Vec<(bool, bool)>>>;
+
+fn take(log: &Log) -> Vec<(bool, bool)> {
+    std::mem::take(&mut *log.write().unwrap())
+}
+
+#[test]
+fn added_changed() {
+    let mut world = World::new();
+    world.create_resources(SimpleResource::default());
+
+    let log = Log::default();
+    let mut schedule = Schedule::builder()
+        .system({
+            let log = Arc::clone(&log);
+            move |resource: Res<SimpleResource>| {
+                let flags = (resource.is_added(), resource.is_changed());
+                log.write().unwrap().push(flags);
+            }
+        })
+        .build();
+
+    schedule.run(&mut world);
+    assert_eq!(take(&log), [(true, true)]);
+    schedule.run(&mut world);
+    assert_eq!(take(&log), [(false, false)]);
+
+    world
+        .get_resource_mut::<SimpleResource>()
+        .unwrap()
+        .set_inner(1);
+    schedule.run(&mut world);
+    assert_eq!(take(&log), [(false, true)]);
+
+    world.create_resources(SimpleResource::default());
+    schedule.run(&mut world);
+    assert_eq!(take(&log), [(true, true)]);
+}
+
+#[test]
+fn deref_mut_marks_changed() {
+    let mut world = World::new();
+    world.create_resources(SimpleResource::default());
+
+    let log = Log::default();
+    let mut schedule = Schedule::builder()
+        .system(|mut resource: ResMut<SimpleResource>| {
+            if resource.inner() < 2 {
+                let inner = resource.inner();
+                resource.set_inner(inner + 1);
+            }
+        })
+        .system({
+            let log = Arc::clone(&log);
+            move |resource: Res<SimpleResource>| {
+                let flags = (resource.is_added(), resource.is_changed());
+                log.write().unwrap().push(flags);
+            }
+        })
+        .build();
+
+    schedule.run(&mut world);
+    schedule.run(&mut world);
+    schedule.run(&mut world);
+    assert_eq!(take(&log), [(true, true), (false, true), (false, false)]);
+}
+
+#[test]
+fn own_changes_are_not_visible() {
+    let mut world = World::new();
+    world.create_resources(SimpleResource::default());
+
+    let log = Log::default();
+    let mut schedule = Schedule::builder()
+        .system({
+            let log = Arc::clone(&log);
+            move |mut resource: ResMut<SimpleResource>| {
+                let flags = (resource.is_added(), resource.is_changed());
+                log.write().unwrap().push(flags);
+                resource.set_changed();
+            }
+        })
+        .build();
+
+    schedule.run(&mut world);
+    schedule.run(&mut world);
+    assert_eq!(take(&log), [(true, true), (false, false)]);
+}
+
+#[test]
+fn foreach_system() {
+    let mut world = utils::prepare_for_view();
+    world.create_resources(SimpleResource::default());
+
+    let mut schedule = Schedule::builder()
+        .foreach_system(|_: &Position, mut resource: ResMut<SimpleResource>| {
+            let inner = resource.inner();
+            resource.set_inner(inner + 1);
+        })
+        .system(|resource: Res<SimpleResource>| assert!(resource.is_changed()))
+        .build();
+    schedule.run(&mut world);
+    assert_eq!(world.get_resource::<SimpleResource>().unwrap().inner(), 10);
+}
+
+#[test]
+fn conditions() {
+    let mut world = World::new();
+    let log = Arc::new(RwLock::new(Vec::new()));
+
+    let logging = |name: &'static str| {
+        let log = Arc::clone(&log);
+        move || log.write().unwrap().push(name)
+    };
+    let mut schedule = Schedule::builder()
+        .system(logging("exists"))
+        .run_if(resource_exists::<SimpleResource>())
+        .system(logging("added"))
+        .run_if(resource_added::<SimpleResource>())
+        .system(logging("changed"))
+        .run_if(resource_changed::<SimpleResource>())
+        .system(logging("empty"))
+        .run_if(RunCondition::new(|world: &World| world.is_empty()))
+        .build();
+
+    schedule.run(&mut world);
+    assert_eq!(std::mem::take(&mut *log.write().unwrap()), ["empty"]);
+
+    world.create_resources(SimpleResource::default());
+    schedule.run(&mut world);
+    assert_eq!(
+        std::mem::take(&mut *log.write().unwrap()),
+        ["exists", "added", "changed"],
+    );
+
+    schedule.run(&mut world);
+    assert_eq!(std::mem::take(&mut *log.write().unwrap()), ["exists"]);
+
+    world
+        .get_resource_mut::<SimpleResource>()
+        .unwrap()
+        .set_inner(1);
+    schedule.run(&mut world);
+    assert_eq!(
+        std::mem::take(&mut *log.write().unwrap()),
+        ["exists", "changed"],
+    );
+
+    let id = schedule.system_ids().next().unwrap();
+    assert!(schedule.set_condition(id, None));
+    world.destroy_resources::<SimpleResource>();
+    schedule.run(&mut world);
+    assert_eq!(
+        std::mem::take(&mut *log.write().unwrap()),
+        ["exists", "empty"],
+    );
+}
+
+#[test]
+fn condition_skips_until_changed() {
+    let mut world = World::new();
+    world.create_resources(SimpleResource::default());
+
+    let mut schedule = Schedule::builder()
+        .with_stats()
+        .system(|| println!("settings changed"))
+        .run_if(resource_changed::<SimpleResource>())
+        .system(|mut resource: ResMut<SimpleResource>| {
+            if resource.inner() == 0 {
+                resource.set_inner(1);
+            }
+        })
+        .build();
+
+    schedule.run(&mut world);
+    schedule.run(&mut world);
+    schedule.run(&mut world);
+
+    let stats = schedule.stats().unwrap();
+    assert_eq!(stats.get(0).unwrap().invocations(), 2);
+}
+
+#[test]
+#[should_panic]
+fn condition_without_system() {
+    Schedule::builder().run_if(resource_exists::<SimpleResource>());
+}
+
+#[test]
+fn from_reference() {
+    let mut resource = SimpleResource::default();
+
+    let res = Res::from(&resource);
+    assert!(res.is_added());
+    assert!(res.is_changed());
+    assert_eq!(res.inner(), 0);
+
+    let mut res_mut = ResMut::from(&mut resource);
+    assert!(res_mut.is_added());
+    assert!(res_mut.is_changed());
+    res_mut.set_inner(10);
+    assert!(res_mut.is_changed());
+    assert_eq!(resource.inner(), 10);
+}
@@ -0,0 +1,88 @@
+use std::alloc::Layout;
+
+use components::{Mass, Position, Velocity};
+use toucan_ecs::component::ComponentDescriptor;
+use toucan_ecs::prelude::*;
+
+mod components;
+mod utils;
+
+#[test]
+fn clone_entity() {
+    let mut world = utils::prepare_for_complex_view();
+    let entities = world
+        .view::<(Entity, &Position)>()
+        .map(|(entity, _)| entity)
+        .collect::<Vec<_>>();
+
+    for entity in entities {
+        let clone = world.clone_entity(entity);
+        assert_ne!(entity, clone);
+        assert_eq!(world.get::<Position>(clone), world.get::<Position>(entity));
+        assert_eq!(world.get::<Velocity>(clone), world.get::<Velocity>(entity));
+        assert_eq!(world.get::<Mass>(clone), world.get::<Mass>(entity));
+    }
+    assert_eq!(world.view_one::<Position>().count(), 20);
+    assert_eq!(world.view_one::<Velocity>().count(), 10);
+    assert_eq!(world.view_one::<Mass>().count(), 10);
+}
+
+#[test]
+fn clone_is_independent() {
+    let mut world = World::new();
+    let entity = world.create_with(Position { x: 1.0, y: 2.0 });
+    let clone = world.clone_entity(entity);
+
+    world.get_mut::<Position>(clone).unwrap().x = 10.0;
+    assert_eq!(world.get::<Position>(entity).unwrap().x, 1.0);
+
+    world.destroy(entity);
+    assert_eq!(world.get::<Position>(clone).unwrap().x, 10.0);
+}
+
+#[test]
+fn clone_empty_and_dynamic() {
+    let mut world = World::new();
+    let empty = world.create();
+    let clone = world.clone_entity(empty);
+    assert!(world.contains(clone));
+    assert!(world.is_entity_empty(clone));
+
+    let descriptor = ComponentDescriptor::new("Health", Layout::new::<u32>());
+    let health = world.register_dynamic(descriptor);
+    let entity = world.create();
+    world.attach_dynamic(entity, health, &100u32.to_ne_bytes());
+
+    let clone = world.clone_entity(entity);
+    assert_eq!(
+        world.get_dynamic(clone, health),
+        Some(&100u32.to_ne_bytes()[..]),
+    );
+}
+
+#[test]
+#[should_panic]
+fn clone_destroyed() {
+    let mut world = World::new();
+    let entity = world.create();
+    world.destroy(entity);
+    world.clone_entity(entity);
+}
+
+#[test]
+fn copy_components() {
+    let mut world = World::new();
+    let from = world.create_with((Position { x: 1.0, y: 1.0 }, Mass(5.0)));
+    let to = world.create_with((Position { x: 0.0, y: 0.0 }, Mass(1.0)));
+
+    world.copy_components::<(Position, Velocity)>(from, to);
+    assert_eq!(
+        world.get::<Position>(to),
+        Some(&Position { x: 1.0, y: 1.0 })
+    );
+    assert_eq!(world.get::<Mass>(to), Some(&Mass(1.0)));
+    assert!(!world.attached::<Velocity>(to));
+
+    world.copy_components::<Mass>(from, to);
+    assert_eq!(world.get::<Mass>(to), Some(&Mass(5.0)));
+}
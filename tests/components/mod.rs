@@ -1,3 +1,5 @@
+#![allow(dead_code)]
+
 use toucan_ecs::prelude::*;
 
 #[derive(Copy, Clone, Component, Debug, PartialEq)]
@@ -0,0 +1,154 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use components::{Mass, Position};
+use toucan_ecs::component::ComponentId;
+use toucan_ecs::prelude::*;
+use toucan_ecs::world::query::DynamicQuery;
+
+mod components;
+mod utils;
+
+fn disable_some(world: &mut World) -> Vec<Entity> {
+    let entities = world.view::<Entity>().collect::<Vec<_>>();
+    let disabled = entities.into_iter().step_by(3).collect::<Vec<_>>();
+    for &entity in &disabled {
+        world.disable(entity);
+    }
+    disabled
+}
+
+#[test]
+fn disable_enable() {
+    let mut world = World::new();
+    let entity = world.create_with(Mass(1.0));
+
+    world.disable(entity);
+    world.disable(entity);
+    assert!(world.is_disabled(entity));
+    assert!(world.contains(entity));
+    assert_eq!(world.get::<Mass>(entity), Some(&Mass(1.0)));
+    assert_eq!(world.view_one::<Mass>().count(), 0);
+
+    world.enable(entity);
+    assert!(!world.is_disabled(entity));
+    assert_eq!(world.view_one::<Mass>().count(), 1);
+
+    world.disable(entity);
+    world.destroy(entity);
+    assert!(!world.is_disabled(entity));
+    let reused = world.create_with(Mass(2.0));
+    assert!(!world.is_disabled(reused));
+    assert_eq!(world.view_one::<Mass>().count(), 1);
+}
+
+#[test]
+fn view() {
+    let mut world = utils::prepare_for_view();
+    let disabled = disable_some(&mut world);
+
+    let entities = world.view::<(Entity, &Position)>().collect::<Vec<_>>();
+    assert_eq!(entities.len(), 10 - disabled.len());
+    assert!(entities
+        .iter()
+        .all(|(entity, _)| !disabled.contains(entity)));
+
+    let all = world.view::<(Entity, &Position, IncludeDisabled)>().count();
+    assert_eq!(all, 10);
+    let only_disabled = world
+        .view::<(Entity, IncludeDisabled)>()
+        .filter(|&(entity, _)| world.is_disabled(entity))
+        .count();
+    assert_eq!(only_disabled, disabled.len());
+
+    assert_eq!(
+        world.view_mut::<(&mut Position, &Mass)>().count(),
+        10 - disabled.len(),
+    );
+    assert_eq!(
+        world
+            .view_mut::<(&mut Position, Option<&Mass>, IncludeDisabled)>()
+            .count(),
+        10,
+    );
+}
+
+#[test]
+fn view_one() {
+    let mut world = utils::prepare_for_view();
+    let disabled = disable_some(&mut world);
+
+    let view = world.view_one::<Position>();
+    assert_eq!(view.len(), 10 - disabled.len());
+    let entities = view.map(|(entity, _)| entity).collect::<Vec<_>>();
+    assert_eq!(entities.len(), 10 - disabled.len());
+    assert!(entities.iter().all(|entity| !disabled.contains(entity)));
+
+    let mut view = world.view_one_mut::<Position>();
+    assert_eq!(view.len(), 10 - disabled.len());
+    view.next();
+    assert_eq!(view.len(), 9 - disabled.len());
+    assert_eq!(view.count(), 9 - disabled.len());
+}
+
+#[test]
+fn foreach_system() {
+    let mut world = utils::prepare_for_view();
+    let disabled = disable_some(&mut world);
+
+    let enabled = Arc::new(AtomicUsize::new(0));
+    let all = Arc::new(AtomicUsize::new(0));
+    let mut schedule = Schedule::builder()
+        .foreach_system({
+            let enabled = Arc::clone(&enabled);
+            move |_: &Position| {
+                enabled.fetch_add(1, Ordering::Relaxed);
+            }
+        })
+        .foreach_system({
+            let all = Arc::clone(&all);
+            move |_: &Position, _: IncludeDisabled| {
+                all.fetch_add(1, Ordering::Relaxed);
+            }
+        })
+        .build();
+    schedule.run(&mut world);
+
+    assert_eq!(enabled.load(Ordering::Relaxed), 10 - disabled.len());
+    assert_eq!(all.load(Ordering::Relaxed), 10);
+}
+
+#[test]
+fn dynamic_view() {
+    let mut world = utils::prepare_for_view();
+    let disabled = disable_some(&mut world);
+
+    let query = DynamicQuery::new().with(ComponentId::of::<Position>());
+    assert_eq!(world.dynamic_view(&query).count(), 10 - disabled.len());
+    let query = query.include_disabled();
+    assert_eq!(world.dynamic_view(&query).count(), 10);
+}
+
+#[test]
+fn move_entity() {
+    let mut world = World::new();
+    let entity = world.create_with(Mass(1.0));
+    world.disable(entity);
+
+    let mut other = World::new();
+    let moved = world.move_entity_to(entity, &mut other);
+    assert!(other.is_disabled(moved));
+    assert_eq!(other.view_one::<Mass>().count(), 0);
+}
+
+#[test]
+fn snapshot() {
+    let mut world = World::new();
+    let entity = world.create_with(Mass(1.0));
+    let snapshot = world.snapshot();
+
+    world.disable(entity);
+    world.restore(&snapshot);
+    assert!(!world.is_disabled(entity));
+    assert_eq!(world.view_one::<Mass>().count(), 1);
+}
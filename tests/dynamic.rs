@@ -0,0 +1,120 @@
+use std::alloc::Layout;
+
+use components::Position;
+use toucan_ecs::component::{ComponentDescriptor, ComponentId};
+use toucan_ecs::prelude::*;
+
+mod components;
+
+fn register_health(world: &mut World) -> ComponentId {
+    let descriptor = ComponentDescriptor::new("Health", Layout::new::<u32>());
+    world.register_dynamic(descriptor)
+}
+
+fn health(world: &World, entity: Entity, id: ComponentId) -> Option<u32> {
+    let data = world.get_dynamic(entity, id)?;
+    Some(u32::from_ne_bytes(data.try_into().unwrap()))
+}
+
+#[test]
+fn register() {
+    let mut world = World::new();
+    let health = register_health(&mut world);
+    let mana = world.register_dynamic(ComponentDescriptor::new("Mana", Layout::new::<u16>()));
+
+    assert!(health.is_dynamic());
+    assert_ne!(health, mana);
+    assert_eq!(health.type_id(), None);
+    assert_ne!(health, ComponentId::of::<Position>());
+
+    let descriptor = world.dynamic_descriptor(mana).unwrap();
+    assert_eq!(descriptor.name(), "Mana");
+    assert_eq!(descriptor.layout(), Layout::new::<u16>());
+    assert!(world
+        .dynamic_descriptor(ComponentId::of::<Position>())
+        .is_none());
+}
+
+#[test]
+fn attach_get_remove() {
+    let mut world = World::new();
+    let id = register_health(&mut world);
+
+    let entities = (0..5u32)
+        .map(|i| {
+            let entity = world.create();
+            world.attach_dynamic(entity, id, &(i * 10).to_ne_bytes());
+            entity
+        })
+        .collect::<Vec<_>>();
+    for (i, &entity) in entities.iter().enumerate() {
+        assert!(world.attached_dynamic(entity, id));
+        assert_eq!(health(&world, entity, id), Some(i as u32 * 10));
+    }
+
+    world.remove_dynamic(entities[1], id);
+    assert!(!world.attached_dynamic(entities[1], id));
+    assert_eq!(health(&world, entities[1], id), None);
+    for (i, &entity) in entities.iter().enumerate().filter(|&(i, _)| i != 1) {
+        assert_eq!(health(&world, entity, id), Some(i as u32 * 10));
+    }
+
+    let data = world.get_dynamic_mut(entities[4], id).unwrap();
+    data.copy_from_slice(&7u32.to_ne_bytes());
+    assert_eq!(health(&world, entities[4], id), Some(7));
+
+    world.attach_dynamic(entities[0], id, &1u32.to_ne_bytes());
+    assert_eq!(health(&world, entities[0], id), Some(1));
+}
+
+#[test]
+fn destroy_entity() {
+    let mut world = World::new();
+    let id = register_health(&mut world);
+
+    let entity = world.create();
+    world.attach_dynamic(entity, id, &100u32.to_ne_bytes());
+    assert!(!world.is_entity_empty(entity));
+
+    world.destroy(entity);
+    assert!(!world.attached_dynamic(entity, id));
+
+    let info = world.info();
+    let health = info.component(id).unwrap();
+    assert_eq!(health.name(), "Health");
+    assert_eq!(health.size(), 4);
+    assert_eq!(health.entities(), 0);
+    assert_eq!(health.type_id(), None);
+}
+
+#[test]
+fn zero_sized() {
+    let mut world = World::new();
+    let marker = world.register_dynamic(ComponentDescriptor::new("Marker", Layout::new::<()>()));
+
+    let first = world.create();
+    let second = world.create();
+    world.attach_dynamic(first, marker, &[]);
+    world.attach_dynamic(second, marker, &[]);
+    world.remove_dynamic(first, marker);
+
+    assert!(!world.attached_dynamic(first, marker));
+    assert_eq!(world.get_dynamic(second, marker), Some(&[][..]));
+}
+
+#[test]
+#[should_panic]
+fn attach_wrong_size() {
+    let mut world = World::new();
+    let id = register_health(&mut world);
+    let entity = world.create();
+    world.attach_dynamic(entity, id, &[0, 1]);
+}
+
+#[test]
+#[should_panic]
+fn attach_not_registered() {
+    let mut world = World::new();
+    let entity = world.create();
+    world.attach_dynamic(entity, ComponentId::of::<Position>(), &[]);
+}
@@ -0,0 +1,148 @@
+use std::alloc::Layout;
+
+use components::{Mass, Position, Velocity};
+use toucan_ecs::component::{ComponentDescriptor, ComponentId};
+use toucan_ecs::prelude::*;
+use toucan_ecs::world::query::DynamicQuery;
+
+mod components;
+mod utils;
+
+fn register_health(world: &mut World) -> ComponentId {
+    let descriptor = ComponentDescriptor::new("Health", Layout::new::<u32>());
+    world.register_dynamic(descriptor)
+}
+
+#[test]
+fn static_components() {
+    let world = utils::prepare_for_complex_view();
+
+    let query = DynamicQuery::new()
+        .with(ComponentId::of::<Position>())
+        .optional(ComponentId::of::<Mass>())
+        .without(ComponentId::of::<Velocity>());
+    let mut count = 0;
+    for (entity, components) in world.dynamic_view(&query) {
+        assert_eq!(components.len(), 2);
+        let position = components[0].as_ref().unwrap();
+        assert_eq!(position.downcast_ref::<Position>(), world.get(entity));
+        assert!(position.downcast_ref::<Mass>().is_none());
+        let mass = components[1].as_ref().unwrap();
+        assert_eq!(mass.downcast_ref::<Mass>(), world.get(entity));
+        count += 1;
+    }
+    assert_eq!(count, 5);
+}
+
+#[test]
+fn optional_missing() {
+    let world = utils::prepare_for_complex_view();
+
+    let query = DynamicQuery::new()
+        .with(ComponentId::of::<Velocity>())
+        .optional(ComponentId::of::<Mass>());
+    let items = world.dynamic_view(&query).collect::<Vec<_>>();
+    assert_eq!(items.len(), 5);
+    assert!(items.iter().all(|(_, components)| components[1].is_none()));
+}
+
+#[test]
+fn empty_query() {
+    let mut world = utils::prepare_for_view();
+    world.create();
+
+    let query = DynamicQuery::new();
+    assert_eq!(world.dynamic_view(&query).count(), 11);
+
+    let query = DynamicQuery::new().without(ComponentId::of::<Position>());
+    assert_eq!(world.dynamic_view(&query).count(), 1);
+}
+
+#[test]
+fn not_registered() {
+    let world = utils::prepare_for_view();
+    let mut other = World::new();
+    let health = register_health(&mut other);
+
+    let query = DynamicQuery::new()
+        .with(ComponentId::of::<Position>())
+        .with(health);
+    assert_eq!(world.dynamic_view(&query).count(), 0);
+}
+
+#[test]
+fn dynamic_components() {
+    let mut world = utils::prepare_for_complex_view();
+    let health = register_health(&mut world);
+
+    let entities = world
+        .view::<(Entity, &Velocity)>()
+        .map(|(entity, _)| entity)
+        .collect::<Vec<_>>();
+    for (i, &entity) in entities.iter().enumerate() {
+        world.attach_dynamic(entity, health, &(i as u32).to_ne_bytes());
+    }
+
+    let query = DynamicQuery::new()
+        .with_mut(health)
+        .with_mut(ComponentId::of::<Position>());
+    for (_, mut components) in world.dynamic_view_mut(&query) {
+        let bytes = components[0].as_mut().unwrap().as_bytes_mut().unwrap();
+        let value = u32::from_ne_bytes((&*bytes).try_into().unwrap());
+        bytes.copy_from_slice(&(value + 100).to_ne_bytes());
+
+        let position = components[1].as_mut().unwrap();
+        assert!(position.as_bytes().is_none());
+        position.downcast_mut::<Position>().unwrap().x = 0.0;
+    }
+
+    for (i, &entity) in entities.iter().enumerate() {
+        let data = world.get_dynamic(entity, health).unwrap();
+        assert_eq!(data, (i as u32 + 100).to_ne_bytes());
+        assert_eq!(world.get::<Position>(entity).unwrap().x, 0.0);
+    }
+    let unchanged = world
+        .view_one::<Position>()
+        .filter(|(_, position)| position.x != 0.0)
+        .count();
+    assert_eq!(unchanged, 5);
+}
+
+#[test]
+fn collect_mutable() {
+    let mut world = utils::prepare_for_complex_view();
+
+    let query = DynamicQuery::new()
+        .with(ComponentId::of::<Velocity>())
+        .with_mut(ComponentId::of::<Position>());
+    let mut items = world.dynamic_view_mut(&query).collect::<Vec<_>>();
+    assert_eq!(items.len(), 5);
+    for (_, components) in &mut items {
+        let position = components[1].as_mut().unwrap();
+        position.downcast_mut::<Position>().unwrap().y = 0.0;
+    }
+
+    let zeroed = world
+        .view::<(&Position, Option<&Velocity>)>()
+        .filter(|(position, velocity)| (position.y == 0.0) == velocity.is_some())
+        .count();
+    assert_eq!(zeroed, 10);
+}
+
+#[test]
+#[should_panic]
+fn mutable_in_shared_view() {
+    let world = utils::prepare_for_view();
+    let query = DynamicQuery::new().with_mut(ComponentId::of::<Position>());
+    world.dynamic_view(&query);
+}
+
+#[test]
+#[should_panic]
+fn conflicting_borrows() {
+    let mut world = utils::prepare_for_view();
+    let query = DynamicQuery::new()
+        .with(ComponentId::of::<Position>())
+        .with_mut(ComponentId::of::<Position>());
+    world.dynamic_view_mut(&query);
+}
@@ -0,0 +1,150 @@
+use toucan_ecs::prelude::*;
+
+#[test]
+fn bits() {
+    let mut world = World::new();
+    let entities = (0..10).map(|_| world.create()).collect::<Vec<_>>();
+
+    for &entity in &entities {
+        let bits = entity.to_bits();
+        assert_eq!(bits as u32, entity.index());
+        assert_eq!((bits >> 32) as u32, entity.generation());
+        assert_eq!(Entity::from_bits(bits), entity);
+    }
+
+    let null = Entity::from_bits(0);
+    assert!(!world.contains(null));
+    assert!(!world.contains(Entity::from_bits(u64::MAX)));
+}
+
+#[test]
+fn try_from_bits() {
+    let mut world = World::new();
+    let entity = world.create();
+    assert_eq!(Entity::try_from_bits(entity.to_bits()), Some(entity));
+
+    let even = (u64::from(entity.generation() + 1) << 32) | u64::from(entity.index());
+    assert_eq!(Entity::try_from_bits(even), None);
+    assert_ne!(Entity::from_bits(even).to_bits(), even);
+
+    // bits are not validated against the world, so they can alias the live entity
+    let alias = Entity::try_from_bits(entity.to_bits()).unwrap();
+    assert!(world.contains(alias));
+}
+
+#[test]
+fn generation() {
+    let mut world = World::new();
+    let entity = world.create();
+    world.destroy(entity);
+    let reused = world.create();
+
+    assert_eq!(reused.index(), entity.index());
+    assert_ne!(reused.generation(), entity.generation());
+    assert!(!world.contains(entity));
+}
+
+#[test]
+fn resolve() {
+    let mut world = World::new();
+    let first = world.create();
+    let second = world.create();
+
+    assert_eq!(world.resolve(first.index()), Some(first));
+    assert_eq!(world.resolve(second.index()), Some(second));
+    assert_eq!(world.resolve(0), None);
+    assert_eq!(world.resolve(u32::MAX), None);
+
+    world.destroy(first);
+    assert_eq!(world.resolve(first.index()), None);
+
+    let reused = world.create();
+    assert_eq!(world.resolve(first.index()), Some(reused));
+
+    world.clear();
+    assert_eq!(world.resolve(second.index()), None);
+}
+
+#[test]
+fn resolve_after_restore() {
+    let mut world = World::new();
+    let entity = world.create();
+    let snapshot = world.snapshot();
+
+    world.destroy(entity);
+    let other = world.create();
+    assert_eq!(world.resolve(entity.index()), Some(other));
+
+    world.restore(&snapshot);
+    assert_eq!(world.resolve(entity.index()), Some(entity));
+}
+
+#[test]
+fn reserve() {
+    let mut world = World::new();
+    let destroyed = world.extend(3);
+    world.destroy(destroyed[1]);
+
+    let first = world.reserve_entity();
+    let rest = world.reserve_entities(3);
+    assert_eq!(first.index(), destroyed[1].index());
+    assert!(!world.contains(first));
+    assert!(rest.iter().all(|&entity| !world.contains(entity)));
+    assert_eq!(world.info().entities(), 2);
+
+    world.flush();
+    assert!(world.contains(first));
+    assert!(!world.contains(destroyed[1]));
+    assert!(rest.iter().all(|&entity| world.contains(entity)));
+    assert_eq!(world.resolve(first.index()), Some(first));
+    assert_eq!(world.info().entities(), 6);
+
+    let created = world.create();
+    assert!(![first].iter().chain(&rest).any(|&entity| entity == created));
+}
+
+#[test]
+fn reserve_flushed_implicitly() {
+    let mut world = World::new();
+    let reserved = world.reserve_entity();
+    let created = world.create();
+    assert_ne!(reserved, created);
+    assert!(world.contains(reserved));
+
+    let reserved = world.reserve_entity();
+    world.destroy(reserved);
+    assert!(!world.contains(reserved));
+}
+
+#[test]
+fn reserve_concurrently() {
+    let mut world = World::new();
+    let destroyed = world.extend(50);
+    for entity in destroyed.into_iter().step_by(2) {
+        world.destroy(entity);
+    }
+
+    let mut reserved = std::thread::scope(|scope| {
+        let world = &world;
+        let handles = (0..4)
+            .map(|_| {
+                scope.spawn(move || {
+                    let mut entities = world.reserve_entities(10);
+                    entities.extend((0..10).map(|_| world.reserve_entity()));
+                    entities
+                })
+            })
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect::<Vec<_>>()
+    });
+    world.flush();
+
+    assert_eq!(world.info().entities(), 25 + 80);
+    assert!(reserved.iter().all(|&entity| world.contains(entity)));
+    reserved.sort_unstable();
+    reserved.dedup();
+    assert_eq!(reserved.len(), 80);
+}
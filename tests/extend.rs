@@ -35,7 +35,10 @@ fn extend_with() {
     let mut world = World::new();
 
     let entities = world
-        .extend_with(std::iter::repeat((Mass(1.0), Position { x: 0.0, y: 0.0 })).take(10))
+        .extend_with(std::iter::repeat_n(
+            (Mass(1.0), Position { x: 0.0, y: 0.0 }),
+            10,
+        ))
         .to_vec();
     assert!(!world.is_empty());
     assert_eq!(entities.len(), 10);
@@ -47,7 +47,7 @@ fn get_mut_and_mutate() {
         dy: -10.0,
     });
 
-    let mut data = world.get_mut::<Velocity>(entity).unwrap();
+    let data = world.get_mut::<Velocity>(entity).unwrap();
     data.dx = 0.0;
     data.dy = 0.0;
     assert_eq!(*data, Velocity { dx: 0.0, dy: 0.0 });
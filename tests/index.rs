@@ -0,0 +1,187 @@
+use toucan_ecs::prelude::*;
+
+#[derive(Copy, Clone, Component, Debug, PartialEq)]
+struct GridCell {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Copy, Clone, Component, Debug, PartialEq)]
+struct PlayerId(u32);
+
+fn cell(x: i32, y: i32) -> GridCell {
+    GridCell { x, y }
+}
+
+fn world() -> World {
+    let mut world = World::new();
+    world.index_by::<GridCell, _>(|cell| (cell.x, cell.y));
+    world.index_by::<PlayerId, _>(|id| id.0);
+    world
+}
+
+fn lookup(world: &World, x: i32, y: i32) -> Vec<Entity> {
+    world.lookup::<GridCell>(&(x, y)).collect()
+}
+
+#[test]
+fn attach_remove() {
+    let mut world = world();
+    let first = world.create_with(cell(0, 0));
+    let second = world.create_with((cell(0, 0), PlayerId(1)));
+    let third = world.create_with(cell(1, 0));
+
+    assert_eq!(lookup(&world, 0, 0), [first, second]);
+    assert_eq!(lookup(&world, 1, 0), [third]);
+    assert_eq!(lookup(&world, 2, 0), []);
+    assert!(world.lookup::<PlayerId>(&1_u32).eq([second]));
+
+    world.attach(first, cell(1, 0));
+    assert_eq!(lookup(&world, 0, 0), [second]);
+    assert_eq!(lookup(&world, 1, 0), [third, first]);
+
+    world.remove::<GridCell>(third);
+    assert_eq!(lookup(&world, 1, 0), [first]);
+
+    world.destroy(second);
+    assert_eq!(lookup(&world, 0, 0), []);
+    assert_eq!(world.lookup::<PlayerId>(&1_u32).count(), 0);
+}
+
+#[test]
+fn existing_components() {
+    let mut world = World::new();
+    let entity = world.create_with(cell(5, 5));
+
+    world.index_by::<GridCell, _>(|cell| cell.x);
+    assert!(world.lookup::<GridCell>(&5).eq([entity]));
+
+    world.index_by::<GridCell, _>(|cell| (cell.x, cell.y));
+    assert_eq!(lookup(&world, 5, 5), [entity]);
+}
+
+#[test]
+fn get_mut_write_back() {
+    let mut world = world();
+    let entity = world.create_with(cell(0, 0));
+    assert_eq!(lookup(&world, 0, 0), [entity]);
+
+    world.get_mut::<GridCell>(entity).unwrap().x = 1;
+    assert_eq!(lookup(&world, 0, 0), []);
+    assert_eq!(lookup(&world, 1, 0), [entity]);
+
+    let mut entry = world.entry(entity).unwrap();
+    entry.get_mut::<GridCell>().unwrap().y = 1;
+    assert_eq!(lookup(&world, 1, 1), [entity]);
+}
+
+#[test]
+fn mutable_views() {
+    let mut world = world();
+    let entities = (0..10)
+        .map(|i| world.create_with(cell(i, 0)))
+        .collect::<Vec<_>>();
+    assert_eq!(lookup(&world, 3, 0), [entities[3]]);
+
+    for (_, cell) in world.view_one_mut::<GridCell>() {
+        cell.y = 1;
+    }
+    assert_eq!(lookup(&world, 3, 0), []);
+    assert_eq!(lookup(&world, 3, 1), [entities[3]]);
+
+    let mut schedule = Schedule::builder()
+        .foreach_system(|cell: &mut GridCell| cell.y = 2)
+        .build();
+    schedule.run(&mut world);
+    assert_eq!(lookup(&world, 3, 1), []);
+    assert_eq!(lookup(&world, 3, 2), [entities[3]]);
+}
+
+fn move_players<'data>(view: ViewMut<'data, (&'data mut GridCell, &'data PlayerId)>) {
+    for (cell, _) in view {
+        cell.y = 1;
+    }
+}
+
+#[test]
+fn view_mut_system() {
+    let mut world = world();
+    let entities = (0..10)
+        .map(|i| world.create_with(cell(i, 0)))
+        .collect::<Vec<_>>();
+    world.attach(entities[2], PlayerId(2));
+    world.attach(entities[7], PlayerId(7));
+    assert_eq!(lookup(&world, 2, 0), [entities[2]]);
+
+    let mut schedule = Schedule::builder().system(move_players).build();
+    schedule.run(&mut world);
+    assert_eq!(lookup(&world, 2, 0), []);
+    assert_eq!(lookup(&world, 2, 1), [entities[2]]);
+    assert_eq!(lookup(&world, 7, 1), [entities[7]]);
+    assert_eq!(lookup(&world, 3, 0), [entities[3]]);
+
+    let mut schedule = Schedule::builder()
+        .foreach_system(|cell: Option<&mut GridCell>, _: &PlayerId| {
+            if let Some(cell) = cell {
+                cell.y = 2;
+            }
+        })
+        .build();
+    schedule.run(&mut world);
+    assert_eq!(lookup(&world, 2, 1), []);
+    assert_eq!(lookup(&world, 7, 2), [entities[7]]);
+    assert_eq!(lookup(&world, 3, 0), [entities[3]]);
+}
+
+#[test]
+fn many_changes() {
+    let mut world = world();
+    let entities = (0..10)
+        .map(|i| world.create_with(cell(i, 0)))
+        .collect::<Vec<_>>();
+    assert_eq!(lookup(&world, 0, 0), [entities[0]]);
+
+    for _ in 0..5 {
+        for &entity in &entities {
+            world.get_mut::<GridCell>(entity).unwrap().y += 1;
+        }
+    }
+    for (i, &entity) in entities.iter().enumerate() {
+        assert_eq!(lookup(&world, i as i32, 5), [entity]);
+    }
+}
+
+#[test]
+fn restore_and_move() {
+    let mut world = world();
+    let entity = world.create_with(cell(0, 0));
+    let snapshot = world.snapshot();
+
+    world.get_mut::<GridCell>(entity).unwrap().x = 1;
+    assert_eq!(lookup(&world, 1, 0), [entity]);
+    world.restore(&snapshot);
+    assert_eq!(lookup(&world, 1, 0), []);
+    assert_eq!(lookup(&world, 0, 0), [entity]);
+
+    let mut other = self::world();
+    let moved = world.move_entity_to(entity, &mut other);
+    assert_eq!(lookup(&world, 0, 0), []);
+    assert_eq!(lookup(&other, 0, 0), [moved]);
+
+    other.clear();
+    assert_eq!(lookup(&other, 0, 0), []);
+}
+
+#[test]
+#[should_panic]
+fn not_indexed() {
+    let world = World::new();
+    world.lookup::<GridCell>(&(0, 0)).for_each(drop);
+}
+
+#[test]
+#[should_panic]
+fn wrong_key_type() {
+    let world = world();
+    world.lookup::<PlayerId>(&0_i64).for_each(drop);
+}
@@ -0,0 +1,80 @@
+use std::any::TypeId;
+use std::mem::size_of;
+
+use components::{Mass, Position, Velocity};
+use toucan_ecs::component::storage::DefaultStorage;
+use toucan_ecs::component::ComponentId;
+use toucan_ecs::prelude::*;
+
+mod components;
+#[cfg(feature = "resource")]
+mod resources;
+mod utils;
+
+#[test]
+fn empty() {
+    let world = World::new();
+    let info = world.info();
+    assert_eq!(info.entities(), 0);
+    assert!(info.components().is_empty());
+}
+
+#[test]
+fn components() {
+    let mut world = utils::prepare_for_complex_view();
+    world.create();
+
+    let info = world.info();
+    assert_eq!(info.entities(), 11);
+
+    let names = info
+        .components()
+        .iter()
+        .map(|component| component.name())
+        .collect::<Vec<_>>();
+    let mut sorted = names.clone();
+    sorted.sort_unstable();
+    assert_eq!(names, sorted);
+
+    let position = info.component(ComponentId::of::<Position>()).unwrap();
+    assert_eq!(position.id(), ComponentId::of::<Position>());
+    assert_eq!(position.type_id(), Some(TypeId::of::<Position>()));
+    assert!(position.name().ends_with("Position"));
+    assert_eq!(position.size(), size_of::<Position>());
+    assert_eq!(
+        position.storage_name(),
+        std::any::type_name::<DefaultStorage<Position>>(),
+    );
+    assert_eq!(position.entities(), 10);
+
+    let velocity = info.component(ComponentId::of::<Velocity>()).unwrap();
+    assert_eq!(velocity.entities(), 5);
+    let mass = info.component(ComponentId::of::<Mass>()).unwrap();
+    assert_eq!(mass.entities(), 5);
+}
+
+#[test]
+fn registered_component() {
+    let mut world = World::new();
+    world.register::<Position>();
+
+    let info = world.info();
+    let position = info.component(ComponentId::of::<Position>()).unwrap();
+    assert_eq!(position.entities(), 0);
+    assert!(info.component(ComponentId::of::<Velocity>()).is_none());
+}
+
+#[test]
+#[cfg(feature = "resource")]
+fn resources() {
+    use resources::SimpleResource;
+
+    let mut world = World::new();
+    world.create_resources(SimpleResource::default());
+
+    let info = world.info();
+    assert_eq!(info.resources().len(), 1);
+    let resource = info.resource(TypeId::of::<SimpleResource>()).unwrap();
+    assert_eq!(resource.type_id(), TypeId::of::<SimpleResource>());
+    assert!(resource.name().ends_with("SimpleResource"));
+}
@@ -0,0 +1,88 @@
+#![cfg(feature = "resource")]
+
+use components::Position;
+use resources::SimpleResource;
+use toucan_ecs::prelude::*;
+
+mod components;
+mod resources;
+mod utils;
+
+#[derive(Resource)]
+#[resource(init = "PositionCount::new")]
+struct PositionCount(usize);
+
+impl PositionCount {
+    fn new(world: &mut World) -> Self {
+        Self(world.view_one::<Position>().len())
+    }
+}
+
+struct Generic<T>(T);
+
+impl<T> Resource for Generic<T> where T: Send + Sync + 'static {}
+
+impl<T> FromWorld for Generic<T>
+where
+    T: Default,
+{
+    fn from_world(_: &mut World) -> Self {
+        Self(T::default())
+    }
+}
+
+#[test]
+fn init_resource() {
+    let mut world = utils::prepare_for_view();
+
+    world.init_resource::<SimpleResource>();
+    world.init_resource::<PositionCount>();
+    world.init_resource::<Generic<u32>>();
+    assert!(world.contains_resources::<(SimpleResource, PositionCount, Generic<u32>)>());
+    assert_eq!(world.get_resource::<PositionCount>().unwrap().0, 10);
+    assert_eq!(world.get_resource::<Generic<u32>>().unwrap().0, 0);
+
+    world.destroy_resources::<PositionCount>();
+    world.extend_with([Position { x: 0.0, y: 0.0 }]);
+    world.init_resource::<PositionCount>();
+    assert_eq!(world.get_resource::<PositionCount>().unwrap().0, 11);
+}
+
+#[test]
+fn init_resource_existing() {
+    let mut world = utils::prepare_for_view();
+    world.create_resources(PositionCount(0));
+
+    world.init_resource::<PositionCount>();
+    assert_eq!(world.get_resource::<PositionCount>().unwrap().0, 0);
+}
+
+#[test]
+fn schedule_init_resource() {
+    let mut world = utils::prepare_for_view();
+
+    let mut schedule = Schedule::builder()
+        .init_resource::<PositionCount>()
+        .init_resource::<PositionCount>()
+        .system(|count: Res<PositionCount>| assert_eq!(count.0, 10))
+        .build();
+    assert!(!world.contains_resources::<PositionCount>());
+    schedule.run(&mut world);
+    assert!(world.contains_resources::<PositionCount>());
+}
+
+#[test]
+fn app_init_resource() {
+    struct CountPlugin;
+
+    impl Plugin for CountPlugin {
+        fn build(&self, app: &mut AppBuilder) {
+            app.init_resource::<PositionCount>();
+        }
+    }
+
+    let mut builder = App::builder();
+    builder.add_plugin(CountPlugin);
+    let app = builder.build();
+    assert_eq!(app.world().get_resource::<PositionCount>().unwrap().0, 0);
+}
@@ -0,0 +1,180 @@
+use components::{Mass, Position};
+use toucan_ecs::prelude::*;
+
+mod components;
+#[cfg(feature = "resource")]
+mod resources;
+mod utils;
+
+#[derive(Copy, Clone, Component, Debug, PartialEq)]
+struct Target(Entity);
+
+impl MapEntities for Target {
+    fn map_entities(&mut self, map: &EntityMap) {
+        self.0 = map.map(self.0);
+    }
+}
+
+#[test]
+fn merge_entities() {
+    let level = utils::prepare_for_complex_view();
+    let old = level
+        .view::<(Entity, &Position)>()
+        .map(|(entity, position)| (entity, *position))
+        .collect::<Vec<_>>();
+
+    let mut world = utils::prepare_for_view();
+    let map = world.merge(level, MergePolicy::Keep).unwrap();
+
+    assert_eq!(map.len(), 10);
+    assert_eq!(world.view_one::<Position>().count(), 20);
+    assert_eq!(world.view_one::<Mass>().count(), 15);
+    for (entity, position) in old {
+        let entity = map.get(entity).unwrap();
+        assert_eq!(world.get::<Position>(entity), Some(&position));
+    }
+}
+
+#[test]
+fn map_entities() {
+    let mut level = World::new();
+    level.register_map_entities::<Target>();
+    let enemy = level.create();
+    let turret = level.create_with(Target(enemy));
+    let root = level.create();
+    let child = level.create_with(Parent(root));
+
+    let mut world = World::new();
+    let outside = world.create();
+    let stray = world.create_with(Target(outside));
+
+    let map = world.merge(level, MergePolicy::Keep).unwrap();
+    let turret = map.get(turret).unwrap();
+    let enemy = map.get(enemy).unwrap();
+    assert_eq!(world.get::<Target>(turret), Some(&Target(enemy)));
+    let child = map.get(child).unwrap();
+    assert_eq!(
+        world.get::<Parent>(child),
+        Some(&Parent(map.get(root).unwrap()))
+    );
+    assert_eq!(world.get::<Target>(stray), Some(&Target(outside)));
+}
+
+#[test]
+fn merge_mappers_are_not_registered() {
+    let mut level = World::new();
+    level.register_map_entities::<Target>();
+    level.create();
+
+    let mut world = World::new();
+    world.merge(level, MergePolicy::Keep).unwrap();
+
+    let mut staging = World::new();
+    let entity = staging.create();
+    staging.attach(entity, Target(entity));
+    let moved = staging.move_entity_to(entity, &mut world);
+    assert_eq!(world.get::<Target>(moved), Some(&Target(entity)));
+}
+
+#[test]
+fn merge_reserved() {
+    let level = World::new();
+    let reserved = level.reserve_entity();
+
+    let mut world = World::new();
+    let map = world.merge(level, MergePolicy::Keep).unwrap();
+    let reserved = map.get(reserved).unwrap();
+    assert!(world.contains(reserved));
+}
+
+#[test]
+fn move_entities_maps_parents() {
+    let mut staging = World::new();
+    let root = staging.create();
+    let child = staging.create_with(Parent(root));
+
+    let mut world = World::new();
+    world.create();
+    let map = staging.move_entities_to([root, child], &mut world);
+    let parent = world.get::<Parent>(map.get(child).unwrap()).unwrap();
+    assert_eq!(parent.0, map.get(root).unwrap());
+}
+
+#[cfg(feature = "resource")]
+mod resource {
+    use super::resources::SimpleResource;
+    use super::*;
+
+    fn worlds() -> (World, World) {
+        let mut world = World::new();
+        let mut resource = SimpleResource::default();
+        resource.set_inner(1);
+        world.create_resources(resource);
+
+        let mut level = World::new();
+        let mut resource = SimpleResource::default();
+        resource.set_inner(2);
+        level.create_resources(resource);
+        level.create_with(Mass(1.0));
+        (world, level)
+    }
+
+    fn inner(world: &World) -> Option<i32> {
+        world
+            .get_resource::<SimpleResource>()
+            .map(SimpleResource::inner)
+    }
+
+    #[test]
+    fn keep() {
+        let (mut world, level) = worlds();
+        world.merge(level, MergePolicy::Keep).unwrap();
+        assert_eq!(inner(&world), Some(1));
+        assert_eq!(world.view_one::<Mass>().count(), 1);
+    }
+
+    #[test]
+    fn replace() {
+        let (mut world, level) = worlds();
+        world.merge(level, MergePolicy::Replace).unwrap();
+        assert_eq!(inner(&world), Some(2));
+    }
+
+    #[test]
+    fn skip() {
+        let (_, level) = worlds();
+        let mut world = World::new();
+        world.merge(level, MergePolicy::Skip).unwrap();
+        assert_eq!(inner(&world), None);
+        assert_eq!(world.view_one::<Mass>().count(), 1);
+    }
+
+    #[test]
+    fn error() {
+        let (mut world, level) = worlds();
+        let error = world.merge(level, MergePolicy::Error).unwrap_err();
+        assert!(error.type_name().ends_with("SimpleResource"));
+        assert_eq!(inner(&world), Some(1));
+        assert_eq!(world.view_one::<Mass>().count(), 0);
+
+        let (_, level) = worlds();
+        let mut world = World::new();
+        world.merge(level, MergePolicy::Error).unwrap();
+        assert_eq!(inner(&world), Some(2));
+    }
+
+    #[test]
+    fn merged_resource_is_added() {
+        let (mut world, level) = worlds();
+        let mut schedule = Schedule::builder()
+            .system(|resource: Res<SimpleResource>| {
+                if resource.inner() == 2 {
+                    assert!(resource.is_added());
+                }
+            })
+            .build();
+        schedule.run(&mut world);
+        world.merge(level, MergePolicy::Replace).unwrap();
+        schedule.run(&mut world);
+    }
+}
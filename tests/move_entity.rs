@@ -0,0 +1,151 @@
+use std::alloc::Layout;
+use std::panic::{self, AssertUnwindSafe};
+
+use components::{Mass, Position, Velocity};
+use toucan_ecs::component::ComponentDescriptor;
+use toucan_ecs::prelude::*;
+
+mod components;
+mod utils;
+
+#[test]
+fn move_entity() {
+    let mut staging = utils::prepare_for_complex_view();
+    let mut world = World::new();
+    let existing = world.create_with(Mass(0.0));
+
+    let (entity, position) = staging.view::<(Entity, &Position)>().next().unwrap();
+    let position = *position;
+    let velocity = staging.get::<Velocity>(entity).copied();
+    let mass = staging.get::<Mass>(entity).copied();
+
+    let moved = staging.move_entity_to(entity, &mut world);
+    assert!(!staging.contains(entity));
+    assert!(!staging.attached::<Position>(entity));
+    assert_eq!(staging.view_one::<Position>().count(), 9);
+
+    assert!(world.contains(moved));
+    assert_ne!(moved, existing);
+    assert_eq!(world.get::<Mass>(existing), Some(&Mass(0.0)));
+    assert_eq!(world.get::<Position>(moved), Some(&position));
+    assert_eq!(world.get::<Velocity>(moved).copied(), velocity);
+    assert_eq!(world.get::<Mass>(moved).copied(), mass);
+}
+
+#[test]
+fn move_entities() {
+    let mut staging = utils::prepare_for_view();
+    let mut world = utils::prepare_for_view();
+
+    let entities = staging
+        .view::<(Entity, &Position)>()
+        .map(|(entity, position)| (entity, *position))
+        .collect::<Vec<_>>();
+    let map = staging.move_entities_to(entities.iter().map(|&(entity, _)| entity), &mut world);
+
+    assert!(staging.is_empty());
+    assert_eq!(map.len(), 10);
+    assert_eq!(world.view_one::<Position>().count(), 20);
+    for (entity, position) in entities {
+        let moved = map.get(entity).unwrap();
+        assert_eq!(world.get::<Position>(moved), Some(&position));
+        assert!(world.attached::<(Velocity, Mass)>(moved));
+    }
+    let mut moved = map.into_iter().map(|(_, new)| new).collect::<Vec<_>>();
+    moved.sort_unstable();
+    moved.dedup();
+    assert_eq!(moved.len(), 10);
+}
+
+#[test]
+fn dynamic_components() {
+    let mut staging = World::new();
+    let mut world = World::new();
+    let health = ComponentDescriptor::new("Health", Layout::new::<u32>());
+    let armor = ComponentDescriptor::new("Armor", Layout::new::<u16>());
+    let staging_health = staging.register_dynamic(health.clone());
+    let staging_armor = staging.register_dynamic(armor.clone());
+    let world_armor = world.register_dynamic(armor);
+
+    let entity = staging.create_with(Mass(1.0));
+    staging.attach_dynamic(entity, staging_health, &1u32.to_ne_bytes());
+    staging.attach_dynamic(entity, staging_armor, &2u16.to_ne_bytes());
+    let moved = staging.move_entity_to(entity, &mut world);
+
+    assert!(!staging.attached_dynamic(entity, staging_health));
+    assert!(!staging.attached_dynamic(entity, staging_armor));
+    assert_eq!(world.get::<Mass>(moved), Some(&Mass(1.0)));
+    assert_eq!(
+        world.get_dynamic(moved, world_armor),
+        Some(&2u16.to_ne_bytes()[..])
+    );
+
+    let info = world.info();
+    let world_health = info
+        .components()
+        .iter()
+        .find(|info| info.name() == "Health")
+        .unwrap();
+    assert_ne!(world_health.id(), staging_health);
+    assert_eq!(
+        world.get_dynamic(moved, world_health.id()),
+        Some(&1u32.to_ne_bytes()[..])
+    );
+}
+
+#[derive(Copy, Clone, Component, Debug, PartialEq)]
+struct Target(Entity);
+
+impl MapEntities for Target {
+    fn map_entities(&mut self, map: &EntityMap) {
+        self.0 = map.map(self.0);
+    }
+}
+
+#[test]
+fn map_moved_entity() {
+    let mut staging = World::new();
+    let mut world = World::new();
+    world.register_map_entities::<Target>();
+
+    let entity = staging.create();
+    staging.attach(entity, Target(entity));
+    let moved = staging.move_entity_to(entity, &mut world);
+
+    assert_eq!(world.get::<Target>(moved), Some(&Target(moved)));
+}
+
+#[test]
+fn move_entities_checks_before_moving() {
+    let mut staging = utils::prepare_for_view();
+    let mut world = World::new();
+    let entities = staging
+        .view_one::<Position>()
+        .map(|(entity, _)| entity)
+        .collect::<Vec<_>>();
+
+    let duplicates = [entities[0], entities[1], entities[0]];
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        staging.move_entities_to(duplicates, &mut world)
+    }));
+    assert!(result.is_err());
+
+    staging.destroy(entities[2]);
+    let destroyed = [entities[0], entities[2]];
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        staging.move_entities_to(destroyed, &mut world)
+    }));
+    assert!(result.is_err());
+
+    assert!(world.is_empty());
+    assert_eq!(staging.view_one::<Position>().count(), 9);
+}
+
+#[test]
+#[should_panic]
+fn move_destroyed() {
+    let mut staging = World::new();
+    let entity = staging.create();
+    staging.destroy(entity);
+    staging.move_entity_to(entity, &mut World::new());
+}
@@ -0,0 +1,115 @@
+use toucan_ecs::prelude::*;
+
+#[test]
+fn set_name() {
+    let mut world = World::new();
+    let entity = world.create();
+
+    world.set_name(entity, "Player");
+    assert_eq!(world.name(entity), Some("Player"));
+    assert_eq!(world.find_by_name("Player"), Some(entity));
+
+    world.set_name(entity, "Enemy");
+    assert_eq!(world.name(entity), Some("Enemy"));
+    assert_eq!(world.find_by_name("Player"), None);
+    assert_eq!(world.find_by_name("Enemy"), Some(entity));
+
+    assert_eq!(world.remove_name(entity).as_deref(), Some("Enemy"));
+    assert_eq!(world.remove_name(entity), None);
+    assert_eq!(world.find_by_name("Enemy"), None);
+}
+
+#[test]
+fn duplicate_names() {
+    let mut world = World::new();
+    let first = world.create();
+    let second = world.create();
+
+    world.set_name(first, "Enemy");
+    world.set_name(second, "Enemy");
+    assert_eq!(world.find_by_name("Enemy"), Some(first));
+
+    world.set_name(first, "Enemy");
+    assert_eq!(world.find_by_name("Enemy"), Some(first));
+
+    world.destroy(first);
+    assert_eq!(world.find_by_name("Enemy"), Some(second));
+
+    world.destroy(second);
+    assert_eq!(world.find_by_name("Enemy"), None);
+}
+
+#[test]
+fn destroy() {
+    let mut world = World::new();
+    let entity = world.create();
+    world.set_name(entity, "Player");
+
+    world.destroy(entity);
+    let reused = world.create();
+    assert_eq!(reused.index(), entity.index());
+    assert_eq!(world.name(reused), None);
+    assert_eq!(world.find_by_name("Player"), None);
+
+    world.set_name(reused, "Player");
+    world.clear();
+    assert_eq!(world.find_by_name("Player"), None);
+}
+
+#[test]
+#[should_panic]
+fn set_name_destroyed() {
+    let mut world = World::new();
+    let entity = world.create();
+    world.destroy(entity);
+    world.set_name(entity, "Player");
+}
+
+#[test]
+fn move_entity() {
+    let mut world = World::new();
+    let entity = world.create();
+    world.set_name(entity, "Player");
+
+    let mut other = World::new();
+    let moved = world.move_entity_to(entity, &mut other);
+    assert_eq!(world.find_by_name("Player"), None);
+    assert_eq!(other.find_by_name("Player"), Some(moved));
+}
+
+#[test]
+fn restore() {
+    let mut world = World::new();
+    let kept = world.create();
+    world.set_name(kept, "Kept");
+    let destroyed = world.create();
+    world.set_name(destroyed, "Destroyed");
+    let snapshot = world.snapshot();
+
+    let created = world.create();
+    world.set_name(created, "Created");
+    world.set_name(kept, "Renamed");
+    world.destroy(destroyed);
+    world.restore(&snapshot);
+
+    assert_eq!(world.find_by_name("Kept"), Some(kept));
+    assert_eq!(world.find_by_name("Renamed"), None);
+    assert_eq!(world.find_by_name("Created"), None);
+    assert_eq!(world.name(destroyed), Some("Destroyed"));
+}
+
+#[test]
+fn debug_entity() {
+    let mut world = World::new();
+    let named = world.create();
+    let unnamed = world.create();
+    world.set_name(named, "Player");
+
+    let named_debug = format!("{:?}", world.debug_entity(named));
+    assert!(named_debug.starts_with("Entity("));
+    assert!(named_debug.ends_with(", \"Player\")"));
+    assert_eq!(
+        format!("{:?}", world.debug_entity(unnamed)),
+        format!("{:?}", unnamed),
+    );
+}
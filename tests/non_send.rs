@@ -0,0 +1,103 @@
+#![cfg(feature = "resource")]
+
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+use toucan_ecs::prelude::*;
+
+#[derive(Default)]
+struct Counter(Rc<Cell<u32>>);
+
+impl Counter {
+    fn get(&self) -> u32 {
+        self.0.get()
+    }
+
+    fn increment(&self) {
+        self.0.set(self.get() + 1)
+    }
+}
+
+#[test]
+fn non_send() {
+    let mut world = World::new();
+    assert!(!world.contains_non_send::<Counter>());
+
+    world.create_non_send(Counter::default());
+    assert!(world.contains_non_send::<Counter>());
+    assert!(!world.is_empty());
+
+    *world.get_non_send_mut::<Counter>().unwrap() = Counter(Rc::new(Cell::new(42)));
+    assert_eq!(world.get_non_send::<Counter>().unwrap().get(), 42);
+
+    world.destroy_non_send::<Counter>();
+    assert!(!world.contains_non_send::<Counter>());
+    assert!(world.is_empty());
+}
+
+#[test]
+fn non_send_system() {
+    let mut world = World::new();
+    world.create_non_send(Counter::default());
+
+    let mut schedule = Schedule::builder()
+        .system(|counter: NonSendMut<Counter>| counter.increment())
+        .system(
+            |counter: NonSend<Counter>, missing: Option<NonSend<Rc<u32>>>| {
+                assert_eq!(counter.get(), 1);
+                assert!(missing.is_none());
+            },
+        )
+        .build();
+    schedule.run(&mut world);
+    assert_eq!(world.get_non_send::<Counter>().unwrap().get(), 1);
+}
+
+#[test]
+#[should_panic(expected = "borrows occur")]
+fn non_send_system_conflict() {
+    let _ = Schedule::builder().system(|_: NonSend<Counter>, _: NonSendMut<Counter>| ());
+}
+
+#[test]
+fn non_send_other_thread() {
+    let mut world = World::new();
+    world.create_non_send(Counter::default());
+
+    let result = thread::spawn(move || {
+        let _ = world.get_non_send::<Counter>();
+    })
+    .join();
+    assert!(result.is_err());
+}
+
+#[test]
+fn non_send_drop_other_thread() {
+    let mut world = World::new();
+    world.create_non_send(Counter::default());
+
+    let result = thread::spawn(move || drop(world)).join();
+    assert!(result.is_err());
+}
+
+#[test]
+fn non_send_schedule_other_thread() {
+    let mut world = World::new();
+    world.create_non_send(Counter::default());
+
+    let executed = Arc::new(AtomicBool::new(false));
+    let mut schedule = Schedule::builder()
+        .system({
+            let executed = Arc::clone(&executed);
+            move || executed.store(true, Ordering::SeqCst)
+        })
+        .system(|counter: NonSendMut<Counter>| counter.increment())
+        .build();
+
+    let result = thread::spawn(move || schedule.run(&mut world)).join();
+    assert!(result.is_err());
+    assert!(!executed.load(Ordering::SeqCst));
+}
@@ -0,0 +1,239 @@
+use toucan_ecs::prelude::*;
+use toucan_ecs::world::{ChangeKind, PatchError, WorldPatch};
+
+#[derive(Copy, Clone, Component, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Health(u32);
+
+#[derive(Copy, Clone, Component, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Target(Entity);
+
+impl MapEntities for Target {
+    fn map_entities(&mut self, map: &EntityMap) {
+        self.0 = map.map(self.0);
+    }
+}
+
+#[derive(Copy, Clone, Component, Debug, PartialEq)]
+struct Ignored;
+
+fn world() -> World {
+    let mut world = World::new();
+    world.register_patch_as::<Health>("health");
+    world.register_patch_as::<Target>("target");
+    world.register_map_entities::<Target>();
+    world
+}
+
+fn kinds(patch: &WorldPatch) -> Vec<(Entity, ChangeKind)> {
+    patch
+        .components()
+        .iter()
+        .map(|component| (component.entity(), component.kind()))
+        .collect()
+}
+
+#[test]
+fn diff() {
+    let mut world = world();
+    let changed = world.create_with(Health(100));
+    let removed = world.create_with(Health(100));
+    let unchanged = world.create_with(Health(100));
+    let destroyed = world.create_with(Health(100));
+    let snapshot = world.snapshot();
+
+    world.get_mut::<Health>(changed).unwrap().0 = 50;
+    world.remove::<Health>(removed);
+    world.attach(unchanged, Ignored);
+    world.destroy(destroyed);
+    let created = world.create_with(Health(1));
+    let attached = world.create();
+    world.attach(unchanged, Target(attached));
+
+    let patch = world.diff(&snapshot);
+    assert!(!patch.is_empty());
+    assert_eq!(patch.created().len(), 2);
+    assert!(patch.created().contains(&created));
+    assert_eq!(patch.destroyed(), [destroyed]);
+
+    assert!(patch.components()[..3]
+        .iter()
+        .all(|component| component.name() == "health"));
+    let mut expected = vec![
+        (changed, ChangeKind::Changed),
+        (removed, ChangeKind::Removed),
+        (created, ChangeKind::Attached),
+    ];
+    expected.sort_unstable_by_key(|&(entity, _)| entity);
+    expected.push((unchanged, ChangeKind::Attached));
+    assert_eq!(kinds(&patch), expected);
+
+    assert!(world.diff(&world.snapshot()).is_empty());
+}
+
+#[test]
+fn apply_patch() {
+    let mut server = world();
+    let mut client = world();
+    let mut entities = EntityMap::new();
+    client.create();
+
+    let snapshot = server.snapshot();
+    let first = server.create_with(Health(100));
+    let second = server.create_with((Health(10), Target(first)));
+    let patch = server.diff(&snapshot);
+    client.apply_patch(&patch, &mut entities).unwrap();
+
+    let first_replica = entities.get(first).unwrap();
+    let second_replica = entities.get(second).unwrap();
+    assert_eq!(client.get::<Health>(first_replica), Some(&Health(100)));
+    assert_eq!(
+        client.get::<Target>(second_replica),
+        Some(&Target(first_replica)),
+    );
+
+    let snapshot = server.snapshot();
+    server.get_mut::<Health>(first).unwrap().0 = 0;
+    server.remove::<Target>(second);
+    server.destroy(second);
+    let patch = server.diff(&snapshot);
+    client.apply_patch(&patch, &mut entities).unwrap();
+
+    assert_eq!(client.get::<Health>(first_replica), Some(&Health(0)));
+    assert!(!client.contains(second_replica));
+    assert!(entities.get(second).is_none());
+}
+
+#[test]
+fn apply_to_replica() {
+    let mut server = world();
+    let entity = server.create_with(Health(100));
+    let mut client = world();
+    client.restore(&server.snapshot());
+
+    let snapshot = server.snapshot();
+    server.get_mut::<Health>(entity).unwrap().0 = 1;
+    let patch = server.diff(&snapshot);
+
+    client.apply_patch(&patch, &mut EntityMap::new()).unwrap();
+    assert_eq!(client.get::<Health>(entity), Some(&Health(1)));
+}
+
+#[test]
+fn unknown_component() {
+    let mut server = world();
+    let snapshot = server.snapshot();
+    server.create_with(Health(100));
+    let patch = server.diff(&snapshot);
+
+    let mut client = World::new();
+    let error = client
+        .apply_patch(&patch, &mut EntityMap::new())
+        .unwrap_err();
+    assert!(matches!(error, PatchError::UnknownComponent { .. }));
+    assert!(client.is_empty());
+}
+
+#[test]
+fn disabled_entities() {
+    let mut server = world();
+    let mut client = world();
+    let mut entities = EntityMap::new();
+    let first = server.create_with(Health(100));
+    let second = server.create_with(Health(100));
+    server.disable(second);
+    client
+        .apply_patch(&server.diff(&World::new().snapshot()), &mut entities)
+        .unwrap();
+
+    let snapshot = server.snapshot();
+    server.get_mut::<Health>(second).unwrap().0 = 50;
+    server.disable(first);
+    server.enable(second);
+    let created = server.create_with(Health(1));
+    server.disable(created);
+
+    let patch = server.diff(&snapshot);
+    let mut disabled = vec![first, created];
+    disabled.sort_unstable();
+    assert_eq!(patch.disabled(), disabled);
+    assert_eq!(patch.enabled(), [second]);
+    let mut expected = vec![
+        (second, ChangeKind::Changed),
+        (created, ChangeKind::Attached),
+    ];
+    expected.sort_unstable_by_key(|&(entity, _)| entity);
+    assert_eq!(kinds(&patch), expected);
+
+    client.apply_patch(&patch, &mut entities).unwrap();
+    let first = entities.map(first);
+    let second = entities.map(second);
+    let created = entities.map(created);
+    assert!(client.is_disabled(first));
+    assert!(!client.is_disabled(second));
+    assert!(client.is_disabled(created));
+    assert_eq!(client.get::<Health>(second), Some(&Health(50)));
+    assert_eq!(client.get::<Health>(created), Some(&Health(1)));
+}
+
+#[test]
+fn registered_names() {
+    let mut server = world();
+    server.register_patch_as::<Health>("hp");
+    let snapshot = server.snapshot();
+    server.create_with(Health(100));
+    let patch = server.diff(&snapshot);
+    assert_eq!(patch.components()[0].name(), "hp");
+
+    let mut client = world();
+    let error = client
+        .apply_patch(&patch, &mut EntityMap::new())
+        .unwrap_err();
+    assert_eq!(
+        error,
+        PatchError::UnknownComponent {
+            name: "hp".to_string()
+        },
+    );
+
+    client.register_patch_as::<Health>("hp");
+    client.apply_patch(&patch, &mut EntityMap::new()).unwrap();
+    assert_eq!(client.view_one::<Health>().count(), 1);
+}
+
+#[test]
+#[should_panic(expected = "already registered")]
+fn duplicate_name() {
+    let mut world = world();
+    world.register_patch_as::<Target>("health");
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn serialize() {
+    let mut server = world();
+    let snapshot = server.snapshot();
+    for i in 0..10 {
+        let entity = server.create_with(Health(i));
+        server.attach(entity, Target(entity));
+    }
+    let patch = server.diff(&snapshot);
+
+    let json = serde_json::to_string(&patch).unwrap();
+    assert_eq!(
+        json,
+        serde_json::to_string(&server.diff(&snapshot)).unwrap()
+    );
+    let deserialized: WorldPatch = serde_json::from_str(&json).unwrap();
+    assert_eq!(serde_json::to_string(&deserialized).unwrap(), json);
+
+    let mut client = world();
+    let mut entities = EntityMap::new();
+    client.apply_patch(&deserialized, &mut entities).unwrap();
+    assert_eq!(client.view_one::<Health>().count(), 10);
+    for (old, new) in entities.iter() {
+        assert_eq!(client.get::<Target>(new), Some(&Target(new)));
+        assert_eq!(client.get::<Health>(new), server.get::<Health>(old));
+    }
+}
@@ -0,0 +1,164 @@
+use components::{Mass, Position, Velocity};
+use toucan_ecs::prelude::*;
+
+mod components;
+
+fn pool(world: &mut World) -> EntityPool<(Position, Mass)> {
+    let template = (Position { x: 0.0, y: 0.0 }, Mass(1.0));
+    EntityPool::new(world, 10, template)
+}
+
+#[test]
+fn new() {
+    let mut world = World::new();
+    let pool = pool(&mut world);
+
+    assert_eq!(pool.len(), 10);
+    assert_eq!(pool.available(), 10);
+    assert_eq!(world.info().entities(), 10);
+    assert_eq!(world.view_one::<Position>().count(), 0);
+    assert_eq!(world.view::<(&Mass, IncludeDisabled)>().count(), 10);
+}
+
+#[test]
+fn acquire_release() {
+    let mut world = World::new();
+    let mut pool = pool(&mut world);
+
+    let entity = pool.acquire(&mut world);
+    assert!(pool.contains(entity));
+    assert!(pool.is_acquired(entity));
+    assert_eq!(pool.available(), 9);
+    assert_eq!(world.view_one::<Position>().count(), 1);
+
+    world.get_mut::<Mass>(entity).unwrap().0 = 100.0;
+    assert!(pool.release(&mut world, entity));
+    assert!(!pool.release(&mut world, entity));
+    assert!(!pool.contains(entity));
+    assert_eq!(pool.available(), 10);
+    assert_eq!(world.info().entities(), 10);
+
+    let reused = pool.acquire(&mut world);
+    assert_eq!(reused.index(), entity.index());
+    assert_ne!(reused.generation(), entity.generation());
+    assert_eq!(world.get::<Mass>(reused), Some(&Mass(1.0)));
+}
+
+#[test]
+fn stale_handle() {
+    let mut world = World::new();
+    let mut pool = pool(&mut world);
+
+    let stale = pool.acquire(&mut world);
+    world.set_name(stale, "stale");
+    pool.release(&mut world, stale);
+    assert!(!world.contains(stale));
+    assert_eq!(world.get::<Mass>(stale), None);
+
+    let reused = pool.acquire(&mut world);
+    assert_eq!(reused.index(), stale.index());
+    assert!(world.contains(reused));
+    assert!(!world.contains(stale));
+    assert_eq!(world.get::<Mass>(stale), None);
+    assert_eq!(
+        world.get::<Position>(reused),
+        Some(&Position { x: 0.0, y: 0.0 })
+    );
+    assert_eq!(world.find_by_name("stale"), Some(reused));
+    assert!(!pool.release(&mut world, stale));
+    assert!(pool.is_acquired(reused));
+}
+
+#[test]
+fn keep_generation() {
+    let mut world = World::new();
+    let template = (Position { x: 0.0, y: 0.0 }, Mass(1.0));
+    let mut pool = EntityPool::with_policy(&mut world, 10, template, GenerationPolicy::Keep);
+    assert_eq!(pool.policy(), GenerationPolicy::Keep);
+
+    let entity = pool.acquire(&mut world);
+    world.get_mut::<Mass>(entity).unwrap().0 = 100.0;
+    assert!(pool.release(&mut world, entity));
+    assert!(world.contains(entity));
+    assert!(world.is_disabled(entity));
+
+    let reused = pool.acquire(&mut world);
+    assert_eq!(reused, entity);
+    assert_eq!(world.get::<Mass>(reused), Some(&Mass(1.0)));
+}
+
+#[test]
+fn release_removes_extra_components() {
+    let mut world = World::new();
+    let template = (Position { x: 0.0, y: 0.0 }, Mass(1.0));
+    let mut pool = EntityPool::with_policy(&mut world, 1, template, GenerationPolicy::Keep);
+
+    let entity = pool.acquire(&mut world);
+    world.attach(entity, Velocity { dx: 1.0, dy: 1.0 });
+    pool.release(&mut world, entity);
+    assert!(!world.attached::<Velocity>(entity));
+    assert_eq!(world.removed::<Velocity>().collect::<Vec<_>>(), [entity]);
+    assert_eq!(world.removed::<Mass>().len(), 0);
+
+    let reused = pool.acquire(&mut world);
+    assert_eq!(reused, entity);
+    assert!(world.attached::<(Position, Mass)>(reused));
+    assert!(!world.attached::<Velocity>(reused));
+}
+
+#[test]
+fn renew_tracks_removals() {
+    let mut world = World::new();
+    let mut pool = pool(&mut world);
+
+    let entity = pool.acquire(&mut world);
+    pool.release(&mut world, entity);
+    assert_eq!(world.removed::<Position>().collect::<Vec<_>>(), [entity]);
+    assert_eq!(world.removed::<Mass>().collect::<Vec<_>>(), [entity]);
+}
+
+#[test]
+fn grow() {
+    let mut world = World::new();
+    let mut pool = pool(&mut world);
+
+    let entities = (0..15)
+        .map(|_| pool.acquire(&mut world))
+        .collect::<Vec<_>>();
+    assert_eq!(pool.len(), 15);
+    assert_eq!(pool.available(), 0);
+    assert_eq!(world.view_one::<Position>().count(), 15);
+
+    for entity in entities {
+        pool.release(&mut world, entity);
+    }
+    assert_eq!(pool.available(), 15);
+    assert_eq!(world.view_one::<Position>().count(), 0);
+}
+
+#[test]
+fn foreign_and_destroyed() {
+    let mut world = World::new();
+    let mut pool = pool(&mut world);
+
+    let foreign = world.create_with(Mass(5.0));
+    assert!(!pool.contains(foreign));
+    assert!(!pool.release(&mut world, foreign));
+    assert!(!world.is_disabled(foreign));
+
+    let acquired = pool.acquire(&mut world);
+    world.destroy(acquired);
+    assert!(!pool.release(&mut world, acquired));
+    assert!(!pool.contains(acquired));
+
+    let destroyed = world
+        .view::<(Entity, &Position, IncludeDisabled)>()
+        .map(|(entity, _, _)| entity)
+        .collect::<Vec<_>>();
+    for entity in destroyed {
+        world.destroy(entity);
+    }
+    let entity = pool.acquire(&mut world);
+    assert!(world.contains(entity));
+    assert_eq!(pool.len(), 1);
+}
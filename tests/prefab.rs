@@ -0,0 +1,98 @@
+use components::{Mass, Position, Velocity};
+use toucan_ecs::prelude::*;
+
+mod components;
+
+fn prefab() -> Prefab {
+    Prefab::new()
+        .with(Position { x: 1.0, y: 1.0 })
+        .with(Mass(10.0))
+}
+
+#[test]
+fn spawn_many() {
+    let prefab = prefab();
+    let mut world = World::new();
+
+    let entities = (0..10)
+        .map(|_| world.spawn_prefab(&prefab))
+        .collect::<Vec<_>>();
+    for &entity in &entities {
+        assert_eq!(
+            world.get::<Position>(entity),
+            Some(&Position { x: 1.0, y: 1.0 })
+        );
+        assert_eq!(world.get::<Mass>(entity), Some(&Mass(10.0)));
+        assert!(!world.attached::<Parent>(entity));
+    }
+
+    world.get_mut::<Mass>(entities[0]).unwrap().0 = 0.0;
+    assert_eq!(world.get::<Mass>(entities[1]), Some(&Mass(10.0)));
+    assert_eq!(prefab.get::<Mass>(), Some(&Mass(10.0)));
+}
+
+#[test]
+fn overrides() {
+    let prefab = prefab();
+    let mut world = World::new();
+
+    let entity = world.spawn_prefab_with(&prefab, (Mass(1.0), Velocity { dx: 1.0, dy: 0.0 }));
+    assert_eq!(
+        world.get::<Position>(entity),
+        Some(&Position { x: 1.0, y: 1.0 })
+    );
+    assert_eq!(world.get::<Mass>(entity), Some(&Mass(1.0)));
+    assert!(world.attached::<Velocity>(entity));
+
+    let entity = world.spawn_prefab(&prefab);
+    assert_eq!(world.get::<Mass>(entity), Some(&Mass(10.0)));
+    assert!(!world.attached::<Velocity>(entity));
+}
+
+#[test]
+fn edit_prefab() {
+    let mut prefab = prefab().with(Mass(5.0));
+    assert_eq!(prefab.get::<Mass>(), Some(&Mass(5.0)));
+
+    prefab.remove::<Position>();
+    prefab.insert(Velocity { dx: 0.0, dy: 1.0 });
+    assert!(prefab.get::<Position>().is_none());
+
+    let mut world = World::new();
+    let entity = world.spawn_prefab(&prefab);
+    assert!(world.attached::<(Mass, Velocity)>(entity));
+    assert!(!world.attached::<Position>(entity));
+}
+
+#[test]
+fn children() {
+    let wheel = Prefab::new().with(Mass(1.0));
+    let car = prefab()
+        .with_child(wheel.clone())
+        .with_child(wheel.clone())
+        .with_child(Prefab::new().with_child(wheel));
+    assert_eq!(car.children().len(), 3);
+
+    let mut world = World::new();
+    let root = world.spawn_prefab(&car);
+    let other = world.spawn_prefab(&car);
+
+    let children = world
+        .view::<(Entity, &Parent)>()
+        .filter(|(_, parent)| parent.0 == root)
+        .map(|(entity, _)| entity)
+        .collect::<Vec<_>>();
+    assert_eq!(children.len(), 3);
+    assert_eq!(world.view_one::<Parent>().count(), 8);
+
+    let grandchildren = world
+        .view::<(&Mass, &Parent)>()
+        .filter(|(_, parent)| children.contains(&parent.0))
+        .count();
+    assert_eq!(grandchildren, 1);
+    let other_children = world
+        .view_one::<Parent>()
+        .filter(|(_, parent)| parent.0 == other)
+        .count();
+    assert_eq!(other_children, 3);
+}
@@ -0,0 +1,135 @@
+use toucan_ecs::prelude::*;
+use toucan_ecs::reflect::ReflectError;
+
+#[derive(Copy, Clone, Component, Debug, Default, PartialEq, Reflect)]
+struct Transform {
+    position: Vec2,
+    angle: f32,
+    #[reflect(skip)]
+    dirty: bool,
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Reflect)]
+struct Vec2(f32, f32);
+
+#[derive(Copy, Clone, Component, Default, Reflect)]
+struct Marker;
+
+#[test]
+fn fields() {
+    let mut transform = Transform::default();
+    assert_eq!(transform.fields(), ["position", "angle"]);
+    assert!(transform.field("dirty").is_none());
+
+    let position = transform.field("position").unwrap();
+    assert_eq!(position.fields(), ["0", "1"]);
+    assert!(position.is::<Vec2>());
+    assert_eq!(position.downcast_ref::<Vec2>(), Some(&Vec2(0.0, 0.0)));
+    assert!(position.display().is_none());
+
+    let y = transform
+        .field_mut("position")
+        .and_then(|position| position.field_mut("1"))
+        .unwrap();
+    y.set_from_str("2.5").unwrap();
+    assert_eq!(y.display().as_deref(), Some("2.5"));
+    *y.downcast_mut::<f32>().unwrap() += 1.0;
+    assert_eq!(transform.position, Vec2(0.0, 3.5));
+
+    assert!(Marker.fields().is_empty());
+    assert!(Reflect::type_name(&Marker).ends_with("Marker"));
+}
+
+#[test]
+fn set_from_str_errors() {
+    let mut transform = Transform::default();
+    let angle = transform.field_mut("angle").unwrap();
+    let error = angle.set_from_str("not a number").unwrap_err();
+    assert!(matches!(
+        error,
+        ReflectError::InvalidValue {
+            type_name: "f32",
+            ..
+        }
+    ));
+
+    let error = transform.set_from_str("0").unwrap_err();
+    assert!(matches!(error, ReflectError::Unsupported { .. }));
+}
+
+#[test]
+fn world() {
+    let mut world = World::new();
+    world.register_reflect::<Transform>();
+    world.register_reflect::<Marker>();
+
+    let names = world.reflect_type_names().collect::<Vec<_>>();
+    assert_eq!(names.len(), 2);
+    assert!(names.iter().any(|name| name.ends_with("::Transform")));
+
+    let entity = world.create();
+    assert!(world.get_reflect(entity, "Transform").unwrap().is_none());
+
+    let transform = world.attach_reflect(entity, "Transform").unwrap();
+    transform
+        .field_mut("angle")
+        .unwrap()
+        .set_from_str("90")
+        .unwrap();
+    assert_eq!(world.get::<Transform>(entity).unwrap().angle, 90.0);
+
+    let full_name = std::any::type_name::<Transform>();
+    let transform = world.get_reflect(entity, full_name).unwrap().unwrap();
+    let angle = transform.field("angle").unwrap();
+    assert_eq!(angle.display().as_deref(), Some("90"));
+
+    let transform = world.get_reflect_mut(entity, "Transform").unwrap().unwrap();
+    transform.downcast_mut::<Transform>().unwrap().position = Vec2(1.0, 1.0);
+    assert_eq!(
+        world.get::<Transform>(entity).unwrap().position,
+        Vec2(1.0, 1.0)
+    );
+
+    world.attach_reflect(entity, "Marker").unwrap();
+    assert!(world.attached::<Marker>(entity));
+}
+
+#[test]
+fn world_unknown_type() {
+    let mut world = World::new();
+    let entity = world.create();
+    let error = world.attach_reflect(entity, "Transform").err().unwrap();
+    assert_eq!(
+        error,
+        ReflectError::UnknownType {
+            name: "Transform".to_string(),
+        },
+    );
+    assert!(world.is_entity_empty(entity));
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn set_value() {
+    use std::collections::BTreeMap;
+
+    use toucan_ecs::reflect::Value;
+
+    let position = Value::Seq(vec![Value::F32(1.0), Value::F64(2.0)]);
+    let map = BTreeMap::from([
+        (Value::String("position".to_string()), position),
+        (Value::String("angle".to_string()), Value::I32(45)),
+    ]);
+    let mut transform = Transform::default();
+    transform.set_value(Value::Map(map)).unwrap();
+    assert_eq!(transform.position, Vec2(1.0, 2.0));
+    assert_eq!(transform.angle, 45.0);
+
+    let map = BTreeMap::from([(Value::String("dirty".to_string()), Value::Bool(true))]);
+    let error = transform.set_value(Value::Map(map)).unwrap_err();
+    assert!(matches!(error, ReflectError::UnknownField { .. }));
+
+    let angle = transform.field_mut("angle").unwrap();
+    let error = angle.set_value(Value::String("a".to_string())).unwrap_err();
+    assert!(matches!(error, ReflectError::InvalidValue { .. }));
+}
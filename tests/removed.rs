@@ -0,0 +1,233 @@
+use std::sync::{Arc, RwLock};
+
+use components::{Mass, Position, Velocity};
+use toucan_ecs::prelude::*;
+
+mod components;
+mod utils;
+
+type Log = Arc<RwLock<Vec<Entity>>>;
+
+fn take(log: &Log) -> Vec<Entity> {
+    std::mem::take(&mut *log.write().unwrap())
+}
+
+fn prepare() -> (World, Vec<Entity>) {
+    let world = utils::prepare_for_view();
+    let entities = world
+        .view_one::<Position>()
+        .map(|(entity, _)| entity)
+        .collect();
+    (world, entities)
+}
+
+fn logging_schedule(log: &Log) -> Schedule {
+    Schedule::builder()
+        .system({
+            let log = Arc::clone(log);
+            move |removed: RemovedComponents<Position>| log.write().unwrap().extend(removed)
+        })
+        .build()
+}
+
+#[test]
+fn remove_and_destroy() {
+    let mut world = World::new();
+    let first = world.create_with((Position { x: 0.0, y: 0.0 }, Mass(1.0)));
+    let second = world.create_with(Position { x: 1.0, y: 1.0 });
+    let third = world.create_with(Mass(2.0));
+
+    world.remove::<Position>(first);
+    world.remove::<Position>(first);
+    world.remove::<Position>(third);
+    world.destroy(second);
+    world.destroy(third);
+
+    assert_eq!(
+        world.removed::<Position>().collect::<Vec<_>>(),
+        [first, second]
+    );
+    assert_eq!(world.removed::<Mass>().collect::<Vec<_>>(), [third]);
+    assert_eq!(world.removed::<Velocity>().len(), 0);
+
+    world.remove_all(first);
+    assert_eq!(world.removed::<Mass>().collect::<Vec<_>>(), [third, first]);
+}
+
+#[test]
+fn since_last_run() {
+    let (mut world, entities) = prepare();
+    let log = Log::default();
+    let mut schedule = logging_schedule(&log);
+
+    world.remove::<Position>(entities[0]);
+    schedule.run(&mut world);
+    assert_eq!(take(&log), []);
+
+    world.remove::<Position>(entities[3]);
+    schedule.run(&mut world);
+    assert_eq!(take(&log), [entities[3]]);
+
+    schedule.run(&mut world);
+    assert_eq!(take(&log), []);
+
+    world.destroy(entities[1]);
+    world.remove::<Velocity>(entities[2]);
+    schedule.run(&mut world);
+    assert_eq!(take(&log), [entities[1]]);
+}
+
+#[test]
+fn separate_systems() {
+    let (mut world, entities) = prepare();
+    let log = Log::default();
+    let mut schedule = logging_schedule(&log);
+    schedule.run(&mut world);
+
+    world.destroy(entities[0]);
+    schedule.run(&mut world);
+    assert_eq!(take(&log), [entities[0]]);
+
+    // new system does not see removals which happened before its first run
+    let late = Log::default();
+    let mut late_schedule = logging_schedule(&late);
+    world.destroy(entities[1]);
+    late_schedule.run(&mut world);
+    assert_eq!(take(&late), []);
+    schedule.run(&mut world);
+    assert_eq!(take(&log), [entities[1]]);
+
+    world.destroy(entities[2]);
+    late_schedule.run(&mut world);
+    assert_eq!(take(&late), [entities[2]]);
+    schedule.run(&mut world);
+    assert_eq!(take(&log), [entities[2]]);
+}
+
+#[test]
+fn schedule_drops_removals_without_readers() {
+    let (mut world, entities) = prepare();
+    let mut schedule = Schedule::builder().build();
+
+    world.destroy(entities[0]);
+    assert_eq!(world.removed::<Position>().len(), 1);
+    schedule.run(&mut world);
+    assert_eq!(world.removed::<Position>().len(), 0);
+}
+
+#[test]
+fn several_schedules() {
+    let (mut world, entities) = prepare();
+    let first = Log::default();
+    let second = Log::default();
+    let mut first_schedule = logging_schedule(&first);
+    let mut empty_schedule = Schedule::builder().build();
+    let mut second_schedule = logging_schedule(&second);
+    first_schedule.run(&mut world);
+    second_schedule.run(&mut world);
+
+    world.destroy(entities[0]);
+    first_schedule.run(&mut world);
+    empty_schedule.run(&mut world);
+    second_schedule.run(&mut world);
+    assert_eq!(take(&first), [entities[0]]);
+    assert_eq!(take(&second), [entities[0]]);
+    assert_eq!(world.removed::<Position>().len(), 0);
+}
+
+#[test]
+fn schedule_runs_several_times() {
+    let (mut world, entities) = prepare();
+    let log = Log::default();
+    let fast = Log::default();
+    let mut schedule = logging_schedule(&log);
+    let mut fast_schedule = logging_schedule(&fast);
+    schedule.run(&mut world);
+    fast_schedule.run(&mut world);
+
+    world.destroy(entities[0]);
+    fast_schedule.run(&mut world);
+    world.destroy(entities[1]);
+    fast_schedule.run(&mut world);
+    fast_schedule.run(&mut world);
+    assert_eq!(take(&fast), [entities[0], entities[1]]);
+
+    schedule.run(&mut world);
+    assert_eq!(take(&log), [entities[0], entities[1]]);
+    assert_eq!(world.removed::<Position>().len(), 0);
+}
+
+#[test]
+fn reader_holds_removals() {
+    let (mut world, entities) = prepare();
+    let log = Log::default();
+    let mut schedule = logging_schedule(&log);
+    let mut empty_schedule = Schedule::builder().build();
+    schedule.run(&mut world);
+
+    world.destroy(entities[0]);
+    empty_schedule.run(&mut world);
+    empty_schedule.run(&mut world);
+    assert_eq!(world.removed::<Position>().len(), 1);
+
+    drop(schedule);
+    empty_schedule.run(&mut world);
+    assert_eq!(world.removed::<Position>().len(), 0);
+}
+
+#[test]
+fn clear_trackers() {
+    let (mut world, entities) = prepare();
+
+    world.destroy(entities[0]);
+    world.clear_trackers();
+    world.destroy(entities[1]);
+    assert_eq!(world.removed::<Position>().len(), 2);
+
+    world.clear_trackers();
+    assert_eq!(
+        world.removed::<Position>().collect::<Vec<_>>(),
+        [entities[1]]
+    );
+
+    world.clear_trackers();
+    assert_eq!(world.removed::<Position>().len(), 0);
+}
+
+#[test]
+fn app_clears_trackers() {
+    let log = Log::default();
+    let mut builder = App::builder();
+    builder.system({
+        let log = Arc::clone(&log);
+        move |removed: RemovedComponents<Position>| log.write().unwrap().extend(removed)
+    });
+    let mut app = builder.build();
+    app.run();
+
+    let entity = app.world_mut().create_with(Position { x: 0.0, y: 0.0 });
+    app.world_mut().destroy(entity);
+    app.run();
+    assert_eq!(take(&log), [entity]);
+
+    app.run();
+    app.run();
+    assert_eq!(take(&log), []);
+    assert_eq!(app.world().removed::<Position>().len(), 0);
+}
+
+#[test]
+fn clear_and_move() {
+    let (mut world, entities) = prepare();
+
+    let mut other = World::new();
+    world.move_entity_to(entities[0], &mut other);
+    assert_eq!(
+        world.removed::<Position>().collect::<Vec<_>>(),
+        [entities[0]]
+    );
+    assert_eq!(other.removed::<Position>().len(), 0);
+
+    world.clear();
+    assert_eq!(world.removed::<Position>().len(), entities.len());
+}
@@ -0,0 +1,115 @@
+#![cfg(feature = "resource")]
+
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, RwLock};
+
+use components::Position;
+use resources::SimpleResource;
+use toucan_ecs::prelude::*;
+
+mod components;
+mod resources;
+
+#[test]
+fn scope() {
+    let mut world = World::new();
+    world.create_resources(SimpleResource::default());
+
+    let count = world.resource_scope(|world, resource: &mut SimpleResource| {
+        assert!(!world.contains_resources::<SimpleResource>());
+        for _ in 0..5 {
+            world.create_with(Position { x: 0.0, y: 0.0 });
+        }
+        resource.set_inner(5);
+        world.view_one::<Position>().count()
+    });
+
+    assert_eq!(count, 5);
+    assert_eq!(world.get_resource::<SimpleResource>().unwrap().inner(), 5);
+}
+
+#[test]
+fn scope_generic_arguments() {
+    let mut world = World::new();
+    let mut resource = SimpleResource::default();
+    resource.set_inner(3);
+    world.create_resources(resource);
+
+    let inner: i32 = world.resource_scope::<SimpleResource, i32>(|_, resource| resource.inner());
+    assert_eq!(inner, 3);
+}
+
+#[test]
+#[should_panic]
+fn scope_missing() {
+    let mut world = World::new();
+    world.resource_scope(|_, _: &mut SimpleResource| ());
+}
+
+#[test]
+fn try_scope() {
+    let mut world = World::new();
+    let result = world.try_resource_scope(|_, _: &mut SimpleResource| unreachable!());
+    assert!(result.is_none());
+
+    world.create_resources(SimpleResource::default());
+    let result = world.try_resource_scope(|_, resource: &mut SimpleResource| resource.inner());
+    assert_eq!(result, Some(0));
+}
+
+#[test]
+fn scope_replaces_created() {
+    let mut world = World::new();
+    world.create_resources(SimpleResource::default());
+
+    world.resource_scope(|world, resource: &mut SimpleResource| {
+        resource.set_inner(1);
+        let mut created = SimpleResource::default();
+        created.set_inner(2);
+        world.create_resources(created);
+    });
+
+    assert_eq!(world.get_resource::<SimpleResource>().unwrap().inner(), 1);
+}
+
+#[test]
+fn scope_panic() {
+    let mut world = World::new();
+    world.create_resources(SimpleResource::default());
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        world.resource_scope(|_, resource: &mut SimpleResource| {
+            resource.set_inner(1);
+            panic!("scope panicked")
+        })
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(world.get_resource::<SimpleResource>().unwrap().inner(), 1);
+}
+
+#[test]
+fn scope_keeps_ticks() {
+    let mut world = World::new();
+    world.create_resources(SimpleResource::default());
+
+    let changed = Arc::new(RwLock::new(Vec::new()));
+    let mut schedule = Schedule::builder()
+        .system({
+            let changed = Arc::clone(&changed);
+            move |resource: Res<SimpleResource>| {
+                changed.write().unwrap().push(resource.is_changed())
+            }
+        })
+        .build();
+    schedule.run(&mut world);
+
+    world.resource_scope(|_, resource: &mut SimpleResource| resource.inner());
+    schedule.run(&mut world);
+
+    world.resource_scope(|_, resource: &mut SimpleResource| resource.set_inner(1));
+    world.get_resource_mut::<SimpleResource>();
+    schedule.run(&mut world);
+
+    assert_eq!(*changed.read().unwrap(), [true, false, true]);
+}
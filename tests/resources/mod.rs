@@ -6,6 +6,7 @@ pub struct SimpleResource {
 }
 
 impl SimpleResource {
+    #[allow(dead_code)]
     pub fn inner(&self) -> i32 {
         self.inner
     }
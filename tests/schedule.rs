@@ -0,0 +1,118 @@
+use std::sync::{Arc, RwLock};
+
+use components::Position;
+use toucan_ecs::prelude::*;
+use toucan_ecs::system::SystemStats;
+
+mod components;
+mod utils;
+
+type Log = Arc<RwLock<Vec<&'static str>>>;
+
+fn logging(log: &Log, name: &'static str) -> impl FnMut() + Send + 'static {
+    let log = Arc::clone(log);
+    move || log.write().unwrap().push(name)
+}
+
+fn take(log: &Log) -> Vec<&'static str> {
+    std::mem::take(&mut *log.write().unwrap())
+}
+
+#[test]
+fn insert_remove() {
+    let mut world = World::new();
+    let log = Log::default();
+
+    let mut schedule = Schedule::builder().system(logging(&log, "first")).build();
+    let second = schedule.insert_system(logging(&log, "second"));
+    assert_eq!(schedule.len(), 2);
+    assert!(schedule.contains_system(second));
+
+    schedule.run(&mut world);
+    assert_eq!(take(&log), ["first", "second"]);
+
+    assert!(schedule.remove_system(second));
+    assert!(!schedule.remove_system(second));
+    assert!(!schedule.contains_system(second));
+
+    let third = schedule.insert_system(logging(&log, "third"));
+    assert_ne!(second, third);
+    schedule.run(&mut world);
+    assert_eq!(take(&log), ["first", "third"]);
+}
+
+#[test]
+fn enable_disable() {
+    let mut world = World::new();
+    let log = Log::default();
+
+    let mut schedule = Schedule::builder()
+        .system(logging(&log, "first"))
+        .system(logging(&log, "second"))
+        .build();
+    let first = schedule.system_ids().next().unwrap();
+    assert!(schedule.is_enabled(first));
+
+    assert!(schedule.disable(first));
+    assert!(!schedule.is_enabled(first));
+    schedule.run(&mut world);
+    assert_eq!(take(&log), ["second"]);
+
+    assert!(schedule.enable(first));
+    schedule.run(&mut world);
+    assert_eq!(take(&log), ["first", "second"]);
+
+    schedule.remove_system(first);
+    assert!(!schedule.enable(first));
+    assert!(!schedule.is_enabled(first));
+}
+
+#[test]
+fn reorder() {
+    let mut world = World::new();
+    let log = Log::default();
+
+    let mut schedule = Schedule::builder().with_stats().build();
+    let first = schedule.insert_system(logging(&log, "first"));
+    let second = schedule.insert_system(logging(&log, "second"));
+    let third = schedule.insert_system(logging(&log, "third"));
+
+    assert!(schedule.move_system(third, 0));
+    assert_eq!(schedule.position(third), Some(0));
+    assert_eq!(
+        schedule.system_ids().collect::<Vec<_>>(),
+        [third, first, second],
+    );
+    schedule.run(&mut world);
+    assert_eq!(take(&log), ["third", "first", "second"]);
+
+    assert!(schedule.move_system(third, 2));
+    schedule.run(&mut world);
+    assert_eq!(take(&log), ["first", "second", "third"]);
+
+    let stats = schedule.stats().unwrap();
+    let ids = stats.iter().map(SystemStats::id).collect::<Vec<_>>();
+    assert_eq!(ids, [first, second, third]);
+    assert_eq!(stats.system(third).unwrap().invocations(), 2);
+}
+
+#[test]
+#[should_panic]
+fn reorder_out_of_bounds() {
+    let mut schedule = Schedule::builder().build();
+    let id = schedule.insert_system(|| println!("Hello, World"));
+    schedule.move_system(id, 1);
+}
+
+#[test]
+fn insert_foreach_system() {
+    let mut world = utils::prepare_for_view();
+
+    let mut schedule = Schedule::builder().build();
+    schedule.insert_foreach_system(|position: &mut Position| position.x = 0.0);
+    schedule.run(&mut world);
+
+    for position in world.view_one::<Position>().map(|(_, position)| position) {
+        assert_eq!(position.x, 0.0);
+    }
+}
@@ -0,0 +1,129 @@
+use std::alloc::Layout;
+
+use components::{Mass, Position, Velocity};
+use toucan_ecs::component::ComponentDescriptor;
+use toucan_ecs::prelude::*;
+
+mod components;
+#[cfg(feature = "resource")]
+mod resources;
+mod utils;
+
+#[test]
+fn restore() {
+    let mut world = utils::prepare_for_complex_view();
+    let snapshot = world.snapshot();
+    assert_eq!(snapshot.len(), 10);
+
+    let entities = world
+        .view::<(Entity, &Position)>()
+        .map(|(entity, position)| (entity, *position))
+        .collect::<Vec<_>>();
+    for (entity, _) in &entities[..5] {
+        world.destroy(*entity);
+    }
+    for (entity, _) in &entities[5..] {
+        world.get_mut::<Position>(*entity).unwrap().x = 0.0;
+    }
+    let created = world.create_with(Mass(0.0));
+    world.register::<Velocity>();
+    world.remove::<Velocity>(entities[9].0);
+
+    world.restore(&snapshot);
+    assert!(!world.contains(created));
+    assert!(!snapshot.contains(created));
+    assert_eq!(world.view_one::<Position>().count(), 10);
+    assert_eq!(world.view_one::<Mass>().count(), 5);
+    assert_eq!(world.view_one::<Velocity>().count(), 5);
+    for (entity, position) in entities {
+        assert!(world.contains(entity));
+        assert!(snapshot.contains(entity));
+        assert_eq!(world.get::<Position>(entity), Some(&position));
+    }
+}
+
+#[test]
+fn restore_many_times() {
+    let mut world = World::new();
+    let entity = world.create_with(Position { x: 0.0, y: 0.0 });
+    let snapshot = world.snapshot();
+
+    for i in 1..=3 {
+        world.get_mut::<Position>(entity).unwrap().x = i as f32;
+        world.attach(entity, Mass(1.0));
+        world.restore(&snapshot);
+        assert_eq!(world.get::<Position>(entity).unwrap().x, 0.0);
+        assert!(!world.attached::<Mass>(entity));
+    }
+}
+
+#[test]
+fn stale_handles_stay_invalid() {
+    let mut world = World::new();
+    let destroyed = world.create();
+    world.destroy(destroyed);
+    let snapshot = world.snapshot();
+    assert!(snapshot.is_empty());
+
+    let reused = world.create();
+    world.restore(&snapshot);
+    assert!(!world.contains(destroyed));
+    assert!(!world.contains(reused));
+
+    let created = world.create();
+    assert!(!world.contains(destroyed));
+    assert_eq!(created, reused);
+}
+
+#[test]
+fn dynamic_components() {
+    let mut world = World::new();
+    let descriptor = ComponentDescriptor::new("Health", Layout::new::<u32>());
+    let health = world.register_dynamic(descriptor);
+    let entity = world.create();
+    world.attach_dynamic(entity, health, &100u32.to_ne_bytes());
+    let snapshot = world.snapshot();
+
+    world.get_dynamic_mut(entity, health).unwrap()[0] = 0;
+    let descriptor = ComponentDescriptor::new("Mana", Layout::new::<u32>());
+    let mana = world.register_dynamic(descriptor);
+    world.attach_dynamic(entity, mana, &1u32.to_ne_bytes());
+
+    world.restore(&snapshot);
+    assert_eq!(
+        world.get_dynamic(entity, health),
+        Some(&100u32.to_ne_bytes()[..]),
+    );
+    assert!(!world.attached_dynamic(entity, mana));
+    assert!(world.dynamic_descriptor(mana).is_some());
+}
+
+#[test]
+#[cfg(feature = "resource")]
+fn resources_are_untouched() {
+    use resources::SimpleResource;
+
+    let mut world = World::new();
+    let snapshot = world.snapshot();
+    world.create_resources(SimpleResource::default());
+    world.restore(&snapshot);
+    assert!(world.contains_resources::<SimpleResource>());
+}
+
+#[test]
+fn removals_are_tracked() {
+    let mut world = World::new();
+    let kept = world.create_with(Position { x: 0.0, y: 0.0 });
+    let snapshot = world.snapshot();
+
+    let created = world.create_with((Position { x: 1.0, y: 1.0 }, Mass(1.0)));
+    world.attach(kept, Mass(2.0));
+    world.restore(&snapshot);
+
+    assert_eq!(world.removed::<Position>().collect::<Vec<_>>(), [created]);
+    let mut removed = world.removed::<Mass>().collect::<Vec<_>>();
+    removed.sort();
+    let mut expected = vec![kept, created];
+    expected.sort();
+    assert_eq!(removed, expected);
+}
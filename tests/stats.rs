@@ -0,0 +1,70 @@
+use components::{Position, Velocity};
+use toucan_ecs::prelude::*;
+
+mod components;
+mod utils;
+
+#[test]
+fn stats_disabled() {
+    let mut world = utils::prepare_for_view();
+
+    let mut schedule = Schedule::builder()
+        .system(|| println!("Hello, World"))
+        .build();
+    schedule.run(&mut world);
+    assert!(schedule.stats().is_none());
+}
+
+#[test]
+fn stats() {
+    let mut world = utils::prepare_for_complex_view();
+
+    let mut schedule = Schedule::builder()
+        .with_stats()
+        .system(|| println!("Hello, World"))
+        .foreach_system(|position: &mut Position| position.x += 1.0)
+        .foreach_system(|_: &Position, velocity: &Velocity| println!("{:?}", velocity))
+        .build();
+    schedule.run(&mut world);
+    schedule.run(&mut world);
+
+    let stats = schedule.stats().unwrap();
+    assert_eq!(stats.runs(), 2);
+    assert_eq!(stats.len(), 3);
+    for system in stats.iter() {
+        assert_eq!(system.invocations(), 2);
+        assert!(system.total_duration() >= system.last_duration());
+    }
+    assert_eq!(stats.get(0).unwrap().entities(), None);
+    assert_eq!(stats.get(1).unwrap().entities(), Some(20));
+    assert_eq!(stats.get(1).unwrap().last_entities(), Some(10));
+    assert_eq!(stats.get(2).unwrap().entities(), Some(10));
+    assert_eq!(stats.get(2).unwrap().last_entities(), Some(5));
+
+    schedule.reset_stats();
+    let stats = schedule.stats().unwrap();
+    assert_eq!(stats.runs(), 0);
+    assert_eq!(stats.get(1).unwrap().invocations(), 0);
+}
+
+#[test]
+#[cfg(feature = "resource")]
+fn stats_skipped_system() {
+    #[derive(Resource)]
+    struct Missing;
+
+    let mut world = World::new();
+
+    let mut schedule = Schedule::builder()
+        .system(|_: Res<Missing>| println!("Will not be invoked"))
+        .build();
+    schedule.enable_stats();
+    schedule.run(&mut world);
+
+    let stats = schedule.stats().unwrap();
+    assert_eq!(stats.runs(), 1);
+    assert_eq!(stats.get(0).unwrap().invocations(), 0);
+
+    schedule.disable_stats();
+    assert!(schedule.stats().is_none());
+}
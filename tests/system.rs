@@ -50,9 +50,10 @@ fn view_one_mut_system(view_one_mut: ViewOneMut<Velocity>) {
     }
 }
 
-fn view_system<'data, Q>(view: View<'data, Q>)
+fn view_system<'data, Q>(view: View<'data, Q::RebindRef<'data>>)
 where
-    Q: Query<'data> + Debug,
+    Q: Query<'static>,
+    for<'w> Q::RebindRef<'w>: Debug,
 {
     for item in view {
         println!("item: {:?}", item)
@@ -81,7 +82,7 @@ fn system() {
     let mut world = utils::prepare_for_view();
 
     let mut local_var = 0;
-    let local_system = || {
+    let local_system = move || {
         local_var += 1;
         println!("Some var is {}", local_var)
     };
@@ -135,3 +136,29 @@ fn for_each_system() {
         .build();
     schedule.run(&mut world);
 }
+
+#[test]
+fn owned_schedule() {
+    struct State {
+        world: World,
+        schedule: Schedule,
+    }
+
+    fn assert_send<T: Send + 'static>(_: &T) {}
+
+    let mut state = State {
+        world: utils::prepare_for_view(),
+        schedule: Schedule::builder()
+            .system(view_one_mut_system)
+            .foreach_system(|position: &mut Position| position.x += 1.0)
+            .build(),
+    };
+    assert_send(&state.schedule);
+
+    let State { world, schedule } = &mut state;
+    schedule.run(world);
+    schedule.run(world);
+
+    *schedule = Schedule::builder().system(view_mut_system).build();
+    schedule.run(world);
+}
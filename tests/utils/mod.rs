@@ -2,6 +2,7 @@ use toucan_ecs::prelude::*;
 
 use crate::components::{Mass, Position, Velocity};
 
+#[allow(dead_code)]
 pub fn prepare_for_view() -> World {
     let mut world = World::new();
 
@@ -1,7 +1,8 @@
-use darling::FromDeriveInput;
+use darling::ast::Data;
+use darling::{FromDeriveInput, FromField};
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{DeriveInput, Path, Result};
+use syn::{DeriveInput, Ident, Index, Path, Result};
 
 #[derive(FromDeriveInput, Default)]
 #[darling(default, attributes(component), forward_attrs(allow, doc, cfg))]
@@ -10,6 +11,26 @@ struct ComponentOptions {
     storage: Vec<Path>,
 }
 
+#[derive(FromDeriveInput, Default)]
+#[darling(default, attributes(resource), forward_attrs(allow, doc, cfg))]
+struct ResourceOptions {
+    init: Option<Path>,
+}
+
+#[derive(FromDeriveInput)]
+#[darling(attributes(reflect), supports(struct_any))]
+struct ReflectOptions {
+    data: Data<(), ReflectField>,
+}
+
+#[derive(FromField)]
+#[darling(attributes(reflect))]
+struct ReflectField {
+    ident: Option<Ident>,
+    #[darling(default)]
+    skip: bool,
+}
+
 pub fn component(input: DeriveInput) -> Result<TokenStream> {
     let ComponentOptions { storage } = ComponentOptions::from_derive_input(&input)?;
     let DeriveInput {
@@ -31,14 +52,71 @@ pub fn component(input: DeriveInput) -> Result<TokenStream> {
 }
 
 pub fn resource(input: DeriveInput) -> Result<TokenStream> {
+    let ResourceOptions { init } = ResourceOptions::from_derive_input(&input)?;
     let DeriveInput {
         ident, generics, ..
     } = input;
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     let trait_ident = quote! { Resource };
+    let from_world = init.map(|init| {
+        quote! {
+            impl #impl_generics FromWorld for #ident #ty_generics #where_clause {
+                fn from_world(world: &mut World) -> Self {
+                    #init(world)
+                }
+            }
+        }
+    });
     let output = quote! {
         impl #impl_generics #trait_ident for #ident #ty_generics #where_clause {}
+        #from_world
+    };
+    Ok(output)
+}
+
+pub fn reflect(input: DeriveInput) -> Result<TokenStream> {
+    let ReflectOptions { data } = ReflectOptions::from_derive_input(&input)?;
+    let DeriveInput {
+        ident, generics, ..
+    } = input;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let fields = data.take_struct().expect("only structs are supported");
+    let (names, members): (Vec<_>, Vec<_>) = fields
+        .into_iter()
+        .enumerate()
+        .filter(|(_, field)| !field.skip)
+        .map(|(index, field)| match field.ident {
+            Some(ident) => (ident.to_string(), quote! { #ident }),
+            None => {
+                let index = Index::from(index);
+                (index.index.to_string(), quote! { #index })
+            }
+        })
+        .unzip();
+
+    let trait_ident = quote! { Reflect };
+    let output = quote! {
+        impl #impl_generics #trait_ident for #ident #ty_generics #where_clause {
+            fn fields(&self) -> &'static [&'static str] {
+                &[ #( #names ),* ]
+            }
+
+            fn field(&self, name: &str) -> ::std::option::Option<&dyn #trait_ident> {
+                match name {
+                    #( #names => ::std::option::Option::Some(&self.#members), )*
+                    _ => ::std::option::Option::None,
+                }
+            }
+
+            fn field_mut(&mut self, name: &str) -> ::std::option::Option<&mut dyn #trait_ident> {
+                match name {
+                    #( #names => ::std::option::Option::Some(&mut self.#members), )*
+                    _ => ::std::option::Option::None,
+                }
+            }
+        }
     };
     Ok(output)
 }
@@ -21,7 +21,7 @@ pub fn component_derive(input: TokenStream) -> TokenStream {
         .into()
 }
 
-#[proc_macro_derive(Resource)]
+#[proc_macro_derive(Resource, attributes(resource))]
 pub fn resource_derive(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     derive::resource(input)
@@ -29,6 +29,14 @@ pub fn resource_derive(input: TokenStream) -> TokenStream {
         .into()
 }
 
+#[proc_macro_derive(Reflect, attributes(reflect))]
+pub fn reflect_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive::reflect(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
 #[proc_macro]
 pub fn fetch_tuple(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input with Punctuated::<Ident, Token![,]>::parse_terminated)